@@ -0,0 +1,89 @@
+//! Cooperative cancellation of solutions running on a worker thread.
+//!
+//! Long running loops call [`checkpoint`], which unwinds the worker once its time is up.
+//! Solutions without checkpoints can't be stopped: their worker is abandoned and keeps running
+//! in the background, but the caller gets control back all the same.
+
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    /// The cancellation flag of the worker running on this thread, if any
+    static FLAG: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
+/// The payload a cancelled worker unwinds with
+#[derive(Debug)]
+pub struct Cancelled;
+
+/// Returns `true` if the worker running on this thread was cancelled
+fn is_cancelled() -> bool {
+    FLAG.with_borrow(|flag| flag.as_ref().is_some_and(|x| x.load(Ordering::Relaxed)))
+}
+
+/// Stops the current solution if it was cancelled, meant to be called in long running loops.
+///
+/// Does nothing outside of [`within`].
+pub fn checkpoint() {
+    if is_cancelled() {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Runs `f` on a worker thread, returns `None` and cancels it if it takes longer than `timeout`.
+///
+/// A panic of `f` is caught and returned like [`std::panic::catch_unwind`] does.
+pub fn within<T: Send + 'static>(
+    timeout: Duration,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Option<thread::Result<T>> {
+    let flag = Arc::new(AtomicBool::new(false));
+    let (sender, receiver) = mpsc::channel();
+
+    let worker_flag = Arc::clone(&flag);
+    thread::spawn(move || {
+        FLAG.set(Some(worker_flag));
+        let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+    });
+
+    let result = receiver.recv_timeout(timeout).ok();
+    if result.is_none() {
+        flag.store(true, Ordering::Relaxed);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn within_works() {
+        assert_eq!(42, within(Duration::from_secs(5), || 42).unwrap().unwrap());
+        assert!(
+            within(Duration::from_secs(5), || panic::resume_unwind(Box::new(1)))
+                .unwrap()
+                .is_err()
+        );
+
+        let (sender, receiver) = mpsc::channel();
+        let start = Instant::now();
+        let result = within(Duration::from_millis(50), move || {
+            let result = panic::catch_unwind(|| loop {
+                checkpoint();
+                thread::sleep(Duration::from_millis(1));
+            });
+            sender.send(result.is_err()).unwrap();
+        });
+        assert!(result.is_none());
+        assert!(start.elapsed() < Duration::from_secs(5));
+        assert_eq!(Ok(true), receiver.recv_timeout(Duration::from_secs(5)));
+        assert!(!is_cancelled());
+        checkpoint();
+    }
+}
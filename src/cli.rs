@@ -0,0 +1,1094 @@
+//! The commands of the CLI, `main.rs` only parses the arguments and dispatches to them.
+
+mod answers;
+pub mod bench;
+mod calendar;
+mod client;
+pub mod config;
+pub mod history;
+mod leaderboard;
+mod progress;
+pub mod report;
+mod scaffold;
+mod submissions;
+mod table;
+mod toml;
+mod watch;
+
+use crate::{
+    BenchArgs, FetchArgs, LeaderboardArgs, NewArgs, Opt, ProgressArgs, RunArgs, SortBy, SubmitArgs,
+    TodayArgs, VerifyArgs, EXAMPLES_DIR, SOLUTIONS_DIR,
+};
+use answers::{Check, ExpectedAnswers};
+use aoc::error::AocError;
+use aoc::input;
+use aoc::params::Params;
+use aoc::runner::{self, DayResult, Outcome};
+use aoc::solutions::{self, Answer, Answers, Entry, Parts, Timings};
+use aoc::util;
+use bench::{Phase, Stats};
+use chrono::{DateTime, Utc};
+use client::{Client, Verdict};
+use config::Config;
+use history::History;
+use itertools::Itertools;
+use leaderboard::Leaderboard;
+use report::{Format, Record};
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use submissions::{Submission, SubmissionLog};
+use table::{Align, Table};
+
+/// The input file name standing for stdin
+const STDIN: &str = "-";
+
+/// The exit code if an input is malformed
+const EXIT_INVALID_INPUT: u8 = 3;
+
+/// The exit code if a solution takes longer than `--timeout`
+const EXIT_TIMEOUT: u8 = 4;
+
+/// The exit code if a solution panics
+const EXIT_PANICKED: u8 = 1;
+
+/// How often `--watch` looks for changed files
+const WATCH_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Solves the inputs of a single day, see [`Opt`]
+pub fn solve_day(opt: &Opt) -> ExitCode {
+    let found = solutions::find(opt.year.unwrap(), opt.day.unwrap()).map_err(String::from);
+    let (entry, config) = match found.and_then(|entry| Ok((entry, Config::load()?))) {
+        Ok(found) => found,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let sources = match single_inputs(opt, entry, &config) {
+        Ok(sources) => sources,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let settings = day_settings(&config, entry, &opt.params, opt.format, opt.timeout);
+    let (params, format, timeout) = match settings {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let parts = Parts::of(opt.part);
+    if opt.watch {
+        return watch(entry, sources, parts, &params);
+    }
+    solve_sources(entry, &sources, parts, &params, format, timeout)
+}
+
+/// Returns the parameters, output format and timeout of a single day, the explicit ones overriding the config
+fn day_settings(
+    config: &Config,
+    entry: &Entry,
+    params: &[(String, String)],
+    format: Option<Format>,
+    timeout: Option<Duration>,
+) -> Result<(Params, Format, Option<Duration>), String> {
+    let params = config.params_of(entry.year, entry.day, params);
+    params.check(entry.params())?;
+    Ok((params, config.format_of(format), config.timeout_of(timeout)))
+}
+
+/// Solves each source in single day mode
+fn solve_sources(
+    entry: &'static Entry,
+    sources: &[Source],
+    parts: Parts,
+    params: &Params,
+    format: Format,
+    timeout: Option<Duration>,
+) -> ExitCode {
+    let mut exit_code = ExitCode::SUCCESS;
+    let mut records = Vec::new();
+
+    for (idx, source) in sources.iter().enumerate() {
+        if format == Format::Text && sources.len() > 1 {
+            if idx > 0 {
+                println!();
+            }
+            println!("== {} ==", source.name);
+        }
+        let solved = solve_within(entry, &source.input, parts, params, timeout);
+        match solved {
+            Ok((answers, timings)) if format == Format::Text => {
+                print_solved(&source.input, parts, &answers, &timings);
+            }
+            Ok((answers, timings)) => records.extend(Record::solved(
+                entry.year,
+                entry.day,
+                &source.name,
+                parts,
+                &answers,
+                &timings,
+            )),
+            Err(error) => {
+                eprintln!("{}", error.diagnostic());
+                let (status, code) = match error {
+                    AocError::Parse { .. } => ("invalid-input", EXIT_INVALID_INPUT),
+                    AocError::Timeout { .. } => ("timeout", EXIT_TIMEOUT),
+                    AocError::Panicked { .. } => ("failed", EXIT_PANICKED),
+                };
+                exit_code = ExitCode::from(code);
+                records.extend(Record::skipped(
+                    entry.year,
+                    entry.day,
+                    &source.name,
+                    parts,
+                    status,
+                    &error.to_string(),
+                ));
+            }
+        }
+    }
+
+    if format != Format::Text {
+        print_records(format, &records);
+    }
+    exit_code
+}
+
+/// Solves `input`, on a worker thread that is given up on after `timeout` if any.
+///
+/// A panic of the solution is reported as [`AocError::Panicked`].
+fn solve_within(
+    entry: &'static Entry,
+    input: &str,
+    parts: Parts,
+    params: &Params,
+    timeout: Option<Duration>,
+) -> Result<(Answers, Timings), AocError> {
+    let (input, params) = (input.to_string(), params.clone());
+    let solved = runner::quietly(|| {
+        runner::guard(timeout, move || entry.solve_with(&input, parts, &params))
+    });
+    let (year, day) = (entry.year, entry.day);
+    match solved {
+        Ok(result) => result,
+        Err(Outcome::TimedOut(after)) => Err(AocError::Timeout { year, day, after }),
+        Err(Outcome::Failed(message)) => Err(AocError::Panicked { year, day, message }),
+        Err(outcome) => unreachable!("guard only fails or times out, got {outcome:?}"),
+    }
+}
+
+fn print_solved(input: &str, parts: Parts, answers: &Answers, timings: &Timings) {
+    if !input::normalize(input).0.is_ascii() {
+        eprintln!("WARNING: Input is not ASCII!");
+    }
+
+    let mut phases = vec![format!("parse: {}", format_duration(timings.parse))];
+    for ((part, answer), duration) in answers
+        .iter()
+        .zip([timings.part_one, timings.part_two])
+        .filter(|((part, _), _)| parts.includes(*part))
+    {
+        print_answer(part, answer);
+        phases.push(format!("part {part}: {}", format_duration(duration)));
+    }
+
+    println!(
+        "Time elapsed: {} ({})",
+        format_duration(timings.total()),
+        phases.join(", ")
+    );
+}
+
+/// Solves the inputs again whenever one of their files changes.
+///
+/// Changes to the module of the day are only reported, as the binary has to be rebuilt to pick them up.
+fn watch(entry: &Entry, mut sources: Vec<Source>, parts: Parts, params: &Params) -> ExitCode {
+    let Some(mut changed) = sources
+        .iter()
+        .map(|x| x.path.clone())
+        .collect::<Option<Vec<_>>>()
+    else {
+        eprintln!("--watch needs input files, stdin and --input can't be watched");
+        return ExitCode::FAILURE;
+    };
+    let named = sources.len() > 1;
+    let module = scaffold::day_path(Path::new(SOLUTIONS_DIR), entry.year, entry.day);
+    let mut watcher = watch::Watcher::new(
+        changed
+            .iter()
+            .cloned()
+            .chain(Some(module.clone()).filter(|x| x.is_file())),
+    );
+    let mut previous = vec![None; sources.len()];
+
+    loop {
+        for (source, previous) in sources.iter_mut().zip(&mut previous) {
+            let Some(path) = source.path.as_ref().filter(|x| changed.contains(x)) else {
+                continue;
+            };
+            if named {
+                println!("== {} ==", source.name);
+            }
+            match read_file(path) {
+                Ok(input) => source.input = input,
+                Err(message) => {
+                    eprintln!("{message}");
+                    continue;
+                }
+            }
+            let result = runner::quietly(|| {
+                runner::catch(|| entry.solve_with(&source.input, parts, params))
+            });
+            match result {
+                Ok(Ok((answers, timings))) => {
+                    for line in watch::diff(previous.as_ref(), &answers, parts) {
+                        println!("{line}");
+                    }
+                    println!("Time elapsed: {}", format_duration(timings.total()));
+                    *previous = Some(answers);
+                }
+                Ok(Err(error)) => eprintln!("{}", error.diagnostic()),
+                Err(message) => eprintln!("FAILED: {message}"),
+            }
+        }
+
+        println!("\nWatching for changes (Ctrl+C to stop)");
+        changed = loop {
+            let mut changed = watcher.wait(WATCH_INTERVAL);
+            if changed.contains(&module) {
+                changed.retain(|x| *x != module);
+                eprintln!(
+                    "{} changed, rebuild and restart --watch to pick it up (still running the old build)",
+                    module.display()
+                );
+            }
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        println!(
+            "\n{} changed",
+            changed.iter().map(|x| x.display()).join(", ")
+        );
+    }
+}
+
+pub fn run(args: &RunArgs) -> ExitCode {
+    let run = Config::load()
+        .and_then(|config| Ok((config.format_of(args.format), args.selection.run(&config)?)));
+    let (format, results) = match run {
+        Ok(run) => run,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if format == Format::Text {
+        print_summary(&results);
+    } else {
+        let records = results.iter().flat_map(Record::of_result).collect_vec();
+        print_records(format, &records);
+    }
+
+    if results.iter().any(DayResult::is_failure) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn print_summary(results: &[DayResult]) {
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Part 1", Align::Left),
+        ("Part 2", Align::Left),
+        ("Time", Align::Right),
+    ]);
+    let mut arts = Vec::new();
+
+    for result in results {
+        let (year, day) = (result.entry.year, result.entry.day);
+        let (one, two) = match &result.outcome {
+            Outcome::Solved(answers) => {
+                let [one, two] =
+                    [(1, &answers.part_one), (2, &answers.part_two)].map(|(part, answer)| {
+                        if !result.parts.includes(part) {
+                            return String::new();
+                        }
+                        if answer.is_multiline() {
+                            arts.push((year, day, part, answer));
+                        }
+                        summary_cell(answer)
+                    });
+                (one, two)
+            }
+            Outcome::MissingInput(path) => {
+                (String::from("MISSING INPUT"), path.display().to_string())
+            }
+            Outcome::Invalid(error) => (String::from("INVALID INPUT"), error.to_string()),
+            Outcome::TimedOut(timeout) => (String::from("TIMEOUT"), format!("after {timeout:?}")),
+            Outcome::Failed(message) => (String::from("FAILED"), message.clone()),
+        };
+        table.add_row(vec![
+            year.to_string(),
+            day.to_string(),
+            one,
+            two,
+            format_duration(result.duration()),
+        ]);
+    }
+
+    print!("{table}");
+
+    for (year, day, part, answer) in arts {
+        println!("\n{year} day {day} part {part}:\n{answer}");
+    }
+
+    let total = results.iter().map(DayResult::duration).sum::<Duration>();
+    let solved = results
+        .iter()
+        .filter(|x| matches!(x.outcome, Outcome::Solved(_)))
+        .count();
+    let failed = results.iter().filter(|x| x.is_failure()).count();
+    println!(
+        "\nSolved: {solved}, missing input: {}, failed: {failed}, total time: {}",
+        results.len() - solved - failed,
+        format_duration(total)
+    );
+}
+
+pub fn verify(args: &VerifyArgs) -> ExitCode {
+    let loaded = Config::load().and_then(|config| {
+        let path = config.answers_file(args.answers.as_deref());
+        let expected = ExpectedAnswers::load(&path)?;
+        Ok((config, path, expected))
+    });
+    let (config, path, mut expected) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let format = config.format_of(args.format);
+    let results = match args.selection.run(&config) {
+        Ok(results) => results,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.record {
+        return record(&mut expected, &results, &path);
+    }
+
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Status", Align::Left),
+    ]);
+    let mut diffs = Vec::new();
+    let mut records = Vec::new();
+    let mut failed = false;
+
+    for result in &results {
+        let (year, day) = (result.entry.year, result.entry.day);
+        let mut day_records = Record::of_result(result);
+        let Outcome::Solved(answers) = &result.outcome else {
+            let status = match result.outcome {
+                Outcome::TimedOut(_) => "TIMEOUT",
+                _ if result.is_failure() => "FAILED",
+                _ => "SKIPPED",
+            };
+            failed |= result.is_failure();
+            table.add_row(vec![
+                year.to_string(),
+                day.to_string(),
+                String::new(),
+                status.to_string(),
+            ]);
+            records.extend(day_records);
+            continue;
+        };
+        let calculated = answers
+            .iter()
+            .filter(|(part, _)| result.parts.includes(*part));
+        for ((part, answer), record) in calculated.zip(&mut day_records) {
+            let status = match expected.check(year, day, part, answer) {
+                Check::Pass => "PASS",
+                Check::Missing => "MISSING",
+                Check::Fail { expected, actual } => {
+                    failed = true;
+                    record.message = Some(format!("expected {expected}"));
+                    diffs.push((year, day, part, answers::diff(&expected, &actual)));
+                    "FAIL"
+                }
+            };
+            record.status = status.to_lowercase();
+            table.add_row(vec![
+                year.to_string(),
+                day.to_string(),
+                part.to_string(),
+                status.to_string(),
+            ]);
+        }
+        records.extend(day_records);
+    }
+
+    if format != Format::Text {
+        print_records(format, &records);
+        return if failed {
+            ExitCode::FAILURE
+        } else {
+            ExitCode::SUCCESS
+        };
+    }
+
+    print!("{table}");
+
+    for (year, day, part, diff) in diffs {
+        println!("\n{year} day {day} part {part}:");
+        for line in diff {
+            println!("  {line}");
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn record(expected: &mut ExpectedAnswers, results: &[DayResult], path: &Path) -> ExitCode {
+    let mut recorded = 0;
+    for result in results {
+        if let Outcome::Solved(answers) = &result.outcome {
+            let calculated = answers
+                .iter()
+                .filter(|(part, _)| result.parts.includes(*part));
+            for (part, answer) in calculated {
+                expected.set(result.entry.year, result.entry.day, part, answer);
+                recorded += 1;
+            }
+        }
+    }
+
+    if let Err(e) = expected.save(path) {
+        eprintln!("{}: {e}", path.display());
+        return ExitCode::FAILURE;
+    }
+    println!("Recorded {recorded} answers in {}", path.display());
+    ExitCode::SUCCESS
+}
+
+pub fn bench(args: &BenchArgs) -> ExitCode {
+    let selected = args.selection.entries().map_err(String::from);
+    let loaded = selected.and_then(|entries| {
+        let (history, config) = (History::load(&args.history)?, Config::load()?);
+        config.check_params(&entries)?;
+        Ok((entries, config, history))
+    });
+    let (entries, config, mut history) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let baseline = args.baseline.clone().or_else(|| {
+        history
+            .labels()
+            .into_iter()
+            .rev()
+            .find(|x| Some(*x) != args.label.as_deref())
+            .map(ToString::to_string)
+    });
+
+    let (inputs, timeout) = (
+        args.selection.inputs(&config),
+        args.selection.timeout(&config),
+    );
+    let parts = args.selection.parts();
+    let mut rows = Vec::new();
+    let mut skipped = Vec::new();
+    let mut failed = false;
+
+    runner::quietly(|| {
+        for entry in entries {
+            let (warmup, runs) = (args.warmup, args.runs);
+            let params = config.params_of(entry.year, entry.day, &[]);
+            let result = runner::read_input(entry, &inputs).and_then(|input| {
+                runner::guard(timeout, move || {
+                    bench::bench(entry, &input, parts, &params, warmup, runs)
+                })?
+                .map_err(Outcome::Invalid)
+            });
+            match result {
+                Ok(phases) => {
+                    rows.extend(
+                        phases
+                            .into_iter()
+                            .map(|(phase, stats)| (entry, phase, stats)),
+                    );
+                }
+                Err(outcome) => {
+                    failed |= !matches!(outcome, Outcome::MissingInput(_));
+                    skipped.push((entry, outcome));
+                }
+            }
+        }
+    });
+
+    if args.sort != SortBy::Day {
+        rows.sort_by(|a, b| args.sort.key(&b.2).total_cmp(&args.sort.key(&a.2)));
+    }
+
+    let regressions = print_bench(&rows, &history, baseline.as_deref(), args);
+
+    print_skipped(&skipped);
+
+    if let Some(label) = &baseline {
+        println!(
+            "\n{regressions} phase(s) slower than baseline {label:?} by more than {}%",
+            args.threshold
+        );
+    }
+
+    if let Some(label) = &args.label {
+        for (entry, phase, stats) in &rows {
+            history.record(label, entry.year, entry.day, *phase, stats.median);
+        }
+        if let Err(e) = history.save(&args.history) {
+            eprintln!("{}: {e}", args.history.display());
+            return ExitCode::FAILURE;
+        }
+        println!("Saved as {label:?} in {}", args.history.display());
+    }
+
+    if failed || regressions > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Prints why the days without statistics were skipped
+fn print_skipped(skipped: &[(&Entry, Outcome)]) {
+    for (entry, outcome) in skipped {
+        match outcome {
+            Outcome::MissingInput(path) => {
+                println!(
+                    "{} day {}: missing input {}",
+                    entry.year,
+                    entry.day,
+                    path.display()
+                );
+            }
+            Outcome::Invalid(error) => println!("{error}"),
+            Outcome::TimedOut(timeout) => {
+                println!(
+                    "{} day {}: TIMEOUT after {timeout:?}",
+                    entry.year, entry.day
+                );
+            }
+            Outcome::Failed(message) => {
+                println!("{} day {}: FAILED {message}", entry.year, entry.day);
+            }
+            Outcome::Solved(_) => {}
+        }
+    }
+}
+
+/// Prints the statistics, compared against the `baseline` run if any.
+///
+/// Returns the number of phases that got slower than allowed.
+fn print_bench(
+    rows: &[(&Entry, Phase, Stats)],
+    history: &History,
+    baseline: Option<&str>,
+    args: &BenchArgs,
+) -> usize {
+    let mut columns = vec![
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Phase", Align::Left),
+        ("Min µs", Align::Right),
+        ("Median µs", Align::Right),
+        ("Mean µs", Align::Right),
+        ("Stddev µs", Align::Right),
+    ];
+    if baseline.is_some() {
+        columns.push(("Baseline µs", Align::Right));
+        columns.push(("Change", Align::Right));
+    }
+    let mut table = Table::new(&columns);
+    let mut regressions = 0;
+
+    for (entry, phase, stats) in rows {
+        let mut row = vec![
+            entry.year.to_string(),
+            entry.day.to_string(),
+            phase.to_string(),
+            format!("{:.1}", stats.min),
+            format!("{:.1}", stats.median),
+            format!("{:.1}", stats.mean),
+            format!("{:.1}", stats.stddev),
+        ];
+        if let Some(label) = baseline {
+            match history.get(label, entry.year, entry.day, *phase) {
+                Some(old) => {
+                    let mut change = if old > 0.0 {
+                        format!("{:+.1}%", (stats.median - old) / old * 100.0)
+                    } else {
+                        String::from("new")
+                    };
+                    if history::is_regression(old, stats.median, args.threshold, args.min_delta) {
+                        regressions += 1;
+                        change.push_str(" SLOWER");
+                    }
+                    row.push(format!("{old:.1}"));
+                    row.push(change);
+                }
+                None => row.push(String::from("-")),
+            }
+        }
+        table.add_row(row);
+    }
+    print!("{table}");
+    regressions
+}
+
+pub fn fetch(args: &FetchArgs) -> ExitCode {
+    let year = solutions::normalize_year(args.year);
+    let result = Config::load().and_then(|config| {
+        let root = config.inputs_root(args.inputs.as_deref());
+        fetch_input(&config, &root, year, args.day, calendar::now())
+    });
+    match result {
+        Ok((path, true)) => println!("Saved input to {}", path.display()),
+        Ok((path, false)) => println!("Input already present at {}", path.display()),
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Makes sure the input of `year` and `day` is stored below `root`, downloading it if missing.
+///
+/// Returns the path of the input and whether it was downloaded.
+fn fetch_input(
+    config: &Config,
+    root: &Path,
+    year: u16,
+    day: u8,
+    now: DateTime<Utc>,
+) -> Result<(PathBuf, bool), String> {
+    let path = runner::input_path(root, year, day);
+    if path.is_file() {
+        return Ok((path, false));
+    }
+    let release = calendar::release_time(year, day)
+        .ok_or_else(|| format!("There is no puzzle on day {day} of {year}"))?;
+    if !calendar::is_released(year, day, now) {
+        return Err(format!("{year} day {day} is not unlocked before {release}"));
+    }
+
+    let client = Client::new(&config.url.value, &config.session_token()?);
+    let input = client.fetch_input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+    }
+    fs::write(&path, input).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok((path, true))
+}
+
+pub fn submit(args: &SubmitArgs) -> ExitCode {
+    let year = solutions::normalize_year(args.year);
+    match Config::load().and_then(|config| submit_answer(&config, args, year)) {
+        Ok(Verdict::Correct) => ExitCode::SUCCESS,
+        Ok(_) => ExitCode::FAILURE,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn submit_answer(config: &Config, args: &SubmitArgs, year: u16) -> Result<Verdict, String> {
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => calculate_answer(config, args, year)?,
+    };
+
+    let mut log = SubmissionLog::load(&args.log)?;
+    log.check(year, args.day, args.part, &answer)?;
+
+    println!(
+        "Submitting {answer} for {year} day {} part {}",
+        args.day, args.part
+    );
+    let client = Client::new(&config.url.value, &config.session_token()?);
+    let verdict = client.submit(year, args.day, args.part, &answer)?;
+    println!("{verdict}");
+
+    log.append(
+        calendar::now(),
+        Submission::new(year, args.day, args.part, &answer, &verdict),
+    )?;
+    Ok(verdict)
+}
+
+/// Solves the part to submit with the input of the inputs directory
+fn calculate_answer(config: &Config, args: &SubmitArgs, year: u16) -> Result<String, String> {
+    let entry = solutions::find(year, args.day)?;
+    let root = config.inputs_root(args.inputs.as_deref());
+    let input = read_file(&runner::input_path(&root, year, args.day))?;
+    let answers = entry
+        .solve(&input, Parts::of(Some(args.part)))
+        .map_err(|e| e.diagnostic())?;
+    let answer = if args.part == 1 {
+        answers.part_one
+    } else {
+        answers.part_two
+    };
+    match answer {
+        Answer::Unsolved => Err(format!("Part {} is not solved", args.part)),
+        Answer::Art(art) => Err(format!(
+            "Part {} is ASCII art, read it and pass it with --answer:\n{art}",
+            args.part
+        )),
+        answer => Ok(answer.to_string()),
+    }
+}
+
+pub fn new_day(args: &NewArgs) -> ExitCode {
+    let year = solutions::normalize_year(args.year);
+    create_day(&args.solutions, &args.examples, year, args.day)
+}
+
+fn create_day(solutions: &Path, examples: &Path, year: u16, day: u8) -> ExitCode {
+    match scaffold::create(solutions, examples, year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Solves the puzzle released today, see [`TodayArgs`]
+pub fn today(args: &TodayArgs) -> ExitCode {
+    let now = args.now.unwrap_or_else(calendar::now);
+    let (year, day) = match calendar::puzzle_of(now) {
+        Ok(puzzle) => puzzle,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let Ok(entry) = solutions::find(year, day) else {
+        return offer_new_day(year, day);
+    };
+
+    let prepared = Config::load().and_then(|config| {
+        let root = config.inputs_root(args.inputs.as_deref());
+        let (path, fetched) = fetch_input(&config, &root, year, day, now)?;
+        if fetched {
+            eprintln!("Saved input to {}", path.display());
+        }
+        let source = Source {
+            name: path.display().to_string(),
+            input: read_file(&path)?,
+            path: Some(path),
+        };
+        Ok((
+            source,
+            day_settings(&config, entry, &[], args.format, None)?,
+        ))
+    });
+    let (source, (params, format, timeout)) = match prepared {
+        Ok(prepared) => prepared,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if format == Format::Text {
+        println!("== {year} day {day} ==");
+    }
+    solve_sources(
+        entry,
+        &[source],
+        Parts::of(args.part),
+        &params,
+        format,
+        timeout,
+    )
+}
+
+/// Asks whether to generate the missing day on a terminal, otherwise tells how to
+fn offer_new_day(year: u16, day: u8) -> ExitCode {
+    eprintln!("{year} day {day} is not implemented yet");
+    if !io::stdin().is_terminal() {
+        eprintln!("Start it with `aoc new --year {year} --day {day}`");
+        return ExitCode::FAILURE;
+    }
+
+    eprint!("Generate the module now? [y/N] ");
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() || !answer.trim().eq_ignore_ascii_case("y") {
+        return ExitCode::FAILURE;
+    }
+    create_day(Path::new(SOLUTIONS_DIR), Path::new(EXAMPLES_DIR), year, day)
+}
+
+pub fn leaderboard(args: &LeaderboardArgs) -> ExitCode {
+    let loaded = Config::load().and_then(|config| {
+        let format = config.format_of(args.format);
+        let text = read_file(&args.file)?;
+        let leaderboard =
+            Leaderboard::parse(&text).map_err(|e| format!("{}: {e}", args.file.display()))?;
+        Ok((format, leaderboard))
+    });
+    let (format, leaderboard) = match loaded {
+        Ok(loaded) => loaded,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match format {
+        Format::Text => {
+            println!(
+                "{} private leaderboard, {} members\n",
+                leaderboard.event,
+                leaderboard.members.len()
+            );
+            println!("{}", leaderboard::ranking_table(&leaderboard));
+            print!("{}", leaderboard::times_table(&leaderboard));
+        }
+        Format::Json => print!("{}", leaderboard::to_json(&leaderboard)),
+        Format::Csv => {
+            eprintln!("The leaderboard is only available as text or json");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+pub fn progress(args: &ProgressArgs) -> ExitCode {
+    let years = progress::of(solutions::all());
+    let Some(path) = &args.readme else {
+        print!("{}", progress::table(&years));
+        if args.grid {
+            println!("\n{}", progress::grid(&years));
+        }
+        return ExitCode::SUCCESS;
+    };
+
+    let table = progress::table(&years).to_string();
+    let updated = read_file(path).and_then(|text| {
+        progress::replace_section(&text, &table)
+            .and_then(|text| fs::write(path, text).map_err(|e| e.to_string()))
+            .map_err(|e| format!("{}: {e}", path.display()))
+    });
+    if let Err(message) = updated {
+        eprintln!("{message}");
+        return ExitCode::FAILURE;
+    }
+    println!("Updated {}", path.display());
+    ExitCode::SUCCESS
+}
+
+pub fn show_config() -> ExitCode {
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut table = Table::new(&[
+        ("Key", Align::Left),
+        ("Value", Align::Left),
+        ("Source", Align::Left),
+    ]);
+    for (key, value, origin) in config.entries() {
+        table.add_row(vec![key, value, origin.to_string()]);
+    }
+    print!("{table}");
+    ExitCode::SUCCESS
+}
+
+fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Json => print!("{}", report::to_json(records)),
+        Format::Csv => print!("{}", report::to_csv(records)),
+        Format::Text => {}
+    }
+}
+
+fn summary_cell(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        String::from("(see below)")
+    } else {
+        answer.to_string()
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+pub fn print_list() {
+    for (year, days) in &solutions::all().chunk_by(|x| x.year) {
+        let days = days.map(|x| x.day).collect_vec();
+        println!(
+            "{year} ({:>2} / {}): {}",
+            days.len(),
+            calendar::days_of_year(year),
+            days.iter().join(", ")
+        );
+    }
+}
+
+pub fn list_params(year: Option<u16>, day: Option<u8>) -> ExitCode {
+    let entries = match (year, day) {
+        (Some(year), Some(day)) => solutions::find(year, day).map(|x| vec![x]),
+        (Some(year), None) => Ok(solutions::of_year(year).collect_vec()),
+        _ => Ok(solutions::all().collect_vec()),
+    };
+    let entries = match entries {
+        Ok(entries) => entries,
+        Err(message) => {
+            eprintln!("{message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Day", Align::Right),
+        ("Param", Align::Left),
+        ("Type", Align::Left),
+        ("Default", Align::Left),
+        ("Description", Align::Left),
+    ]);
+    for entry in entries {
+        for param in entry.params() {
+            table.add_row(vec![
+                entry.year.to_string(),
+                entry.day.to_string(),
+                param.name.to_string(),
+                param.type_name().to_string(),
+                param.default.to_string(),
+                param.description.to_string(),
+            ]);
+        }
+    }
+    print!("{table}");
+    ExitCode::SUCCESS
+}
+
+fn print_answer(part: u8, answer: &Answer) {
+    if answer.is_multiline() {
+        println!("Part {part}:\n{answer}");
+    } else {
+        println!("Part {part}: {answer}");
+    }
+}
+
+/// A puzzle input together with a name of where it came from
+struct Source {
+    name: String,
+    input: String,
+    /// The file the input was read from
+    path: Option<PathBuf>,
+}
+
+/// Returns the inputs of single day mode: the input files (`-` being stdin), `--input`
+/// or the input found in the inputs directory, in this order
+fn single_inputs(opt: &Opt, entry: &Entry, config: &Config) -> Result<Vec<Source>, String> {
+    if !opt.input_files.is_empty() {
+        let stdins = opt.input_files.iter().filter(|x| x.as_os_str() == STDIN);
+        if stdins.count() > 1 {
+            return Err(String::from("stdin (`-`) can only be given once"));
+        }
+        return opt
+            .input_files
+            .iter()
+            .map(|path| {
+                if path.as_os_str() == STDIN {
+                    read_stdin().map(|input| Source {
+                        name: String::from("stdin"),
+                        input,
+                        path: None,
+                    })
+                } else {
+                    read_file(path).map(|input| Source {
+                        name: path.display().to_string(),
+                        input,
+                        path: Some(path.clone()),
+                    })
+                }
+            })
+            .collect();
+    }
+    if let Some(input) = &opt.input {
+        return Ok(vec![Source {
+            name: String::from("--input"),
+            input: input.clone(),
+            path: None,
+        }]);
+    }
+    let root = config.inputs_root(opt.inputs.as_deref());
+    let path = runner::input_path(&root, entry.year, entry.day);
+    if path.is_file() {
+        Ok(vec![Source {
+            name: path.display().to_string(),
+            input: read_file(&path)?,
+            path: Some(path),
+        }])
+    } else {
+        Err(format!(
+            "{} (looked for {})",
+            util::NO_INPUT,
+            path.display()
+        ))
+    }
+}
+
+fn read_stdin() -> Result<String, String> {
+    let mut contents = String::new();
+    io::stdin()
+        .lock()
+        .read_to_string(&mut contents)
+        .map_err(|e| format!("stdin: {e}"))?;
+    Ok(contents)
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
@@ -0,0 +1,151 @@
+//! The local file of expected answers used to verify the solutions.
+//!
+//! Each day is a section named `<year>-<dd>` with the keys `part_one` and `part_two`:
+//!
+//! ```toml
+//! [2015-07]
+//! part_one = 956
+//! part_two = 40149
+//! ```
+
+use crate::cli::toml::{self, Document, Value};
+use aoc::solutions::Answer;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+/// The result of comparing a calculated answer with the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String, actual: String },
+    Missing,
+}
+
+/// Expected answers per year, day and part
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    document: Document,
+}
+
+impl ExpectedAnswers {
+    /// Reads the answers from `path`, a missing file counts as empty
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    fn parse(text: &str) -> Result<Self, toml::Error> {
+        toml::parse(text).map(|document| Self { document })
+    }
+
+    /// Writes the answers to `path`, sorted by year and day
+    pub fn save(&mut self, path: &Path) -> io::Result<()> {
+        self.document.sections.sort_by(|a, b| a.name.cmp(&b.name));
+        fs::write(path, self.document.to_string())
+    }
+
+    /// Returns the expected answer in its textual form
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<String> {
+        self.document
+            .section(&section_name(year, day))
+            .and_then(|x| x.get(part_key(part)))
+            .map(ToString::to_string)
+    }
+
+    /// Stores `answer` as expected answer, unsolved answers are ignored
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: &Answer) {
+        let value = match answer {
+            Answer::Number(x) => {
+                i64::try_from(*x).map_or_else(|_| Value::String(x.to_string()), Value::Integer)
+            }
+            Answer::Text(x) | Answer::Art(x) => Value::String(x.clone()),
+            Answer::Unsolved => return,
+        };
+        self.document
+            .section_mut(&section_name(year, day))
+            .set(part_key(part), value);
+    }
+
+    /// Compares `actual` with the expected answer
+    pub fn check(&self, year: u16, day: u8, part: u8, actual: &Answer) -> Check {
+        match self.get(year, day, part) {
+            None => Check::Missing,
+            Some(expected) => {
+                let actual = actual.to_string();
+                if expected == actual {
+                    Check::Pass
+                } else {
+                    Check::Fail { expected, actual }
+                }
+            }
+        }
+    }
+}
+
+fn section_name(year: u16, day: u8) -> String {
+    format!("{year}-{day:02}")
+}
+
+fn part_key(part: u8) -> &'static str {
+    if part == 1 {
+        "part_one"
+    } else {
+        "part_two"
+    }
+}
+
+/// Returns a line based diff, prefixing expected lines with `-` and actual lines with `+`
+pub fn diff(expected: &str, actual: &str) -> Vec<String> {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let mut result = Vec::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (e, a) = (expected.get(i), actual.get(i));
+        if e == a {
+            continue;
+        }
+        if let Some(e) = e {
+            result.push(format!("- {e}"));
+        }
+        if let Some(a) = a {
+            result.push(format!("+ {a}"));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_works() {
+        let mut answers = ExpectedAnswers::parse("[2015-07]\npart_one = 956\n").unwrap();
+        assert_eq!(Check::Pass, answers.check(2015, 7, 1, &Answer::Number(956)));
+        assert_eq!(
+            Check::Fail {
+                expected: "956".to_string(),
+                actual: "12".to_string()
+            },
+            answers.check(2015, 7, 1, &Answer::Number(12))
+        );
+        assert_eq!(
+            Check::Missing,
+            answers.check(2015, 7, 2, &Answer::Number(1))
+        );
+
+        answers.set(2015, 7, 2, &Answer::Text("abc".to_string()));
+        assert_eq!(Check::Pass, answers.check(2015, 7, 2, &"abc".into()));
+    }
+
+    #[test]
+    fn diff_works() {
+        assert_eq!(vec!["- 1", "+ 2"], diff("1", "2"));
+        assert_eq!(vec!["- b", "+ c", "+ d"], diff("a\nb", "a\nc\nd"));
+        assert!(diff("a\nb", "a\nb").is_empty());
+    }
+}
@@ -0,0 +1,140 @@
+//! Repeated timing of the single phases (parsing, part one, part two) of a day.
+
+use aoc::error::AocError;
+use aoc::params::Params;
+use aoc::solutions::{Entry, Parts, Timings};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// A phase of solving a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Parse, Phase::PartOne, Phase::PartTwo];
+
+    /// Returns the name used in data files
+    pub fn key(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part_one",
+            Phase::PartTwo => "part_two",
+        }
+    }
+
+    /// Returns `true` if the phase runs when solving `parts`
+    pub fn is_part_of(self, parts: Parts) -> bool {
+        match self {
+            Phase::Parse => true,
+            Phase::PartOne => parts.includes(1),
+            Phase::PartTwo => parts.includes(2),
+        }
+    }
+
+    fn of(self, timings: &Timings) -> Duration {
+        match self {
+            Phase::Parse => timings.parse,
+            Phase::PartOne => timings.part_one,
+            Phase::PartTwo => timings.part_two,
+        }
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::PartOne => write!(f, "part 1"),
+            Phase::PartTwo => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Statistics over repeated measurements, all values in microseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Calculates the statistics of `samples`, which must not be empty
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut micros = samples
+            .iter()
+            .map(|x| x.as_secs_f64() * 1_000_000.0)
+            .collect::<Vec<_>>();
+        micros.sort_by(f64::total_cmp);
+
+        let n = micros.len();
+        let median = if n % 2 == 0 {
+            f64::midpoint(micros[n / 2 - 1], micros[n / 2])
+        } else {
+            micros[n / 2]
+        };
+        let mean = micros.iter().sum::<f64>() / n as f64;
+        let variance = micros.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+        Self {
+            min: micros[0],
+            median,
+            mean,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// Solves `parts` of `input` with `params` `warmup` times without measuring, then `runs` times
+/// with measuring.
+///
+/// Returns the statistics for each phase of `parts`, or the error if the input is malformed.
+pub fn bench(
+    entry: &Entry,
+    input: &str,
+    parts: Parts,
+    params: &Params,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<(Phase, Stats)>, AocError> {
+    for _ in 0..warmup {
+        entry.solve_with(input, parts, params)?;
+    }
+
+    let samples = (0..runs.max(1))
+        .map(|_| {
+            entry
+                .solve_with(input, parts, params)
+                .map(|(_, timings)| timings)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Phase::ALL
+        .into_iter()
+        .filter(|phase| phase.is_part_of(parts))
+        .map(|phase| {
+            let durations = samples.iter().map(|x| phase.of(x)).collect::<Vec<_>>();
+            (phase, Stats::from_samples(&durations))
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_works() {
+        let samples = [1, 3, 2, 6].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples);
+        assert!((stats.min - 1.0).abs() < 1e-9);
+        assert!((stats.median - 2.5).abs() < 1e-9);
+        assert!((stats.mean - 3.0).abs() < 1e-9);
+        assert!((stats.stddev - 3.5_f64.sqrt()).abs() < 1e-9);
+    }
+}
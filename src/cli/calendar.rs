@@ -0,0 +1,100 @@
+//! Release times of the puzzles.
+//!
+//! A puzzle unlocks at midnight of its day in December, in the time zone of the puzzle site (UTC-5).
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Offset of the release time zone to UTC in seconds
+const RELEASE_OFFSET: i32 = -5 * 3600;
+
+/// The time zone the puzzles are released in
+pub fn release_zone() -> FixedOffset {
+    FixedOffset::east_opt(RELEASE_OFFSET).unwrap()
+}
+
+/// Returns the number of puzzles of `year`, zero before the first event
+pub fn days_of_year(year: u16) -> u8 {
+    match year {
+        ..=2014 => 0,
+        2015..=2024 => 25,
+        _ => 12,
+    }
+}
+
+/// Returns the moment the puzzle of `year` and `day` unlocks, `None` if there is no such puzzle
+pub fn release_time(year: u16, day: u8) -> Option<DateTime<FixedOffset>> {
+    if day == 0 || day > days_of_year(year) {
+        return None;
+    }
+    let midnight =
+        NaiveDate::from_ymd_opt(i32::from(year), 12, u32::from(day))?.and_hms_opt(0, 0, 0)?;
+    release_zone().from_local_datetime(&midnight).single()
+}
+
+/// Returns `true` if the puzzle exists and is unlocked at `now`
+pub fn is_released(year: u16, day: u8, now: DateTime<Utc>) -> bool {
+    release_time(year, day).is_some_and(|x| x <= now)
+}
+
+/// Returns the year and day of the puzzle released last at `now`, if that happened on the same day.
+///
+/// Fails outside of the days of December with a puzzle, measured in the release time zone.
+pub fn puzzle_of(now: DateTime<Utc>) -> Result<(u16, u8), String> {
+    let date = now.with_timezone(&release_zone()).date_naive();
+    let (year, day) = (date.year() as u16, date.day() as u8);
+    if date.month() == 12 && day <= days_of_year(year) {
+        Ok((year, day))
+    } else if date.month() == 12 && days_of_year(year) > 0 {
+        Err(format!(
+            "The puzzles of {year} ended on December {}, it is {date} in UTC-5",
+            days_of_year(year)
+        ))
+    } else {
+        Err(format!(
+            "Puzzles are only released in December, it is {date} in UTC-5"
+        ))
+    }
+}
+
+/// The current time, read from the system clock
+pub fn now() -> DateTime<Utc> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    DateTime::from_timestamp(elapsed.as_secs() as i64, elapsed.subsec_nanos()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_released_works() {
+        let before = Utc.with_ymd_and_hms(2021, 12, 15, 4, 59, 59).unwrap();
+        let after = Utc.with_ymd_and_hms(2021, 12, 15, 5, 0, 0).unwrap();
+        assert!(!is_released(2021, 15, before));
+        assert!(is_released(2021, 15, after));
+        assert!(is_released(2021, 14, before));
+        assert!(!is_released(2021, 26, after));
+        assert!(!is_released(2021, 0, after));
+        assert!(!is_released(2025, 13, after));
+        assert!(!is_released(2014, 1, after));
+    }
+
+    #[test]
+    fn puzzle_of_works() {
+        let at = |month, day, hour| Utc.with_ymd_and_hms(2021, month, day, hour, 0, 0).unwrap();
+        assert_eq!(Ok((2021, 1)), puzzle_of(at(12, 1, 5)));
+        assert_eq!(Ok((2021, 14)), puzzle_of(at(12, 15, 4)));
+        assert_eq!(Ok((2021, 25)), puzzle_of(at(12, 26, 4)));
+        assert!(puzzle_of(at(12, 1, 4)).is_err());
+        assert!(puzzle_of(at(12, 26, 5)).is_err());
+        assert!(puzzle_of(at(7, 10, 12)).is_err());
+
+        let late = Utc.with_ymd_and_hms(2025, 12, 13, 5, 0, 0).unwrap();
+        assert!(puzzle_of(late)
+            .unwrap_err()
+            .contains("ended on December 12"));
+    }
+}
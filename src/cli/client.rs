@@ -0,0 +1,286 @@
+//! A client for the puzzle site, authenticated with the session cookie of a logged in user.
+
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+const USER_AGENT: &str = "github.com/leun4m/aoc by me@leun4m.de";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Talks to the puzzle site at `base_url`
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Downloads the personal input of `year` and `day`
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.request("GET", &url)
+            .call()
+            .map_err(|e| describe(&url, e))?
+            .into_string()
+            .map_err(|e| format!("{url}: {e}"))
+    }
+
+    /// Submits `answer` for `part` of `year` and `day`
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.to_string();
+        self.request("POST", &url)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|e| describe(&url, e))?
+            .into_string()
+            .map(|html| Verdict::parse(&html))
+            .map_err(|e| format!("{url}: {e}"))
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        ureq::request(method, url)
+            .timeout(TIMEOUT)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+/// The reaction of the puzzle site to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint in which direction
+    Wrong,
+    /// Submitted too recently, retry after the duration
+    RateLimited(Duration),
+    /// The part was solved before or is not unlocked yet
+    WrongLevel,
+    /// The response could not be understood, contains its main text
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Interprets the HTML page returned after submitting
+    pub fn parse(html: &str) -> Self {
+        let text = main_text(html);
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::RateLimited(wait_time(&text).unwrap_or(Duration::from_mins(1)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+
+    /// Returns the name used in the submission log
+    pub fn key(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::WrongLevel => "wrong-level",
+            Verdict::Unknown(_) => "unknown",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "That's the right answer!"),
+            Verdict::TooHigh => write!(f, "Wrong, the answer is too high."),
+            Verdict::TooLow => write!(f, "Wrong, the answer is too low."),
+            Verdict::Wrong => write!(f, "Wrong answer."),
+            Verdict::RateLimited(wait) => {
+                write!(f, "Answered too recently, wait {}s.", wait.as_secs())
+            }
+            Verdict::WrongLevel => write!(f, "Not the right level, solved already?"),
+            Verdict::Unknown(text) => write!(f, "Unexpected response: {text}"),
+        }
+    }
+}
+
+/// Returns the text of the `<article>` (or the whole page) without tags and surplus whitespace
+fn main_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(inner, _)| {
+            inner.split_once('>').map_or(inner, |x| x.1)
+        });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Reads a wait time like `You have 1m 23s left to wait`
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (amount, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for part in amount.split_whitespace() {
+        let (number, factor) = if let Some(x) = part.strip_suffix('h') {
+            (x, 3600)
+        } else if let Some(x) = part.strip_suffix('m') {
+            (x, 60)
+        } else {
+            (part.strip_suffix('s')?, 1)
+        };
+        seconds += number.parse::<u64>().ok()? * factor;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+fn describe(url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(404, _) => format!("{url}: not found (is the puzzle unlocked?)"),
+        ureq::Error::Status(400 | 401 | 500, _) => {
+            format!("{url}: rejected, the session token is probably invalid or expired")
+        }
+        ureq::Error::Status(code, _) => format!("{url}: unexpected status {code}"),
+        ureq::Error::Transport(e) => format!("{url}: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Answers a single request with `status` and `body`,
+    /// returns the base URL and the received request (head lines and body)
+    fn serve_once(
+        status: &str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<(Vec<String>, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let status = status.to_string();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(&stream);
+            let mut head = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end().to_string();
+                if line.is_empty() {
+                    break;
+                }
+                head.push(line);
+            }
+            let length = head
+                .iter()
+                .find_map(|x| {
+                    x.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            (head, String::from_utf8(request_body).unwrap())
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn fetch_input_works() {
+        let (url, server) = serve_once("200 OK", "1\n2\n");
+        let input = Client::new(&url, "secret").fetch_input(2021, 5);
+        let (head, _) = server.join().unwrap();
+
+        assert_eq!(Ok("1\n2\n".to_string()), input);
+        assert_eq!("GET /2021/day/5/input HTTP/1.1", head[0]);
+        assert!(head
+            .iter()
+            .any(|x| x == "cookie: session=secret" || x == "Cookie: session=secret"));
+    }
+
+    #[test]
+    fn fetch_input_reports_status() {
+        let (url, server) = serve_once("404 Not Found", "");
+        let result = Client::new(&url, "secret").fetch_input(2021, 5);
+        server.join().unwrap();
+        assert!(result.unwrap_err().contains("not found"));
+    }
+
+    #[test]
+    fn submit_works() {
+        let (url, server) = serve_once(
+            "200 OK",
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let verdict = Client::new(&url, "secret").submit(2021, 5, 2, "42");
+        let (head, body) = server.join().unwrap();
+
+        assert_eq!(Ok(Verdict::Correct), verdict);
+        assert_eq!("POST /2021/day/5/answer HTTP/1.1", head[0]);
+        assert_eq!("level=2&answer=42", body);
+    }
+
+    #[test]
+    fn verdict_parse_works() {
+        let wrong = "<article><p>That's not the right answer; your answer is too high.  \
+            Please wait one minute.</p></article>";
+        assert_eq!(Verdict::TooHigh, Verdict::parse(wrong));
+        assert_eq!(
+            Verdict::TooLow,
+            Verdict::parse(
+                "<article>That's not the right answer; your answer is too low.</article>"
+            )
+        );
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(83)),
+            Verdict::parse("<article><p>You gave an answer too recently. You have 1m 23s left to wait.</p></article>")
+        );
+        assert_eq!(
+            Verdict::WrongLevel,
+            Verdict::parse(
+                "<article><p>You don't seem to be solving the right level.</p></article>"
+            )
+        );
+        assert_eq!(
+            Verdict::Unknown("Hello".to_string()),
+            Verdict::parse("<html><b>Hello</b></html>")
+        );
+    }
+}
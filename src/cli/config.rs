@@ -0,0 +1,553 @@
+//! Local settings of the CLI, read from the optional `aoc.toml` and the environment.
+//!
+//! ```toml
+//! inputs = "../aoc-inputs"                # `~` isn't expanded
+//! answers = "answers.toml"
+//! format = "json"
+//! session_file = ".session"              # or the token itself as `session`
+//! url = "http://localhost:8080"
+//! timeout = 30                            # seconds, fractions as string like "0.5"
+//!
+//! [params.2015-14]
+//! time = 1000
+//! ```
+//!
+//! Settings are layered: the defaults are overridden by the file, the file by the environment
+//! and the environment by the flags of the command line.
+//! The parameters apply to every command solving the day, `--param` only to single days.
+
+use crate::cli::report::Format;
+use crate::cli::toml::{self, Document, Value};
+use aoc::params::Params;
+use aoc::solutions::{self, Entry};
+use clap::ValueEnum;
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// The config file, looked up in the working directory
+pub const CONFIG_FILE: &str = "aoc.toml";
+/// Environment variable overriding the inputs directory of the config file
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+/// The inputs directory if nothing else is configured
+pub const DEFAULT_INPUTS: &str = "inputs";
+/// Environment variable overriding the answers file of the config file
+pub const ANSWERS_ENV: &str = "AOC_ANSWERS";
+/// The answers file if nothing else is configured
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+/// Environment variable overriding the output format of the config file
+pub const FORMAT_ENV: &str = "AOC_FORMAT";
+/// Environment variable overriding the session token of the config file
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable overriding the session token file of the config file
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+/// Environment variable overriding the puzzle site of the config file
+pub const URL_ENV: &str = "AOC_URL";
+/// The puzzle site if nothing else is configured
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+/// Environment variable overriding the timeout of the config file
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+
+/// The keys of the config file outside of a section
+const KEYS: [&str; 7] = [
+    "inputs",
+    "answers",
+    "format",
+    "session",
+    "session_file",
+    "url",
+    "timeout",
+];
+/// The prefix of the sections with the parameters of a day, e.g. `[params.2015-14]`
+const PARAMS_PREFIX: &str = "params.";
+
+/// Where the value of a setting came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Default,
+    File,
+    Env(&'static str),
+    CommandLine,
+}
+
+impl Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Origin::Default => write!(f, "default"),
+            Origin::File => write!(f, "{CONFIG_FILE}"),
+            Origin::Env(name) => write!(f, "${name}"),
+            Origin::CommandLine => write!(f, "command line"),
+        }
+    }
+}
+
+/// The value of a setting together with its origin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub origin: Origin,
+}
+
+impl<T> Setting<T> {
+    fn new(value: T, origin: Origin) -> Self {
+        Self { value, origin }
+    }
+
+    /// Replaces the value by the one given on the command line, if any
+    pub fn overridden(self, explicit: Option<T>) -> Self {
+        match explicit {
+            Some(value) => Self::new(value, Origin::CommandLine),
+            None => self,
+        }
+    }
+}
+
+/// Where the session token of the puzzle site is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Session {
+    Token(String),
+    /// A file containing the token
+    File(PathBuf),
+}
+
+impl Session {
+    /// Returns the token, reading it from its file if necessary
+    pub fn token(&self) -> Result<String, String> {
+        match self {
+            Session::Token(token) => Ok(token.clone()),
+            Session::File(path) => fs::read_to_string(path)
+                .map(|x| x.trim().to_string())
+                .map_err(|e| format!("{}: {e}", path.display())),
+        }
+    }
+}
+
+/// The effective settings, without the flags of the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    pub inputs: Setting<PathBuf>,
+    pub answers: Setting<PathBuf>,
+    pub format: Setting<Format>,
+    pub session: Option<Setting<Session>>,
+    pub url: Setting<String>,
+    pub timeout: Option<Setting<Duration>>,
+    /// The parameter values of single days as year, day, name and value
+    pub params: Vec<(u16, u8, String, String)>,
+}
+
+impl Config {
+    /// Reads [`CONFIG_FILE`] (a missing file counts as empty) and the environment
+    pub fn load() -> Result<Self, String> {
+        let document = match fs::read_to_string(CONFIG_FILE) {
+            Ok(text) => toml::parse(&text).map_err(|e| format!("{CONFIG_FILE}: {e}"))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => Document::default(),
+            Err(e) => return Err(format!("{CONFIG_FILE}: {e}")),
+        };
+        Self::from_sources(&document, |name| env::var(name).ok())
+    }
+
+    fn from_sources(
+        document: &Document,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, String> {
+        let layers = Layers { document, env };
+        layers.check_keys()?;
+
+        Ok(Self {
+            inputs: layers.path("inputs", INPUTS_ENV, DEFAULT_INPUTS)?,
+            answers: layers.path("answers", ANSWERS_ENV, DEFAULT_ANSWERS)?,
+            format: layers
+                .get("format", FORMAT_ENV, format)?
+                .unwrap_or(Setting::new(Format::default(), Origin::Default)),
+            session: layers.session()?,
+            url: layers
+                .get("url", URL_ENV, string)?
+                .unwrap_or(Setting::new(DEFAULT_URL.to_string(), Origin::Default)),
+            timeout: layers.get("timeout", TIMEOUT_ENV, timeout)?,
+            params: params(document)?,
+        })
+    }
+
+    /// Returns the values of `year` and `day` set in the file, overridden by `explicit` ones
+    pub fn params_of(&self, year: u16, day: u8, explicit: &[(String, String)]) -> Params {
+        self.params
+            .iter()
+            .filter(|(y, d, _, _)| (*y, *d) == (year, day))
+            .map(|(_, _, name, value)| (name.clone(), value.clone()))
+            .chain(explicit.iter().cloned())
+            .collect()
+    }
+
+    /// Returns the directory containing the inputs as `<year>/day_<dd>.txt`, `explicit` if given
+    pub fn inputs_root(&self, explicit: Option<&Path>) -> PathBuf {
+        self.inputs
+            .clone()
+            .overridden(explicit.map(Path::to_path_buf))
+            .value
+    }
+
+    /// Returns the file with the expected answers, `explicit` if given
+    pub fn answers_file(&self, explicit: Option<&Path>) -> PathBuf {
+        self.answers
+            .clone()
+            .overridden(explicit.map(Path::to_path_buf))
+            .value
+    }
+
+    /// Returns the output format, `explicit` if given
+    pub fn format_of(&self, explicit: Option<Format>) -> Format {
+        self.format.clone().overridden(explicit).value
+    }
+
+    /// Returns the time after which a day is given up on, `explicit` if given
+    pub fn timeout_of(&self, explicit: Option<Duration>) -> Option<Duration> {
+        explicit.or(self.timeout.as_ref().map(|x| x.value))
+    }
+
+    /// Returns the session token of the puzzle site
+    pub fn session_token(&self) -> Result<String, String> {
+        self.session
+            .as_ref()
+            .ok_or_else(|| {
+                format!(
+                    "No session token given! Set {SESSION_ENV}, {SESSION_FILE_ENV}, `session` or `session_file` in {CONFIG_FILE}"
+                )
+            })?
+            .value
+            .token()
+    }
+
+    /// Checks the values set in the file for each of `entries`, see [`Params::check`]
+    pub fn check_params(&self, entries: &[&Entry]) -> Result<(), String> {
+        for entry in entries {
+            self.params_of(entry.year, entry.day, &[])
+                .check(entry.params())
+                .map_err(|e| format!("{CONFIG_FILE}: {} day {}: {e}", entry.year, entry.day))?;
+        }
+        Ok(())
+    }
+
+    /// Returns every setting as key, displayed value and origin, with the session token masked
+    pub fn entries(&self) -> Vec<(String, String, Origin)> {
+        let mut entries = vec![
+            entry("inputs", self.inputs.value.display(), self.inputs.origin),
+            entry("answers", self.answers.value.display(), self.answers.origin),
+            entry("format", format_name(self.format.value), self.format.origin),
+        ];
+        entries.push(match &self.session {
+            Some(Setting {
+                value: Session::Token(_),
+                origin,
+            }) => entry("session", "<token>", *origin),
+            Some(Setting {
+                value: Session::File(path),
+                origin,
+            }) => entry("session_file", path.display(), *origin),
+            None => entry("session", "(not set)", Origin::Default),
+        });
+        entries.push(entry("url", &self.url.value, self.url.origin));
+        entries.push(match &self.timeout {
+            Some(timeout) => entry("timeout", format!("{:?}", timeout.value), timeout.origin),
+            None => entry("timeout", "(none)", Origin::Default),
+        });
+        for (year, day, name, value) in &self.params {
+            let key = format!("{PARAMS_PREFIX}{year}-{day:02}.{name}");
+            entries.push((key, value.clone(), Origin::File));
+        }
+        entries
+    }
+}
+
+fn entry(key: &str, value: impl Display, origin: Origin) -> (String, String, Origin) {
+    (key.to_string(), value.to_string(), origin)
+}
+
+fn format_name(format: Format) -> String {
+    format
+        .to_possible_value()
+        .map_or_else(String::new, |x| x.get_name().to_string())
+}
+
+/// The file and environment to look up settings in
+struct Layers<'a, E> {
+    document: &'a Document,
+    env: E,
+}
+
+impl<E: Fn(&str) -> Option<String>> Layers<'_, E> {
+    fn check_keys(&self) -> Result<(), String> {
+        for section in &self.document.sections {
+            if section.name.is_empty() {
+                if let Some((key, _)) = section.entries.iter().find(|(x, _)| !KEYS.contains(&&**x))
+                {
+                    return Err(format!(
+                        "{CONFIG_FILE}: unknown key `{key}`, expected one of: {}",
+                        KEYS.join(", ")
+                    ));
+                }
+            } else if !section.name.starts_with(PARAMS_PREFIX) {
+                return Err(format!(
+                    "{CONFIG_FILE}: unknown section [{}], expected [{PARAMS_PREFIX}<year>-<dd>]",
+                    section.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the non-empty environment variable `env_name` or else `key` of the file
+    fn get<T>(
+        &self,
+        key: &str,
+        env_name: &'static str,
+        parse: fn(&Value) -> Result<T, String>,
+    ) -> Result<Option<Setting<T>>, String> {
+        match self.env_value(env_name, parse)? {
+            Some(setting) => Ok(Some(setting)),
+            None => self.file_value(key, parse),
+        }
+    }
+
+    fn env_value<T>(
+        &self,
+        env_name: &'static str,
+        parse: fn(&Value) -> Result<T, String>,
+    ) -> Result<Option<Setting<T>>, String> {
+        match (self.env)(env_name).filter(|x| !x.is_empty()) {
+            Some(value) => parse(&Value::String(value))
+                .map(|x| Some(Setting::new(x, Origin::Env(env_name))))
+                .map_err(|e| format!("${env_name}: {e}")),
+            None => Ok(None),
+        }
+    }
+
+    fn file_value<T>(
+        &self,
+        key: &str,
+        parse: fn(&Value) -> Result<T, String>,
+    ) -> Result<Option<Setting<T>>, String> {
+        match self.document.section("").and_then(|x| x.get(key)) {
+            Some(value) => parse(value)
+                .map(|x| Some(Setting::new(x, Origin::File)))
+                .map_err(|e| format!("{CONFIG_FILE}: `{key}`: {e}")),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the first of [`SESSION_ENV`], [`SESSION_FILE_ENV`], `session` and `session_file`
+    fn session(&self) -> Result<Option<Setting<Session>>, String> {
+        let token = |x: Setting<String>| Setting::new(Session::Token(x.value), x.origin);
+        let file =
+            |x: Setting<String>| Setting::new(Session::File(PathBuf::from(x.value)), x.origin);
+        if let Some(setting) = self.env_value(SESSION_ENV, string)? {
+            return Ok(Some(token(setting)));
+        }
+        if let Some(setting) = self.env_value(SESSION_FILE_ENV, string)? {
+            return Ok(Some(file(setting)));
+        }
+        if let Some(setting) = self.file_value("session", string)? {
+            return Ok(Some(token(setting)));
+        }
+        Ok(self.file_value("session_file", string)?.map(file))
+    }
+
+    fn path(
+        &self,
+        key: &str,
+        env_name: &'static str,
+        default: &str,
+    ) -> Result<Setting<PathBuf>, String> {
+        Ok(self.get(key, env_name, string)?.map_or_else(
+            || Setting::new(PathBuf::from(default), Origin::Default),
+            |x| Setting::new(PathBuf::from(x.value), x.origin),
+        ))
+    }
+}
+
+fn string(value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        other => Err(format!("expected a string, found {}", other.to_toml())),
+    }
+}
+
+fn format(value: &Value) -> Result<Format, String> {
+    let name = string(value)?;
+    Format::from_str(&name, true).map_err(|_| {
+        let names = Format::value_variants()
+            .iter()
+            .map(|x| format_name(*x))
+            .collect::<Vec<_>>();
+        format!("expected one of {}, found {name:?}", names.join(", "))
+    })
+}
+
+fn timeout(value: &Value) -> Result<Duration, String> {
+    match value {
+        Value::Integer(seconds) => parse_timeout(&seconds.to_string()),
+        other => parse_timeout(&string(other)?),
+    }
+}
+
+/// Parses a positive number of seconds, e.g. `--timeout 0.5`
+pub fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite() && *x > 0.0)
+        .map(Duration::from_secs_f64)
+        .ok_or_else(|| format!("Expected a positive number of seconds, got {seconds:?}"))
+}
+
+/// Collects the values of the `[params.<year>-<dd>]` sections, the year may have 2 or 4 digits
+fn params(document: &Document) -> Result<Vec<(u16, u8, String, String)>, String> {
+    let mut params = Vec::new();
+    for section in &document.sections {
+        let Some(name) = section.name.strip_prefix(PARAMS_PREFIX) else {
+            continue;
+        };
+        let (year, day) = name
+            .split_once('-')
+            .and_then(|(year, day)| {
+                Some((
+                    solutions::normalize_year(year.parse().ok()?),
+                    day.parse().ok()?,
+                ))
+            })
+            .ok_or_else(|| {
+                format!(
+                    "{CONFIG_FILE}: expected [{PARAMS_PREFIX}<year>-<dd>], found [{}]",
+                    section.name
+                )
+            })?;
+        for (key, value) in &section.entries {
+            params.push((year, day, key.clone(), value.to_string()));
+        }
+    }
+    Ok(params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(text: &str, env: &[(&str, &str)]) -> Result<Config, String> {
+        let env = env
+            .iter()
+            .map(|(x, y)| ((*x).to_string(), (*y).to_string()))
+            .collect::<Vec<_>>();
+        Config::from_sources(&toml::parse(text).unwrap(), |name| {
+            env.iter().find(|(x, _)| x == name).map(|(_, y)| y.clone())
+        })
+    }
+
+    #[test]
+    fn layers_work() {
+        let defaults = config("", &[]).unwrap();
+        assert_eq!(
+            Setting::new(PathBuf::from(DEFAULT_INPUTS), Origin::Default),
+            defaults.inputs
+        );
+        assert_eq!(None, defaults.timeout);
+        assert_eq!(None, defaults.session);
+
+        let file = "inputs = \"puzzles\"\nformat = \"JSON\"\ntimeout = 2\nsession_file = \"s\"";
+        let config = config(file, &[(INPUTS_ENV, "env"), (TIMEOUT_ENV, "")]).unwrap();
+        assert_eq!(
+            Setting::new(PathBuf::from("env"), Origin::Env(INPUTS_ENV)),
+            config.inputs
+        );
+        assert_eq!(Setting::new(Format::Json, Origin::File), config.format);
+        assert_eq!(
+            Some(Setting::new(Duration::from_secs(2), Origin::File)),
+            config.timeout
+        );
+        assert_eq!(
+            Some(Setting::new(
+                Session::File(PathBuf::from("s")),
+                Origin::File
+            )),
+            config.session
+        );
+        assert_eq!(PathBuf::from("env"), config.inputs_root(None));
+        assert_eq!(Format::Csv, config.format_of(Some(Format::Csv)));
+        assert_eq!(Some(Duration::from_secs(2)), config.timeout_of(None));
+        assert!(defaults.session_token().is_err());
+        assert_eq!(
+            Setting::new(PathBuf::from("cli"), Origin::CommandLine),
+            config.inputs.overridden(Some(PathBuf::from("cli")))
+        );
+    }
+
+    #[test]
+    fn params_work() {
+        let config = config(
+            "[params.2015-14]\ntime = 1000\n\n[params.16-08]\nwidth = 7",
+            &[],
+        )
+        .unwrap();
+        let explicit = [(String::from("time"), String::from("5"))];
+        let expected = [(String::from("time"), String::from("5"))]
+            .into_iter()
+            .collect::<Params>();
+        assert_eq!(expected, config.params_of(2015, 14, &explicit));
+        assert_eq!(
+            [(String::from("time"), String::from("1000"))]
+                .into_iter()
+                .collect::<Params>(),
+            config.params_of(2015, 14, &[])
+        );
+        assert_eq!(Params::default(), config.params_of(2015, 7, &[]));
+        // 2-digit years are read like `--year`
+        assert_eq!(
+            [(String::from("width"), String::from("7"))]
+                .into_iter()
+                .collect::<Params>(),
+            config.params_of(2016, 8, &[])
+        );
+
+        let entry = solutions::find(2015, 14).unwrap();
+        assert_eq!(Ok(()), config.check_params(&[entry]));
+        let invalid = self::config("[params.2015-14]\nspeed = 1", &[]).unwrap();
+        assert!(invalid.check_params(&[entry]).is_err());
+    }
+
+    #[test]
+    fn entries_work() {
+        let config = config(
+            "session = \"secret\"\ntimeout = 3\n[params.2015-07]\nwire = \"d\"",
+            &[(FORMAT_ENV, "csv")],
+        )
+        .unwrap();
+        let entries = config.entries();
+        assert!(entries.contains(&entry("format", "csv", Origin::Env(FORMAT_ENV))));
+        assert!(entries.contains(&entry("session", "<token>", Origin::File)));
+        assert!(entries.contains(&entry("timeout", "3s", Origin::File)));
+        assert!(entries.contains(&entry("params.2015-07.wire", "d", Origin::File)));
+        assert!(entries
+            .iter()
+            .all(|(_, value, _)| !value.contains("secret")));
+    }
+
+    #[test]
+    fn invalid_config_is_rejected() {
+        assert!(config("input = \"x\"", &[]).is_err());
+        assert!(config("inputs = 1", &[]).is_err());
+        assert!(config("format = \"xml\"", &[]).is_err());
+        assert!(config("timeout = 0", &[]).is_err());
+        assert!(config("[2015-14]", &[]).is_err());
+        assert!(config("[params.2015]", &[]).is_err());
+        assert!(config("", &[(TIMEOUT_ENV, "soon")]).is_err());
+        assert_eq!(
+            Duration::from_millis(500),
+            config("timeout = \"0.5\"", &[])
+                .unwrap()
+                .timeout
+                .unwrap()
+                .value
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! The local history of benchmark results, used to detect performance regressions.
+//!
+//! Every labeled run stores the median of each phase in nanoseconds
+//! and when it was recorded in seconds since the Unix epoch, one section per run label and day:
+//!
+//! ```toml
+//! [before-refactor/2015-06]
+//! parse = 4604400
+//! part_one = 100
+//! part_two = 100
+//! recorded = 1734159600
+//! ```
+
+use crate::cli::bench::Phase;
+use crate::cli::toml::{self, Document, Value};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The key of the time a section was recorded at
+const RECORDED: &str = "recorded";
+
+/// Benchmark medians per run label, year, day and phase
+#[derive(Debug, Default)]
+pub struct History {
+    document: Document,
+}
+
+impl History {
+    /// Reads the history from `path`, a missing file counts as empty
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => toml::parse(&text)
+                .map(|document| Self { document })
+                .map_err(|e| format!("{}: {e}", path.display())),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {e}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.document.to_string())
+    }
+
+    /// Returns all run labels ordered by when they were last recorded, oldest first.
+    ///
+    /// Labels without a recording time (from older histories) come first, ties keep the order of the file.
+    pub fn labels(&self) -> Vec<&str> {
+        let mut labels: Vec<(&str, i64)> = Vec::new();
+        for section in &self.document.sections {
+            let Some((label, _)) = section.name.rsplit_once('/') else {
+                continue;
+            };
+            let recorded = match section.get(RECORDED) {
+                Some(Value::Integer(x)) => *x,
+                _ => 0,
+            };
+            match labels.iter_mut().find(|(x, _)| *x == label) {
+                Some((_, latest)) => *latest = recorded.max(*latest),
+                None => labels.push((label, recorded)),
+            }
+        }
+        labels.sort_by_key(|(_, recorded)| *recorded);
+        labels.into_iter().map(|(label, _)| label).collect()
+    }
+
+    /// Returns the stored median of `phase` in microseconds
+    pub fn get(&self, label: &str, year: u16, day: u8, phase: Phase) -> Option<f64> {
+        match self
+            .document
+            .section(&section_name(label, year, day))?
+            .get(phase.key())?
+        {
+            Value::Integer(nanos) => Some(*nanos as f64 / 1000.0),
+            _ => None,
+        }
+    }
+
+    /// Stores the median of `phase` (in microseconds) with the current time, replacing an older value of the same run
+    pub fn record(&mut self, label: &str, year: u16, day: u8, phase: Phase, median: f64) {
+        let nanos = (median * 1000.0).round() as i64;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |x| x.as_secs() as i64);
+        let section = self.document.section_mut(&section_name(label, year, day));
+        section.set(phase.key(), Value::Integer(nanos));
+        section.set(RECORDED, Value::Integer(now));
+    }
+}
+
+fn section_name(label: &str, year: u16, day: u8) -> String {
+    format!("{label}/{year}-{day:02}")
+}
+
+/// Checks that `label` can be used as part of a section name
+pub fn validate_label(label: &str) -> Result<(), String> {
+    if label.is_empty() || label.contains(['[', ']', '/', '#', '\n']) {
+        Err(format!(
+            "invalid run label {label:?}: must not be empty or contain [ ] / #"
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `true` if `current` is slower than `baseline` by more than `threshold` percent
+/// and at least `min_delta` microseconds, which filters out noise of very fast phases
+pub fn is_regression(baseline: f64, current: f64, threshold: f64, min_delta: f64) -> bool {
+    current - baseline >= min_delta && current > baseline * (1.0 + threshold / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_works() {
+        let mut history = History::default();
+        history.record("a", 2015, 6, Phase::Parse, 4604.4);
+        history.record("b", 2015, 6, Phase::Parse, 10.0);
+        history.record("a", 2015, 6, Phase::PartOne, 0.1);
+
+        assert_eq!(vec!["a", "b"], history.labels());
+        assert_eq!(Some(4604.4), history.get("a", 2015, 6, Phase::Parse));
+        assert_eq!(Some(0.1), history.get("a", 2015, 6, Phase::PartOne));
+        assert_eq!(None, history.get("b", 2015, 6, Phase::PartOne));
+    }
+
+    #[test]
+    fn labels_are_ordered_by_recording() {
+        let mut history = History::default();
+        history.record("b", 2015, 6, Phase::Parse, 1.0);
+        history.record("a", 2015, 6, Phase::Parse, 1.0);
+        history.record("a", 2015, 7, Phase::Parse, 1.0);
+        assert_eq!(vec!["b", "a"], history.labels());
+
+        history
+            .document
+            .section_mut("b/2015-06")
+            .set(RECORDED, Value::Integer(i64::MAX));
+        assert_eq!(vec!["a", "b"], history.labels());
+
+        history
+            .document
+            .section_mut("old/2015-06")
+            .set("parse", Value::Integer(1));
+        assert_eq!(vec!["old", "a", "b"], history.labels());
+    }
+
+    #[test]
+    fn is_regression_works() {
+        assert!(is_regression(100.0, 120.0, 10.0, 5.0));
+        assert!(!is_regression(100.0, 105.0, 10.0, 1.0));
+        assert!(!is_regression(0.1, 0.5, 10.0, 5.0));
+        assert!(!is_regression(100.0, 50.0, 10.0, 5.0));
+    }
+
+    #[test]
+    fn validate_label_works() {
+        assert!(validate_label("before-refactor").is_ok());
+        assert!(validate_label("").is_err());
+        assert!(validate_label("a/b").is_err());
+    }
+}
@@ -0,0 +1,427 @@
+//! Analysis of the JSON export of a private leaderboard.
+//!
+//! The export contains every member with the Unix time of each star (`get_star_ts`),
+//! from which the completion times since the unlock of the puzzle are calculated.
+
+use crate::cli::calendar;
+use crate::cli::table::{Align, Table};
+use aoc::json::{self, Json};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// A parsed leaderboard export
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub members: Vec<Member>,
+}
+
+/// A member of the leaderboard with the Unix times of the stars per day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub id: i64,
+    pub name: String,
+    pub local_score: i64,
+    pub stars: i64,
+    /// The times of part one and two (if solved) per day
+    pub days: BTreeMap<u8, (i64, Option<i64>)>,
+}
+
+/// The completion times of a member on a day, in seconds since the unlock of the puzzle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayTimes {
+    pub day: u8,
+    pub part_one: i64,
+    pub part_two: Option<i64>,
+}
+
+impl DayTimes {
+    /// Returns the time between the two stars
+    pub fn delta(&self) -> Option<i64> {
+        self.part_two.map(|x| x - self.part_one)
+    }
+}
+
+/// Consecutive days with both stars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Streak {
+    /// The streak up to the latest day of the leaderboard
+    pub current: u8,
+    pub longest: u8,
+}
+
+/// A member with the rank on the leaderboard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing<'a> {
+    pub rank: usize,
+    pub member: &'a Member,
+    pub streak: Streak,
+}
+
+impl Leaderboard {
+    /// Parses the export of `/<year>/leaderboard/private/view/<id>.json`
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let root = json::parse(text).map_err(|e| e.to_string())?;
+        let event = field(&root, "event", "")?;
+        let event = event
+            .as_str()
+            .and_then(|x| x.parse().ok())
+            .or_else(|| event.as_i64().and_then(|x| u16::try_from(x).ok()))
+            .ok_or("`event`: expected a year")?;
+        let members = field(&root, "members", "")?
+            .as_object()
+            .ok_or("`members`: expected an object")?
+            .iter()
+            .map(|(id, member)| Member::parse(member, &format!("members.{id}")))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { event, members })
+    }
+
+    /// Returns the members ordered by local score, stars and time of the last star
+    pub fn standings(&self) -> Vec<Standing<'_>> {
+        let last_day = self
+            .members
+            .iter()
+            .filter_map(|x| x.days.keys().last())
+            .max()
+            .copied()
+            .unwrap_or(0);
+        let mut members = self.members.iter().collect::<Vec<_>>();
+        members.sort_by_key(|x| (-x.local_score, -x.stars, x.last_star(), x.name.clone()));
+        members
+            .iter()
+            .map(|member| Standing {
+                rank: 1 + members
+                    .iter()
+                    .filter(|x| x.local_score > member.local_score)
+                    .count(),
+                member,
+                streak: member.streak(last_day),
+            })
+            .collect()
+    }
+
+    /// Returns the completion times of `member` per day
+    pub fn times(&self, member: &Member) -> Vec<DayTimes> {
+        member
+            .days
+            .iter()
+            .filter_map(|(&day, &(part_one, part_two))| {
+                let release = calendar::release_time(self.event, day)?.timestamp();
+                Some(DayTimes {
+                    day,
+                    part_one: (part_one - release).max(0),
+                    part_two: part_two.map(|x| (x - release).max(0)),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Member {
+    fn parse(json: &Json, path: &str) -> Result<Self, String> {
+        let integer = |key| {
+            field(json, key, path)?
+                .as_i64()
+                .ok_or(format!("`{path}.{key}`: expected an integer"))
+        };
+        let id = integer("id")?;
+        let name = match field(json, "name", path)? {
+            Json::Null => format!("(anonymous user #{id})"),
+            name => name
+                .as_str()
+                .ok_or(format!("`{path}.name`: expected a string"))?
+                .to_string(),
+        };
+
+        let mut days = BTreeMap::new();
+        let levels = field(json, "completion_day_level", path)?
+            .as_object()
+            .ok_or(format!("`{path}.completion_day_level`: expected an object"))?;
+        for (day, parts) in levels {
+            let path = format!("{path}.completion_day_level.{day}");
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("`{path}`: expected a day"))?;
+            let star = |part| {
+                parts
+                    .get(part)
+                    .map(|x| {
+                        field(x, "get_star_ts", &format!("{path}.{part}"))?
+                            .as_i64()
+                            .ok_or(format!("`{path}.{part}.get_star_ts`: expected an integer"))
+                    })
+                    .transpose()
+            };
+            let part_one = star("1")?.ok_or(format!("`{path}`: missing part 1"))?;
+            days.insert(day, (part_one, star("2")?));
+        }
+
+        Ok(Self {
+            id,
+            name,
+            local_score: integer("local_score")?,
+            stars: integer("stars")?,
+            days,
+        })
+    }
+
+    fn last_star(&self) -> i64 {
+        self.days
+            .values()
+            .map(|&(one, two)| two.unwrap_or(one))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the streaks of days with both stars, the current one ending on `last_day`
+    fn streak(&self, last_day: u8) -> Streak {
+        let mut streak = Streak::default();
+        let mut run = 0;
+        for day in 1..=last_day {
+            run = match self.days.get(&day) {
+                Some((_, Some(_))) => run + 1,
+                _ => 0,
+            };
+            streak.longest = streak.longest.max(run);
+        }
+        streak.current = run;
+        streak
+    }
+}
+
+/// Returns `key` of the object `json`, `path` naming the object in errors
+fn field<'a>(json: &'a Json, key: &str, path: &str) -> Result<&'a Json, String> {
+    json.get(key).ok_or_else(|| {
+        if path.is_empty() {
+            format!("missing `{key}`")
+        } else {
+            format!("`{path}`: missing `{key}`")
+        }
+    })
+}
+
+/// Formats seconds as `hh:mm:ss`, prefixed by the days if longer
+pub fn format_seconds(seconds: i64) -> String {
+    let (days, rest) = (seconds / 86_400, seconds % 86_400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        rest / 3600,
+        rest % 3600 / 60,
+        rest % 60
+    );
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/// Renders the standings
+pub fn ranking_table(leaderboard: &Leaderboard) -> Table {
+    let mut table = Table::new(&[
+        ("Rank", Align::Right),
+        ("Name", Align::Left),
+        ("Score", Align::Right),
+        ("Stars", Align::Right),
+        ("Streak", Align::Right),
+        ("Longest", Align::Right),
+    ]);
+    for standing in leaderboard.standings() {
+        table.add_row(vec![
+            standing.rank.to_string(),
+            standing.member.name.clone(),
+            standing.member.local_score.to_string(),
+            standing.member.stars.to_string(),
+            standing.streak.current.to_string(),
+            standing.streak.longest.to_string(),
+        ]);
+    }
+    table
+}
+
+/// Renders the completion times of every member, by day and the time of part two
+pub fn times_table(leaderboard: &Leaderboard) -> Table {
+    let mut rows = leaderboard
+        .members
+        .iter()
+        .flat_map(|member| {
+            leaderboard
+                .times(member)
+                .into_iter()
+                .map(move |times| (times, member))
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by_key(|(times, member)| {
+        (
+            times.day,
+            times.part_two.is_none(),
+            times.part_two,
+            times.part_one,
+            member.name.clone(),
+        )
+    });
+
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Name", Align::Left),
+        ("Part 1", Align::Right),
+        ("Part 2", Align::Right),
+        ("Delta", Align::Right),
+    ]);
+    for (times, member) in rows {
+        table.add_row(vec![
+            times.day.to_string(),
+            member.name.clone(),
+            format_seconds(times.part_one),
+            times.part_two.map(format_seconds).unwrap_or_default(),
+            times.delta().map(format_seconds).unwrap_or_default(),
+        ]);
+    }
+    table
+}
+
+/// Renders the standings with the completion times in seconds, one member per line
+pub fn to_json(leaderboard: &Leaderboard) -> String {
+    let members = leaderboard
+        .standings()
+        .into_iter()
+        .map(|standing| {
+            let days = leaderboard
+                .times(standing.member)
+                .into_iter()
+                .map(|times| {
+                    Json::Object(vec![
+                        (String::from("day"), i64::from(times.day).into()),
+                        (String::from("part_one_s"), times.part_one.into()),
+                        (String::from("part_two_s"), times.part_two.into()),
+                        (String::from("delta_s"), times.delta().into()),
+                    ])
+                })
+                .collect();
+            Json::Object(vec![
+                (String::from("rank"), (standing.rank as i64).into()),
+                (String::from("id"), standing.member.id.into()),
+                (String::from("name"), standing.member.name.as_str().into()),
+                (
+                    String::from("local_score"),
+                    standing.member.local_score.into(),
+                ),
+                (String::from("stars"), standing.member.stars.into()),
+                (
+                    String::from("streak"),
+                    i64::from(standing.streak.current).into(),
+                ),
+                (
+                    String::from("longest_streak"),
+                    i64::from(standing.streak.longest).into(),
+                ),
+                (String::from("days"), Json::Array(days)),
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    let mut json = format!("{{\"event\": {}, \"members\": [", leaderboard.event);
+    for (idx, member) in members.iter().enumerate() {
+        let separator = if idx == 0 { "" } else { "," };
+        write!(json, "{separator}\n  {member}").unwrap();
+    }
+    if !members.is_empty() {
+        json.push('\n');
+    }
+    json.push_str("]}\n");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2021 day 1 unlocked at 1638334800, day 2 at 1638421200
+    const EXPORT: &str = r#"{
+  "owner_id": 1,
+  "event": "2021",
+  "members": {
+    "1": {"id": 1, "name": "Ada", "stars": 4, "local_score": 10, "global_score": 0, "last_star_ts": 1638421800,
+          "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1638334860, "star_index": 1}, "2": {"get_star_ts": 1638335100, "star_index": 2}},
+            "2": {"1": {"get_star_ts": 1638421500, "star_index": 5}, "2": {"get_star_ts": 1638421800, "star_index": 6}}
+          }},
+    "2": {"id": 2, "name": null, "stars": 3, "local_score": 10, "global_score": 0, "last_star_ts": 1638508000,
+          "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1638334900, "star_index": 3}},
+            "2": {"1": {"get_star_ts": 1638421600, "star_index": 7}, "2": {"get_star_ts": 1638508000, "star_index": 8}}
+          }},
+    "3": {"id": 3, "name": "Eve", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+          "completion_day_level": {}}
+  }
+}"#;
+
+    #[test]
+    fn parse_works() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        assert_eq!(2021, leaderboard.event);
+        assert_eq!(3, leaderboard.members.len());
+        assert_eq!("(anonymous user #2)", leaderboard.members[1].name);
+        assert_eq!(
+            Some(&(1_638_334_900, None)),
+            leaderboard.members[1].days.get(&1)
+        );
+
+        assert!(Leaderboard::parse("{\"event\": \"2021\"}").is_err());
+        let error = Leaderboard::parse(&EXPORT.replace("\"stars\": 4", "\"stars\": \"4\""));
+        assert_eq!(
+            Err(String::from("`members.1.stars`: expected an integer")),
+            error
+        );
+    }
+
+    #[test]
+    fn standings_work() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        let standings = leaderboard.standings();
+        let ranks = standings
+            .iter()
+            .map(|x| (x.rank, x.member.id, x.streak.current, x.streak.longest))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(1, 1, 2, 2), (1, 2, 1, 1), (3, 3, 0, 0)], ranks);
+    }
+
+    #[test]
+    fn times_work() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        let times = leaderboard.times(&leaderboard.members[0]);
+        assert_eq!(
+            DayTimes {
+                day: 1,
+                part_one: 60,
+                part_two: Some(300),
+            },
+            times[0]
+        );
+        assert_eq!(Some(240), times[0].delta());
+        assert_eq!(
+            Some(86_400),
+            leaderboard.times(&leaderboard.members[1])[1].delta()
+        );
+    }
+
+    #[test]
+    fn format_seconds_works() {
+        assert_eq!("00:01:05", format_seconds(65));
+        assert_eq!("1d 01:00:00", format_seconds(90_000));
+    }
+
+    #[test]
+    fn to_json_works() {
+        let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+        let json = to_json(&leaderboard);
+        assert!(json.starts_with(
+            "{\"event\": 2021, \"members\": [\n  {\"rank\": 1, \"id\": 1, \"name\": \"Ada\""
+        ));
+        assert!(json.contains(
+            "{\"day\": 1, \"part_one_s\": 100, \"part_two_s\": null, \"delta_s\": null}"
+        ));
+        assert!(json::parse(&json).is_ok());
+    }
+}
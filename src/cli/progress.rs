@@ -0,0 +1,162 @@
+//! Progress per year, computed from the registered days.
+//!
+//! The table of README.md is kept between [`START_MARKER`] and [`END_MARKER`] and rewritten by `aoc progress --readme`.
+
+use crate::cli::calendar;
+use crate::cli::table::{Align, Table};
+use aoc::solutions::Entry;
+use std::fmt::Write;
+
+/// The line after which the progress table starts
+pub const START_MARKER: &str = "<!-- progress:start -->";
+/// The line before which the progress table ends
+pub const END_MARKER: &str = "<!-- progress:end -->";
+
+/// The registered days of a year
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearProgress {
+    pub year: u16,
+    pub days: Vec<u8>,
+}
+
+impl YearProgress {
+    /// Returns the number of puzzles of the year
+    pub fn total(&self) -> u8 {
+        calendar::days_of_year(self.year)
+    }
+
+    /// Returns the share of registered days in percent, rounded
+    pub fn percent(&self) -> usize {
+        let total = usize::from(self.total()).max(1);
+        (self.days.len() * 100 + total / 2) / total
+    }
+}
+
+/// Groups the registered days by year, `entries` are expected ordered by year and day
+pub fn of<'a>(entries: impl IntoIterator<Item = &'a Entry>) -> Vec<YearProgress> {
+    let mut years: Vec<YearProgress> = Vec::new();
+    for entry in entries {
+        match years.last_mut() {
+            Some(last) if last.year == entry.year => last.days.push(entry.day),
+            _ => years.push(YearProgress {
+                year: entry.year,
+                days: vec![entry.day],
+            }),
+        }
+    }
+    years
+}
+
+/// Renders the Markdown table of README.md
+pub fn table(years: &[YearProgress]) -> Table {
+    let mut table = Table::new(&[
+        ("Year", Align::Left),
+        ("Progress", Align::Right),
+        ("Days", Align::Right),
+    ]);
+    for year in years {
+        table.add_row(vec![
+            year.year.to_string(),
+            format!("{} %", year.percent()),
+            format!("{:>2} / {}", year.days.len(), year.total()),
+        ]);
+    }
+    table
+}
+
+/// Renders one row per year with a `*` for every registered day and a `.` for every other puzzle
+pub fn grid(years: &[YearProgress]) -> String {
+    let width = years.iter().map(YearProgress::total).max().unwrap_or(0);
+    let mut grid = String::from("    ");
+    for day in 1..=width {
+        write!(grid, " {day:>2}").unwrap();
+    }
+    for year in years {
+        write!(grid, "\n{}", year.year).unwrap();
+        for day in 1..=year.total() {
+            let star = if year.days.contains(&day) { '*' } else { '.' };
+            write!(grid, "  {star}").unwrap();
+        }
+    }
+    grid
+}
+
+/// Replaces the lines between [`START_MARKER`] and [`END_MARKER`] in `text` with `section`
+pub fn replace_section(text: &str, section: &str) -> Result<String, String> {
+    let missing = |marker| format!("Missing the marker {marker:?}");
+    let start = text
+        .find(START_MARKER)
+        .ok_or_else(|| missing(START_MARKER))?
+        + START_MARKER.len();
+    let end = text[start..]
+        .find(END_MARKER)
+        .ok_or_else(|| missing(END_MARKER))?
+        + start;
+    Ok(format!(
+        "{}\n\n{}\n\n{}",
+        &text[..start],
+        section.trim_end(),
+        &text[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn years() -> Vec<YearProgress> {
+        vec![
+            YearProgress {
+                year: 2015,
+                days: (1..=21).collect(),
+            },
+            YearProgress {
+                year: 2025,
+                days: vec![1, 3],
+            },
+        ]
+    }
+
+    #[test]
+    fn of_works() {
+        let progress = of(aoc::solutions::all());
+        assert_eq!(
+            aoc::solutions::all().count(),
+            progress.iter().map(|x| x.days.len()).sum::<usize>()
+        );
+        assert!(progress.windows(2).all(|x| x[0].year < x[1].year));
+    }
+
+    #[test]
+    fn table_works() {
+        assert_eq!(
+            "| Year | Progress |    Days |
+| ---- | -------: | ------: |
+| 2015 |     84 % | 21 / 25 |
+| 2025 |     17 % |  2 / 12 |
+",
+            table(&years()).to_string()
+        );
+    }
+
+    #[test]
+    fn grid_works() {
+        let grid = grid(&years());
+        let lines = grid.lines().collect::<Vec<_>>();
+        assert_eq!(3, lines.len());
+        assert!(lines[0].ends_with("24 25"));
+        assert_eq!(4 + 25 * 3, lines[1].len());
+        assert_eq!("2025  *  .  *  .  .  .  .  .  .  .  .  .", lines[2]);
+    }
+
+    #[test]
+    fn replace_section_works() {
+        let text = format!("# aoc\n\n{START_MARKER}\nold\n{END_MARKER}\n\n## Usage\n");
+        assert_eq!(
+            format!("# aoc\n\n{START_MARKER}\n\nnew\n\n{END_MARKER}\n\n## Usage\n"),
+            replace_section(&text, "new\n").unwrap()
+        );
+        assert!(replace_section("# aoc\n", "new").is_err());
+        assert!(replace_section(&format!("{START_MARKER}\n"), "new").is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Machine readable output of results: one record per year, day and part as JSON or CSV.
+
+use aoc::json::Json;
+use aoc::runner::{DayResult, Outcome};
+use aoc::solutions::{Answer, Answers, Parts, Timings};
+use clap::ValueEnum;
+use std::time::Duration;
+
+/// The output format of the CLI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable tables and lines
+    #[default]
+    Text,
+    /// An array with one object per year, day and part
+    Json,
+    /// One row per year, day and part, with a header row
+    Csv,
+}
+
+/// The result of one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Where the input came from
+    pub input: String,
+    pub answer: Option<Answer>,
+    /// What happened, e.g. `solved`, `missing-input`, `timeout` or `pass`
+    pub status: String,
+    /// Time spent on calculating the part
+    pub duration: Duration,
+    /// Time spent on parsing the input, shared by both parts
+    pub parse: Duration,
+    /// Details like the reason of a failure
+    pub message: Option<String>,
+}
+
+impl Record {
+    /// Creates the records of the calculated `parts` of a solved day,
+    /// with the status `solved` or `unsolved`
+    pub fn solved(
+        year: u16,
+        day: u8,
+        input: &str,
+        parts: Parts,
+        answers: &Answers,
+        timings: &Timings,
+    ) -> Vec<Self> {
+        [
+            (1, &answers.part_one, timings.part_one),
+            (2, &answers.part_two, timings.part_two),
+        ]
+        .into_iter()
+        .filter(|(part, _, _)| parts.includes(*part))
+        .map(|(part, answer, duration)| Self {
+            year,
+            day,
+            part,
+            input: input.to_string(),
+            answer: Some(answer.clone()),
+            status: String::from(if *answer == Answer::Unsolved {
+                "unsolved"
+            } else {
+                "solved"
+            }),
+            duration,
+            parse: timings.parse,
+            message: None,
+        })
+        .collect()
+    }
+
+    /// Creates the records of the `parts` of a day that could not be solved
+    pub fn skipped(
+        year: u16,
+        day: u8,
+        input: &str,
+        parts: Parts,
+        status: &str,
+        message: &str,
+    ) -> Vec<Self> {
+        [1, 2]
+            .into_iter()
+            .filter(|part| parts.includes(*part))
+            .map(|part| Self {
+                year,
+                day,
+                part,
+                input: input.to_string(),
+                answer: None,
+                status: status.to_string(),
+                duration: Duration::ZERO,
+                parse: Duration::ZERO,
+                message: Some(message.to_string()),
+            })
+            .collect()
+    }
+
+    /// Creates the records of the calculated parts of a batch run day
+    pub fn of_result(result: &DayResult) -> Vec<Self> {
+        let (year, day, parts) = (result.entry.year, result.entry.day, result.parts);
+        let input = result.input.display().to_string();
+        let skipped =
+            |status, message: &str| Self::skipped(year, day, &input, parts, status, message);
+        match &result.outcome {
+            Outcome::Solved(answers) => {
+                Self::solved(year, day, &input, parts, answers, &result.timings)
+            }
+            Outcome::MissingInput(_) => skipped("missing-input", "input not found"),
+            Outcome::Invalid(error) => skipped("invalid-input", &error.to_string()),
+            Outcome::TimedOut(timeout) => {
+                skipped("timeout", &format!("no answer within {timeout:?}"))
+            }
+            Outcome::Failed(message) => skipped("failed", message),
+        }
+    }
+
+    fn answer_type(&self) -> Option<&'static str> {
+        self.answer.as_ref().map(|answer| match answer {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+            Answer::Art(_) => "art",
+            Answer::Unsolved => "unsolved",
+        })
+    }
+
+    fn answer_text(&self) -> Option<String> {
+        self.answer
+            .as_ref()
+            .filter(|x| **x != Answer::Unsolved)
+            .map(ToString::to_string)
+    }
+}
+
+/// Renders `records` as a JSON array, one object per line.
+///
+/// Number answers beyond the integers `f64` holds exactly are written as strings, so no digit gets lost.
+pub fn to_json(records: &[Record]) -> String {
+    let objects = records
+        .iter()
+        .map(|record| {
+            let answer = match &record.answer {
+                Some(Answer::Number(x)) if x.unsigned_abs() < 1 << f64::MANTISSA_DIGITS => {
+                    Json::Number(*x as f64)
+                }
+                _ => record.answer_text().as_deref().into(),
+            };
+            Json::Object(vec![
+                (String::from("year"), i64::from(record.year).into()),
+                (String::from("day"), i64::from(record.day).into()),
+                (String::from("part"), i64::from(record.part).into()),
+                (String::from("input"), record.input.as_str().into()),
+                (String::from("answer"), answer),
+                (String::from("type"), record.answer_type().into()),
+                (String::from("status"), record.status.as_str().into()),
+                (String::from("duration_ms"), json_millis(record.duration)),
+                (String::from("parse_ms"), json_millis(record.parse)),
+                (String::from("message"), record.message.as_deref().into()),
+            ])
+        })
+        .map(|object| object.to_string())
+        .collect::<Vec<_>>();
+
+    if objects.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n  {}\n]\n", objects.join(",\n  "))
+    }
+}
+
+/// Renders `records` as CSV with a header row, empty cells stand for missing values
+pub fn to_csv(records: &[Record]) -> String {
+    let mut csv =
+        String::from("year,day,part,input,answer,type,status,duration_ms,parse_ms,message\n");
+    for record in records {
+        let cells = [
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            csv_cell(&record.input),
+            csv_cell(&record.answer_text().unwrap_or_default()),
+            record.answer_type().unwrap_or_default().to_string(),
+            csv_cell(&record.status),
+            millis(record.duration),
+            millis(record.parse),
+            csv_cell(record.message.as_deref().unwrap_or_default()),
+        ];
+        csv.push_str(&cells.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// The duration in milliseconds, rounded to microseconds like the CSV cells
+fn json_millis(duration: Duration) -> Json {
+    Json::Number((duration.as_secs_f64() * 1_000_000.0).round() / 1000.0)
+}
+
+fn csv_cell(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records() -> Vec<Record> {
+        let answers = Answers {
+            part_one: Answer::Number(42),
+            part_two: Answer::Art("#.\n\"#".to_string()),
+        };
+        let timings = Timings {
+            parse: Duration::from_micros(1500),
+            part_one: Duration::from_micros(250),
+            part_two: Duration::ZERO,
+        };
+        let mut records = Record::solved(2016, 8, "in.txt", Parts::Both, &answers, &timings);
+        records.extend(Record::skipped(
+            2016,
+            9,
+            "x",
+            Parts::Both,
+            "failed",
+            "boom, \"bad\"",
+        ));
+        records
+    }
+
+    #[test]
+    fn to_json_works() {
+        assert_eq!(
+            "[\n  \
+             {\"year\": 2016, \"day\": 8, \"part\": 1, \"input\": \"in.txt\", \"answer\": 42, \"type\": \"number\", \"status\": \"solved\", \"duration_ms\": 0.25, \"parse_ms\": 1.5, \"message\": null},\n  \
+             {\"year\": 2016, \"day\": 8, \"part\": 2, \"input\": \"in.txt\", \"answer\": \"#.\\n\\\"#\", \"type\": \"art\", \"status\": \"solved\", \"duration_ms\": 0, \"parse_ms\": 1.5, \"message\": null},\n  \
+             {\"year\": 2016, \"day\": 9, \"part\": 1, \"input\": \"x\", \"answer\": null, \"type\": null, \"status\": \"failed\", \"duration_ms\": 0, \"parse_ms\": 0, \"message\": \"boom, \\\"bad\\\"\"},\n  \
+             {\"year\": 2016, \"day\": 9, \"part\": 2, \"input\": \"x\", \"answer\": null, \"type\": null, \"status\": \"failed\", \"duration_ms\": 0, \"parse_ms\": 0, \"message\": \"boom, \\\"bad\\\"\"}\n]\n",
+            to_json(&records())
+        );
+        assert_eq!("[]\n", to_json(&[]));
+
+        let mut record = records().remove(0);
+        record.answer = Some(Answer::Number(1 << 60));
+        assert!(to_json(&[record]).contains("\"answer\": \"1152921504606846976\""));
+    }
+
+    #[test]
+    fn to_csv_works() {
+        assert_eq!(
+            "year,day,part,input,answer,type,status,duration_ms,parse_ms,message\n\
+             2016,8,1,in.txt,42,number,solved,0.250,1.500,\n\
+             2016,8,2,in.txt,\"#.\n\"\"#\",art,solved,0.000,1.500,\n\
+             2016,9,1,x,,,failed,0.000,0.000,\"boom, \"\"bad\"\"\"\n\
+             2016,9,2,x,,,failed,0.000,0.000,\"boom, \"\"bad\"\"\"\n",
+            to_csv(&records())
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Generates the module of a new day and registers it in its year (and the year in `solutions.rs`).
+
+use crate::cli::calendar;
+use aoc::runner;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The example file of a new day, see `tests/examples.rs`
+const EXAMPLE_TEMPLATE: &str = "\
+# The expected answers of the example, a part without answer is not checked.
+# Other keys set parameters of the day, e.g. `time = 1000`.
+# part_one =
+# part_two =
+---
+";
+
+/// Maximal line width of the rewritten `years!` list, as rustfmt would wrap it
+const MAX_WIDTH: usize = 100;
+
+/// Creates the day module of `year` and `day` below `root` (usually `src/solutions`)
+/// and registers it, creating the year module if necessary.
+/// An empty example file is added below `examples` (usually `tests/examples`) unless there is one.
+///
+/// Nothing is written if the day exists already. Returns the created or changed files.
+pub fn create(root: &Path, examples: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if calendar::release_time(year, day).is_none() {
+        return Err(format!("There is no puzzle on day {day} of {year}"));
+    }
+
+    let solutions_file = root.with_extension("rs");
+    let year_file = root.join(format!("year_{year}.rs"));
+    let day_file = day_path(root, year, day);
+    if day_file.exists() {
+        return Err(format!("{} exists already", day_file.display()));
+    }
+
+    let mut changes = Vec::new();
+    if year_file.exists() {
+        let registered = register_day(&read(&year_file)?, day)?;
+        changes.push((year_file, registered));
+    } else {
+        let solutions = register_year(&read(&solutions_file)?, year)?;
+        changes.push((solutions_file, solutions));
+        changes.push((year_file, year_template(year, day)));
+    }
+    changes.push((day_file, day_template()));
+    let example_file = runner::input_path(examples, year, day);
+    if !example_file.exists() {
+        changes.push((example_file, String::from(EXAMPLE_TEMPLATE)));
+    }
+
+    for (path, content) in &changes {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+        }
+        fs::write(path, content).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(changes.into_iter().map(|(path, _)| path).collect())
+}
+
+/// Returns the location of the module of `year` and `day` below `root` (usually `src/solutions`)
+pub fn day_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(format!("year_{year}"))
+        .join(format!("day_{day:02}.rs"))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Adds `day` to the `days!` block of a year module, keeping the days ordered
+fn register_day(year_file: &str, day: u8) -> Result<String, String> {
+    let entry = format!("    {day} => day_{day:02},");
+    let mut lines = year_file.lines().map(String::from).collect::<Vec<_>>();
+    let mut position = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let Some((number, _)) = line.trim().split_once(" => ") else {
+            continue;
+        };
+        let Ok(number) = number.parse::<u8>() else {
+            continue;
+        };
+        if number == day {
+            return Err(format!("Day {day} is registered already"));
+        }
+        if number < day || position.is_none() {
+            position = Some(if number < day { idx + 1 } else { idx });
+        }
+    }
+
+    let position = position
+        .or_else(|| lines.iter().position(|x| x.trim() == "}"))
+        .ok_or("No `days!` block found")?;
+    lines.insert(position, entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the module of `year` to the `years!` list of `solutions.rs`, keeping the years ordered
+fn register_year(solutions_file: &str, year: u16) -> Result<String, String> {
+    let start = solutions_file
+        .find("\nyears!(")
+        .ok_or("No `years!` list found")?
+        + "\nyears!(".len();
+    let end = start
+        + solutions_file[start..]
+            .find(");")
+            .ok_or("Unclosed `years!` list")?;
+
+    let module = format!("year_{year}");
+    let mut years = solutions_file[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .collect::<Vec<_>>();
+    if years.contains(&module.as_str()) {
+        return Err(format!("{module} is registered already"));
+    }
+    years.push(&module);
+    years.sort_unstable();
+
+    let mut lines = vec![String::new()];
+    for year in years {
+        let line = lines.last_mut().unwrap();
+        if !line.is_empty() && 4 + line.len() + 1 + year.len() + 1 > MAX_WIDTH {
+            lines.push(format!("{year},"));
+        } else {
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(year);
+            line.push(',');
+        }
+    }
+    let list = format!("\n    {}\n", lines.join("\n    "));
+
+    Ok(format!(
+        "{}{list}{}",
+        &solutions_file[..start],
+        &solutions_file[end..]
+    ))
+}
+
+fn year_template(year: u16, day: u8) -> String {
+    format!("days! {{\n    {year};\n    {day} => day_{day:02},\n}}\n")
+}
+
+fn day_template() -> String {
+    String::from(
+        r#"use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    lines: Vec<String>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: parse(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.lines)
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.lines)
+    }
+}
+
+fn parse(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+fn part_one(_lines: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+fn part_two(_lines: &[String]) -> Answer {
+    Answer::Unsolved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_INPUT: &str = "";
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Answer::Unsolved, part_one(&parse(EXAMPLE_INPUT)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Answer::Unsolved, part_two(&parse(EXAMPLE_INPUT)));
+    }
+}
+"#,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_day_works() {
+        let year = "days! {\n    2023;\n    1 => day_01,\n    4 => day_04,\n}\n";
+        assert_eq!(
+            "days! {\n    2023;\n    1 => day_01,\n    2 => day_02,\n    4 => day_04,\n}\n",
+            register_day(year, 2).unwrap()
+        );
+        assert_eq!(
+            "days! {\n    2023;\n    1 => day_01,\n    4 => day_04,\n    12 => day_12,\n}\n",
+            register_day(year, 12).unwrap()
+        );
+        assert!(register_day(year, 4).is_err());
+        assert_eq!(
+            "days! {\n    2023;\n    3 => day_03,\n}\n",
+            register_day("days! {\n    2023;\n}\n", 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn register_year_works() {
+        let solutions = "mod x;\n\nyears!(\n    year_2015, year_2017,\n);\n\nfn y() {}\n";
+        assert_eq!(
+            "mod x;\n\nyears!(\n    year_2015, year_2016, year_2017,\n);\n\nfn y() {}\n",
+            register_year(solutions, 2016).unwrap()
+        );
+        assert!(register_year(solutions, 2017).is_err());
+    }
+
+    #[test]
+    fn register_year_matches_registry() {
+        let solutions = include_str!("../solutions.rs");
+        let years = aoc::solutions::all()
+            .map(|x| x.year)
+            .collect::<std::collections::BTreeSet<_>>();
+        let last = *years.last().unwrap();
+        let removed = solutions.replace(&format!(" year_{last},"), "");
+        assert_eq!(solutions, register_year(&removed, last).unwrap());
+    }
+}
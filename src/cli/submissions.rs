@@ -0,0 +1,193 @@
+//! The local log of submitted answers, used to avoid sending answers known to be wrong.
+//!
+//! Every submission is appended as a tab separated line:
+//! `<time>\t<year>\t<day>\t<part>\t<verdict>\t<answer>`.
+
+use crate::cli::client::Verdict;
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// A single logged submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The [`Verdict::key`]
+    pub verdict: String,
+    pub answer: String,
+}
+
+impl Submission {
+    /// Creates the entry for `answer` judged with `verdict`
+    pub fn new(year: u16, day: u8, part: u8, answer: &str, verdict: &Verdict) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            verdict: verdict.key().to_string(),
+            answer: answer.to_string(),
+        }
+    }
+}
+
+/// All submissions of the log file
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    /// Reads the log at `path`, a missing file counts as empty
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+        let submissions = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                parse_line(line)
+                    .ok_or_else(|| format!("{}: line {}: invalid entry", path.display(), idx + 1))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    /// Returns the submissions of `part` of `year` and `day`, oldest first
+    pub fn of(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |x| x.year == year && x.day == day && x.part == part)
+    }
+
+    /// Checks `answer` against the earlier submissions,
+    /// returns why it should not be sent if it is known or bound to be wrong.
+    ///
+    /// Answers with tabs or line breaks are rejected, they can't be logged.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        if answer.contains(['\t', '\n', '\r']) {
+            return Err(format!(
+                "{answer:?} contains a tab or line break, no answer does"
+            ));
+        }
+
+        let mut too_high: Option<i128> = None;
+        let mut too_low: Option<i128> = None;
+
+        for submission in self.of(year, day, part) {
+            match submission.verdict.as_str() {
+                "correct" => return Err(format!("Already solved with {}", submission.answer)),
+                "too-high" | "too-low" | "wrong" if submission.answer == answer => {
+                    return Err(format!("{answer} was already submitted and is wrong"));
+                }
+                _ => {}
+            }
+            if let Ok(number) = submission.answer.parse::<i128>() {
+                match submission.verdict.as_str() {
+                    "too-high" => too_high = Some(too_high.map_or(number, |x| x.min(number))),
+                    "too-low" => too_low = Some(too_low.map_or(number, |x| x.max(number))),
+                    _ => {}
+                }
+            }
+        }
+
+        if let Ok(number) = answer.parse::<i128>() {
+            if let Some(high) = too_high.filter(|&x| number >= x) {
+                return Err(format!("{answer} is wrong, {high} was already too high"));
+            }
+            if let Some(low) = too_low.filter(|&x| number <= x) {
+                return Err(format!("{answer} is wrong, {low} was already too low"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends a submission to the log file
+    pub fn append(&mut self, now: DateTime<Utc>, submission: Submission) -> Result<(), String> {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            timestamp(now),
+            submission.year,
+            submission.day,
+            submission.part,
+            submission.verdict,
+            submission.answer
+        );
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| format!("{}: {e}", self.path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+fn timestamp(now: DateTime<Utc>) -> String {
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        now.year(),
+        now.month(),
+        now.day(),
+        now.hour(),
+        now.minute(),
+        now.second()
+    )
+}
+
+fn parse_line(line: &str) -> Option<Submission> {
+    let mut fields = line.splitn(6, '\t');
+    let _time = fields.next()?;
+    Some(Submission {
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        verdict: fields.next()?.to_string(),
+        answer: fields.next()?.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn log(lines: &[&str]) -> SubmissionLog {
+        SubmissionLog {
+            path: PathBuf::new(),
+            submissions: lines.iter().map(|x| parse_line(x).unwrap()).collect(),
+        }
+    }
+
+    #[test]
+    fn check_works() {
+        let log = log(&[
+            "2021-12-05T05:10:00Z\t2021\t5\t1\ttoo-high\t500",
+            "2021-12-05T05:11:00Z\t2021\t5\t1\ttoo-low\t100",
+            "2021-12-05T05:12:00Z\t2021\t5\t1\twrong\tabc",
+            "2021-12-05T05:13:00Z\t2021\t5\t1\trate-limited\t300",
+            "2021-12-05T05:20:00Z\t2021\t5\t2\tcorrect\t42",
+        ]);
+
+        assert!(log.check(2021, 5, 1, "300").is_ok());
+        assert!(log.check(2021, 5, 1, "500").is_err());
+        assert!(log.check(2021, 5, 1, "501").is_err());
+        assert!(log.check(2021, 5, 1, "99").is_err());
+        assert!(log.check(2021, 5, 1, "abc").is_err());
+        assert!(log.check(2021, 5, 1, "abd").is_ok());
+        assert!(log.check(2021, 5, 2, "43").is_err());
+        assert!(log.check(2021, 6, 1, "500").is_ok());
+        assert!(log.check(2021, 6, 1, "5\t0").is_err());
+        assert!(log.check(2021, 6, 1, "5\n0").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! A minimal text table for the summaries printed by the CLI.
+
+use std::fmt::{self, Display};
+
+/// Horizontal alignment of a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// A table with a header row, rendered with padded columns
+#[derive(Debug)]
+pub struct Table {
+    headers: Vec<String>,
+    aligns: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates a table with the given columns
+    pub fn new(columns: &[(&str, Align)]) -> Self {
+        Self {
+            headers: columns.iter().map(|(x, _)| (*x).to_string()).collect(),
+            aligns: columns.iter().map(|(_, x)| *x).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Appends a row, missing cells are left empty
+    pub fn add_row(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        (0..self.headers.len())
+            .map(|i| {
+                self.rows
+                    .iter()
+                    .filter_map(|row| row.get(i))
+                    .chain(std::iter::once(&self.headers[i]))
+                    .map(|x| x.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    fn write_row(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        row: &[String],
+        widths: &[usize],
+    ) -> fmt::Result {
+        let cells = widths
+            .iter()
+            .enumerate()
+            .map(|(i, &width)| {
+                let cell = row.get(i).map_or("", String::as_str);
+                match self.aligns[i] {
+                    Align::Left => format!("{cell:<width$}"),
+                    Align::Right => format!("{cell:>width$}"),
+                }
+            })
+            .collect::<Vec<_>>();
+        writeln!(f, "| {} |", cells.join(" | "))
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.widths();
+        self.write_row(f, &self.headers, &widths)?;
+        let separator = widths
+            .iter()
+            .zip(&self.aligns)
+            .map(|(&width, align)| match align {
+                Align::Left => "-".repeat(width),
+                Align::Right => format!("{}:", "-".repeat(width.saturating_sub(1))),
+            })
+            .collect::<Vec<_>>();
+        writeln!(f, "| {} |", separator.join(" | "))?;
+        for row in &self.rows {
+            self.write_row(f, row, &widths)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_works() {
+        let mut table = Table::new(&[("Year", Align::Left), ("Days", Align::Right)]);
+        table.add_row(vec!["2015".to_string(), "21".to_string()]);
+        table.add_row(vec!["2019".to_string(), "2".to_string()]);
+        assert_eq!(
+            "| Year | Days |\n| ---- | ---: |\n| 2015 |   21 |\n| 2019 |    2 |\n",
+            table.to_string()
+        );
+    }
+}
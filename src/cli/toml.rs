@@ -0,0 +1,265 @@
+//! Reader and writer for the small subset of [TOML](https://toml.io) used by the local data files.
+//!
+//! Supported are `[section]` headers, `key = value` pairs with basic strings, integers and
+//! booleans, and `#` comments. Keys before the first header belong to the section `""`.
+
+use std::fmt::{self, Display};
+
+/// A single value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+}
+
+impl Value {
+    /// Returns the value as it would appear after the `=`
+    pub fn to_toml(&self) -> String {
+        match self {
+            Value::String(x) => format!("\"{}\"", escape(x)),
+            Value::Integer(x) => x.to_string(),
+            Value::Boolean(x) => x.to_string(),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::String(x) => write!(f, "{x}"),
+            Value::Integer(x) => write!(f, "{x}"),
+            Value::Boolean(x) => write!(f, "{x}"),
+        }
+    }
+}
+
+/// A `[section]` with its entries in file order
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Section {
+    pub name: String,
+    pub entries: Vec<(String, Value)>,
+}
+
+impl Section {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the value of `key`
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    /// Sets `key` to `value`, replacing an existing entry
+    pub fn set(&mut self, key: &str, value: Value) {
+        if let Some(entry) = self.entries.iter_mut().find(|(k, _)| k == key) {
+            entry.1 = value;
+        } else {
+            self.entries.push((key.to_string(), value));
+        }
+    }
+}
+
+/// A parsed document, sections are kept in file order
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Document {
+    pub sections: Vec<Section>,
+}
+
+impl Document {
+    /// Returns the section called `name`
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|x| x.name == name)
+    }
+
+    /// Returns the section called `name`, appending it if necessary
+    pub fn section_mut(&mut self, name: &str) -> &mut Section {
+        if let Some(idx) = self.sections.iter().position(|x| x.name == name) {
+            &mut self.sections[idx]
+        } else {
+            self.sections.push(Section::new(name));
+            self.sections.last_mut().unwrap()
+        }
+    }
+}
+
+impl Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for section in &self.sections {
+            if !first {
+                writeln!(f)?;
+            }
+            first = false;
+            if !section.name.is_empty() {
+                writeln!(f, "[{}]", section.name)?;
+            }
+            for (key, value) in &section.entries {
+                writeln!(f, "{key} = {}", value.to_toml())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A syntax error, `line` is 1-based
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub line: usize,
+    pub message: String,
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Parses `text` into a [`Document`]
+pub fn parse(text: &str) -> Result<Document, Error> {
+    let mut document = Document::default();
+    let mut current = String::new();
+
+    for (idx, raw) in text.lines().enumerate() {
+        let error = |message: &str| Error {
+            line: idx + 1,
+            message: message.to_string(),
+        };
+        let line = strip_comment(raw).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[') {
+            let name = name
+                .strip_suffix(']')
+                .ok_or_else(|| error("unclosed section header"))?;
+            current = name.trim().to_string();
+            document.section_mut(&current);
+        } else {
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error("expected `key = value`"))?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(error("missing key"));
+            }
+            let value = parse_value(value.trim()).map_err(|e| error(&e))?;
+            document.section_mut(&current).set(key, value);
+        }
+    }
+
+    Ok(document)
+}
+
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn parse_value(text: &str) -> Result<Value, String> {
+    if let Some(inner) = text.strip_prefix('"') {
+        let inner = inner
+            .strip_suffix('"')
+            .ok_or_else(|| String::from("unterminated string"))?;
+        unescape(inner).map(Value::String)
+    } else if text == "true" || text == "false" {
+        Ok(Value::Boolean(text == "true"))
+    } else {
+        text.replace('_', "")
+            .parse()
+            .map(Value::Integer)
+            .map_err(|_| format!("invalid value: {text}"))
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut result = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+fn unescape(text: &str) -> Result<String, String> {
+    let mut result = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('"') => result.push('"'),
+                Some('\\') => result.push('\\'),
+                Some('n') => result.push('\n'),
+                Some('t') => result.push('\t'),
+                other => return Err(format!("invalid escape: \\{}", other.unwrap_or(' '))),
+            }
+        } else if c == '"' {
+            return Err(String::from("unescaped quote in string"));
+        } else {
+            result.push(c);
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+root = 1 # comment
+
+[2015-07]
+part_one = 956
+part_two = "a \"quoted\"\nsecond # line"
+flag = true
+"#;
+
+    #[test]
+    fn parse_works() {
+        let document = parse(EXAMPLE).unwrap();
+        assert_eq!(
+            Some(&Value::Integer(1)),
+            document.section("").unwrap().get("root")
+        );
+        let section = document.section("2015-07").unwrap();
+        assert_eq!(Some(&Value::Integer(956)), section.get("part_one"));
+        assert_eq!(
+            Some(&Value::String("a \"quoted\"\nsecond # line".to_string())),
+            section.get("part_two")
+        );
+        assert_eq!(Some(&Value::Boolean(true)), section.get("flag"));
+    }
+
+    #[test]
+    fn roundtrip_works() {
+        let document = parse(EXAMPLE).unwrap();
+        assert_eq!(document, parse(&document.to_string()).unwrap());
+    }
+
+    #[test]
+    fn parse_reports_line() {
+        assert_eq!(1, parse("[open").unwrap_err().line);
+        assert_eq!(3, parse("a = 1\n\nb = \"x").unwrap_err().line);
+    }
+}
@@ -0,0 +1,146 @@
+//! Polling of files for changes (plain `std`, no notification APIs) and the answer diff between runs.
+
+use aoc::solutions::{Answer, Answers, Parts};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// What identifies a version of a file: modification time and length, `None` if missing
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Watches files by comparing their modification times
+#[derive(Debug)]
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+}
+
+impl Watcher {
+    /// Starts watching `paths` in their current state
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = stamp(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the files modified, created or removed since the last call
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, old) in &mut self.files {
+            let new = stamp(path);
+            if new != *old {
+                *old = new;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    /// Blocks until at least one file changed, checking every `interval`
+    pub fn wait(&mut self, interval: Duration) -> Vec<PathBuf> {
+        loop {
+            let changed = self.changed();
+            if !changed.is_empty() {
+                return changed;
+            }
+            thread::sleep(interval);
+        }
+    }
+}
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Describes the answers of the selected `parts` compared to the `previous` run, one entry per part
+pub fn diff(previous: Option<&Answers>, current: &Answers, parts: Parts) -> Vec<String> {
+    current
+        .iter()
+        .filter(|(part, _)| parts.includes(*part))
+        .map(|(part, answer)| {
+            let before = previous.map(|x| if part == 1 { &x.part_one } else { &x.part_two });
+            describe(part, answer, before)
+        })
+        .collect()
+}
+
+fn describe(part: u8, answer: &Answer, before: Option<&Answer>) -> String {
+    let note = match before {
+        None => String::new(),
+        Some(before) if before == answer => String::from(" (unchanged)"),
+        Some(_) if answer.is_multiline() => String::from(" (changed)"),
+        Some(Answer::Art(_)) => String::from(" (was ASCII art)"),
+        Some(before) => format!(" (was {before})"),
+    };
+    if answer.is_multiline() {
+        format!("Part {part}{note}:\n{answer}")
+    } else {
+        format!("Part {part}: {answer}{note}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    fn answers(part_one: Answer, part_two: Answer) -> Answers {
+        Answers { part_one, part_two }
+    }
+
+    #[test]
+    fn diff_works() {
+        let first = answers(Answer::Number(41), Answer::Art(String::from("#.")));
+        let second = answers(Answer::Number(42), Answer::Art(String::from("#.")));
+
+        assert_eq!(
+            vec!["Part 1: 41", "Part 2:\n#."],
+            diff(None, &first, Parts::Both)
+        );
+        assert_eq!(
+            vec!["Part 1: 42 (was 41)", "Part 2 (unchanged):\n#."],
+            diff(Some(&first), &second, Parts::Both)
+        );
+        assert_eq!(
+            vec!["Part 1: 42 (unchanged)"],
+            diff(Some(&second), &second, Parts::One)
+        );
+        assert_eq!(
+            vec!["Part 2: - (was ASCII art)"],
+            diff(
+                Some(&first),
+                &answers(Answer::Unsolved, Answer::Unsolved),
+                Parts::Two
+            )
+        );
+    }
+
+    #[test]
+    fn watcher_works() {
+        let path = std::env::temp_dir().join(format!("aoc-watch-{}.txt", std::process::id()));
+        let missing = path.with_extension("missing");
+        fs::write(&path, "1").unwrap();
+
+        let mut watcher = Watcher::new([path.clone(), missing]);
+        assert!(watcher.changed().is_empty());
+
+        let later = SystemTime::now() + Duration::from_mins(1);
+        File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(later))
+            .unwrap();
+        assert_eq!(vec![path.clone()], watcher.changed());
+        assert!(watcher.changed().is_empty());
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(vec![path], watcher.wait(Duration::from_millis(1)));
+    }
+}
@@ -0,0 +1,170 @@
+//! Errors of solving a day, reported to the user instead of panicking.
+
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+/// A malformed part of a puzzle input.
+///
+/// Created from the offending slice of the input, so that it can be located
+/// (1-based line and column) in the whole input afterwards with [`ParseError::locate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    /// The offending text
+    pub text: String,
+    /// The 1-based line and column of `text`, known once located
+    pub position: Option<(usize, usize)>,
+    /// The whole line containing `text`, known once located
+    pub line: Option<String>,
+    address: usize,
+}
+
+impl ParseError {
+    /// Creates an error for `span`, which should be a slice of the puzzle input to be located
+    pub fn new(span: &str, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            text: span.to_string(),
+            position: None,
+            line: None,
+            address: span.as_ptr() as usize,
+        }
+    }
+
+    /// Determines line and column of the offending text if it is a slice of `input`
+    #[must_use]
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        let Some(offset) = self
+            .address
+            .checked_sub(start)
+            .filter(|&x| x + self.text.len() <= input.len())
+        else {
+            return self;
+        };
+
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |x| x + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |x| offset + x);
+        self.position = Some((
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+        ));
+        self.line = Some(
+            input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        );
+        self
+    }
+
+    /// Renders the located line with a caret under the offending text
+    pub fn snippet(&self) -> Option<String> {
+        let ((number, column), line) = (self.position?, self.line.as_ref()?);
+        let gutter = " ".repeat(number.to_string().len());
+        let width = self
+            .text
+            .lines()
+            .next()
+            .unwrap_or("")
+            .chars()
+            .count()
+            .max(1);
+        Some(format!(
+            "{gutter} |\n{number} | {line}\n{gutter} | {}{}",
+            " ".repeat(column - 1),
+            "^".repeat(width)
+        ))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.position {
+            Some((line, column)) => write!(f, "line {line}, column {column}: {}", self.message),
+            None => write!(f, "{} in `{}`", self.message, self.text),
+        }
+    }
+}
+
+/// An error of solving a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AocError {
+    /// The puzzle input is malformed
+    Parse {
+        year: u16,
+        day: u8,
+        error: ParseError,
+    },
+    /// The solution took longer than allowed
+    Timeout { year: u16, day: u8, after: Duration },
+    /// The solution panicked, e.g. on an input its parser didn't expect
+    Panicked { year: u16, day: u8, message: String },
+}
+
+impl AocError {
+    /// Renders the error over multiple lines, with a snippet of the input if available
+    pub fn diagnostic(&self) -> String {
+        match self {
+            AocError::Parse { error, .. } => match error.snippet() {
+                Some(snippet) => format!("error: {self}\n{snippet}"),
+                None => format!("error: {self}"),
+            },
+            AocError::Timeout { .. } | AocError::Panicked { .. } => format!("error: {self}"),
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse { year, day, error } => {
+                write!(f, "invalid input for {year} day {day}: {error}")
+            }
+            AocError::Timeout { year, day, after } => {
+                write!(f, "no answer for {year} day {day} within {after:?}")
+            }
+            AocError::Panicked { year, day, message } => {
+                write!(f, "{year} day {day} panicked: {message}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_works() {
+        let input = "123 -> a\nx XOR y -> b\n";
+        let error = ParseError::new(&input[11..14], "unknown operator").locate(input);
+        assert_eq!(Some((2, 3)), error.position);
+        assert_eq!("line 2, column 3: unknown operator", error.to_string());
+        assert_eq!(
+            Some("  |\n2 | x XOR y -> b\n  |   ^^^".to_string()),
+            error.snippet()
+        );
+
+        let owned = String::from("XOR");
+        let error = ParseError::new(&owned, "unknown operator").locate(input);
+        assert_eq!(None, error.position);
+        assert_eq!("unknown operator in `XOR`", error.to_string());
+    }
+
+    #[test]
+    fn diagnostic_works() {
+        let input = "1\n2\nx\n";
+        let error = AocError::Parse {
+            year: 2021,
+            day: 1,
+            error: ParseError::new(&input[4..5], "not a number").locate(input),
+        };
+        assert_eq!(
+            "error: invalid input for 2021 day 1: line 3, column 1: not a number\n  |\n3 | x\n  | ^",
+            error.diagnostic()
+        );
+    }
+}
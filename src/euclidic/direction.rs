@@ -40,6 +40,7 @@ impl Direction {
         }
     }
 
+    #[must_use]
     pub fn opposing(self) -> Direction {
         *Direction::ALL
             .iter()
@@ -47,6 +48,7 @@ impl Direction {
             .unwrap()
     }
 
+    #[must_use]
     pub fn rotate_clockwise(self) -> Direction {
         match self {
             Direction::North => Direction::East,
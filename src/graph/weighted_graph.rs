@@ -87,7 +87,7 @@ where
                 return Some(cost);
             }
 
-            if *dist.get(&node).unwrap() < cost {
+            if *dist.get(&node).unwrap_or(&Distance::Infinite) < cost {
                 continue;
             }
 
@@ -97,7 +97,7 @@ where
                     node: edge.node,
                 };
 
-                if *dist.get(&next.node).unwrap() > next.cost {
+                if *dist.get(&next.node).unwrap_or(&Distance::Infinite) > next.cost {
                     heap.push(next);
                     *dist.entry(next.node).or_default() = Distance::Some(next.cost);
                 }
@@ -170,3 +170,24 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest_path_works() {
+        let mut graph = WeightedGraph::new();
+        graph.add_edge('a', 'b', 1);
+        graph.add_edge('a', 'c', 5);
+        graph.add_edge('b', 'c', 2);
+        graph.add_edge('c', 'd', 1);
+
+        assert_eq!(Some(0), graph.shortest_path('a', 'a'));
+        assert_eq!(Some(3), graph.shortest_path('a', 'c'));
+        // `d` has no edges of its own
+        assert_eq!(Some(4), graph.shortest_path('a', 'd'));
+        assert_eq!(None, graph.shortest_path('d', 'a'));
+        assert_eq!(None, graph.shortest_path('a', 'e'));
+    }
+}
@@ -0,0 +1,184 @@
+//! Normalization of raw puzzle inputs before they are parsed.
+//!
+//! Inputs saved by editors or browsers may start with a byte order mark, use CRLF line endings
+//! or end with blank lines, none of which the parsers of the days should have to deal with.
+
+use crate::error::ParseError;
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+
+const BOM: char = '\u{feff}';
+
+/// How a solution wants its input prepared, see [`crate::solutions::Solution::INPUT_MODE`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputMode {
+    /// Normalized with [`normalize`]
+    #[default]
+    Normalized,
+    /// Normalized and rejected if it contains non-ASCII characters, e.g. for parsers slicing bytes
+    Ascii,
+    /// Passed as is, for inputs whose whitespace matters
+    Raw,
+}
+
+/// What [`normalize`] changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Changes {
+    /// A leading byte order mark was stripped
+    pub bom: bool,
+    /// The number of CRLF line endings converted to LF
+    pub crlf: usize,
+    /// The number of whitespace-only lines dropped at the end
+    pub trailing_lines: usize,
+}
+
+impl Changes {
+    /// Returns `true` if the input was left as is
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Display for Changes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut changes = Vec::new();
+        if self.bom {
+            changes.push(String::from("stripped the byte order mark"));
+        }
+        if self.crlf > 0 {
+            changes.push(format!(
+                "converted {} CRLF line ending{}",
+                self.crlf,
+                plural(self.crlf)
+            ));
+        }
+        if self.trailing_lines > 0 {
+            changes.push(format!(
+                "dropped {} trailing blank line{}",
+                self.trailing_lines,
+                plural(self.trailing_lines)
+            ));
+        }
+        if changes.is_empty() {
+            write!(f, "nothing changed")
+        } else {
+            write!(f, "{}", changes.join(", "))
+        }
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Strips a byte order mark, converts CRLF to LF and drops whitespace-only lines at the end.
+///
+/// The line ending of the last line is dropped as well, the input is only copied if anything changed.
+pub fn normalize(input: &str) -> (Cow<'_, str>, Changes) {
+    let mut changes = Changes::default();
+
+    let mut text = input;
+    if let Some(rest) = text.strip_prefix(BOM) {
+        changes.bom = true;
+        text = rest;
+    }
+
+    let content_end = text.trim_end().len();
+    let end = match text[content_end..].find(['\r', '\n']) {
+        _ if content_end == 0 => 0,
+        Some(x) => content_end + x,
+        None => text.len(),
+    };
+    changes.trailing_lines = text[end..].matches('\n').count().saturating_sub(1);
+    text = &text[..end];
+
+    changes.crlf = text.matches("\r\n").count();
+    if changes.crlf == 0 {
+        (Cow::Borrowed(text), changes)
+    } else {
+        (Cow::Owned(text.replace("\r\n", "\n")), changes)
+    }
+}
+
+/// Returns an error for the first non-ASCII character of `input`
+pub fn check_ascii(input: &str) -> Result<(), ParseError> {
+    match input.char_indices().find(|(_, c)| !c.is_ascii()) {
+        None => Ok(()),
+        Some((i, c)) => Err(ParseError::new(
+            &input[i..i + c.len_utf8()],
+            format!("non-ASCII character {c:?}"),
+        )
+        .locate(input)),
+    }
+}
+
+/// Prepares `input` for a solution with the given `mode`
+pub fn prepare(input: &str, mode: InputMode) -> Result<(Cow<'_, str>, Changes), ParseError> {
+    match mode {
+        InputMode::Raw => Ok((Cow::Borrowed(input), Changes::default())),
+        InputMode::Normalized => Ok(normalize(input)),
+        InputMode::Ascii => {
+            let (text, changes) = normalize(input);
+            check_ascii(&text)?;
+            Ok((text, changes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_works() {
+        assert_eq!(
+            (Cow::Borrowed("a\nb"), Changes::default()),
+            normalize("a\nb\n")
+        );
+        assert_eq!(
+            (
+                Cow::Owned(String::from("a\n\nb")),
+                Changes {
+                    bom: true,
+                    crlf: 2,
+                    trailing_lines: 2,
+                }
+            ),
+            normalize("\u{feff}a\r\n\r\nb\r\n  \r\n\n")
+        );
+        assert_eq!("  a ", normalize("  a \r\n \t\n").0);
+        assert_eq!("", normalize("\n\n").0);
+    }
+
+    #[test]
+    fn prepare_works() {
+        assert_eq!("a\r\n\n", prepare("a\r\n\n", InputMode::Raw).unwrap().0);
+        assert_eq!("ä", prepare("ä\n\n", InputMode::Normalized).unwrap().0);
+
+        let error = prepare("ab\ncä\n", InputMode::Ascii).unwrap_err();
+        assert_eq!("ä", error.text);
+        assert_eq!(Some((2, 2)), error.position);
+
+        assert_eq!("ab", prepare("\u{feff}ab\r\n", InputMode::Ascii).unwrap().0);
+        let error = prepare("\u{feff}a\r\nä", InputMode::Ascii).unwrap_err();
+        assert_eq!(Some((2, 1)), error.position);
+    }
+
+    #[test]
+    fn changes_display_works() {
+        let changes = Changes {
+            bom: true,
+            crlf: 2,
+            trailing_lines: 0,
+        };
+        assert_eq!(
+            "stripped the byte order mark, converted 2 CRLF line endings",
+            changes.to_string()
+        );
+        assert_eq!("nothing changed", Changes::default().to_string());
+    }
+}
@@ -0,0 +1,398 @@
+//! A small JSON reader and writer, grown out of the parser of 2015 day 12.
+//!
+//! Objects keep their keys in the order of the document, numbers are read as `f64`.
+
+use crate::error::ParseError;
+use std::fmt::{self, Display, Write};
+
+/// A JSON value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    /// Returns the value of `key` if this is an object containing it
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        self.as_object()?
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns the number if it is integral
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Json::Number(x) if x.fract() == 0.0 && x.abs() < 2f64.powi(53) => Some(*x as i64),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&[(String, Json)]> {
+        match self {
+            Json::Object(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Json::Number(value as f64)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+/// Renders the value compactly, with a space after `:` and `,`
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(x) => write!(f, "{x}"),
+            Json::Number(x) => write!(f, "{x}"),
+            Json::String(x) => write_string(f, x),
+            Json::Array(values) => {
+                f.write_char('[')?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            Json::Object(entries) => {
+                f.write_char('{')?;
+                for (idx, (key, value)) in entries.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ": {value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut impl Write, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if c.is_control() => write!(f, "\\u{:04x}", u32::from(c))?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+/// Parses a complete JSON document, surrounding whitespace is allowed
+pub fn parse(input: &str) -> Result<Json, ParseError> {
+    let mut reader = Reader { input, pos: 0 };
+    reader
+        .document()
+        .map_err(|(pos, message)| ParseError::new(reader.span(pos), message).locate(input))
+}
+
+/// The byte position and message of a syntax error
+type Error = (usize, String);
+
+struct Reader<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn document(&mut self) -> Result<Json, Error> {
+        let value = self.value()?;
+        self.skip_whitespace();
+        if self.pos < self.input.len() {
+            return Err(self.error("unexpected text after the value"));
+        }
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Json, Error> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => self.string().map(Json::String),
+            Some('-' | '0'..='9') => self.number(),
+            Some(_) if self.eat("true") => Ok(Json::Bool(true)),
+            Some(_) if self.eat("false") => Ok(Json::Bool(false)),
+            Some(_) if self.eat("null") => Ok(Json::Null),
+            Some(_) => Err(self.error("expected a value")),
+            None => Err(self.error("unexpected end, expected a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, Error> {
+        self.pos += 1;
+        let mut entries = Vec::new();
+        self.skip_whitespace();
+        if self.eat("}") {
+            return Ok(Json::Object(entries));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("expected a key"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            if !self.eat(":") {
+                return Err(self.error("expected `:`"));
+            }
+            entries.push((key, self.value()?));
+            self.skip_whitespace();
+            if self.eat("}") {
+                return Ok(Json::Object(entries));
+            }
+            if !self.eat(",") {
+                return Err(self.error("expected `,` or `}`"));
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, Error> {
+        self.pos += 1;
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        if self.eat("]") {
+            return Ok(Json::Array(values));
+        }
+        loop {
+            values.push(self.value()?);
+            self.skip_whitespace();
+            if self.eat("]") {
+                return Ok(Json::Array(values));
+            }
+            if !self.eat(",") {
+                return Err(self.error("expected `,` or `]`"));
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        self.pos += 1;
+        let mut result = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err((start, String::from("unterminated string")));
+            };
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Ok(result),
+                '\\' => result.push(self.escape()?),
+                c if c.is_control() => {
+                    self.pos -= c.len_utf8();
+                    return Err(self.error("control character in string"));
+                }
+                c => result.push(c),
+            }
+        }
+    }
+
+    /// Reads the escape sequence after a `\`
+    fn escape(&mut self) -> Result<char, Error> {
+        let start = self.pos - 1;
+        let c = self
+            .peek()
+            .ok_or((start, String::from("unterminated string")))?;
+        self.pos += c.len_utf8();
+        match c {
+            '"' | '\\' | '/' => Ok(c),
+            'b' => Ok('\u{8}'),
+            'f' => Ok('\u{c}'),
+            'n' => Ok('\n'),
+            'r' => Ok('\r'),
+            't' => Ok('\t'),
+            'u' => {
+                let high = self.hex()?;
+                if !(0xd800..0xdc00).contains(&high) {
+                    return char::from_u32(high).ok_or((start, String::from("invalid escape")));
+                }
+                if !self.eat("\\u") {
+                    return Err((start, String::from("unpaired surrogate")));
+                }
+                let low = self.hex()?;
+                if !(0xdc00..0xe000).contains(&low) {
+                    return Err((start, String::from("unpaired surrogate")));
+                }
+                char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00))
+                    .ok_or((start, String::from("invalid escape")))
+            }
+            _ => Err((start, String::from("invalid escape"))),
+        }
+    }
+
+    fn hex(&mut self) -> Result<u32, Error> {
+        let digits = self.input.get(self.pos..self.pos + 4).unwrap_or("");
+        if digits.len() != 4 || !digits.chars().all(|x| x.is_ascii_hexdigit()) {
+            return Err(self.error("expected 4 hex digits"));
+        }
+        self.pos += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn number(&mut self) -> Result<Json, Error> {
+        let start = self.pos;
+        let rest = &self.input[start..];
+        let len = rest
+            .find(|c: char| !matches!(c, '0'..='9' | '-' | '+' | '.' | 'e' | 'E'))
+            .unwrap_or(rest.len());
+        let text = &rest[..len];
+        self.pos += len;
+        match text.parse::<f64>() {
+            Ok(number) if is_number(text) => Ok(Json::Number(number)),
+            _ => Err((start, format!("invalid number {text:?}"))),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Advances past `expected` if the input continues with it
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.input[self.pos..].starts_with(expected);
+        if found {
+            self.pos += expected.len();
+        }
+        found
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn error(&self, message: &str) -> Error {
+        (self.pos, message.to_string())
+    }
+
+    /// Returns the character at `pos` as slice of the input, empty at the end
+    fn span(&self, pos: usize) -> &'a str {
+        let len = self.input[pos..].chars().next().map_or(0, char::len_utf8);
+        &self.input[pos..pos + len]
+    }
+}
+
+/// Returns `true` if `text` follows the number grammar `-?(0|[1-9]\d*)(\.\d+)?([eE][+-]?\d+)?`
+fn is_number(text: &str) -> bool {
+    let digits = |x: &str| x.len() - x.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let rest = text.strip_prefix('-').unwrap_or(text);
+    let integer = digits(rest);
+    if integer == 0 || (integer > 1 && rest.starts_with('0')) {
+        return false;
+    }
+    let mut rest = &rest[integer..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let count = digits(fraction);
+        if count == 0 {
+            return false;
+        }
+        rest = &fraction[count..];
+    }
+    if let Some(exponent) = rest.strip_prefix(['e', 'E']) {
+        let exponent = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        let count = digits(exponent);
+        if count == 0 {
+            return false;
+        }
+        rest = &exponent[count..];
+    }
+    rest.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_works() {
+        assert_eq!(Ok(Json::Number(-10.0)), parse("-10"));
+        assert_eq!(Ok(Json::Number(1.5e3)), parse(" 1.5e3\n"));
+        assert_eq!(
+            Ok(Json::from("a\"\u{e9}\u{1f600}")),
+            parse(r#""a\"\u00e9\ud83d\ude00""#)
+        );
+        assert_eq!(Ok(Json::Array(Vec::new())), parse("[ ]"));
+        assert_eq!(
+            Ok(Json::Object(vec![
+                (
+                    String::from("b"),
+                    Json::Array(vec![Json::Null, Json::Bool(true)])
+                ),
+                (String::from("a"), Json::Object(Vec::new())),
+            ])),
+            parse(r#"{"b": [null, true], "a": {}}"#)
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_located() {
+        let error = parse("{\n  \"a\": 1,\n  \"b\" 2\n}").unwrap_err();
+        assert_eq!("expected `:`", error.message);
+        assert_eq!(Some((3, 7)), error.position);
+
+        for invalid in [
+            "", "[1,]", "{\"a\"}", "01", "1.", "-", "1e", ".5", "\"a", "tru", "[1] 2", "\"\\x\"",
+        ] {
+            assert!(parse(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn accessors_work() {
+        let json = parse(r#"{"id": 42, "name": "x", "ts": 1.5}"#).unwrap();
+        assert_eq!(Some(42), json.get("id").and_then(Json::as_i64));
+        assert_eq!(Some("x"), json.get("name").and_then(Json::as_str));
+        assert_eq!(None, json.get("ts").and_then(Json::as_i64));
+        assert_eq!(None, json.get("missing"));
+    }
+
+    #[test]
+    fn display_works() {
+        let text = r#"{"a": [1, 2.5, null], "b\n": "\"x\"", "c": false}"#;
+        assert_eq!(text, parse(text).unwrap().to_string());
+    }
+}
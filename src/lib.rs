@@ -0,0 +1,30 @@
+//! Solutions to the [Advent of Code](https://adventofcode.com) puzzles and the helpers they are built with.
+//!
+//! The registered days are dispatched through [`solutions`], e.g. `solutions::find(2015, 1)?.solve(input, Parts::Both)`,
+//! a batch of days is run with [`runner`].
+
+#![warn(clippy::pedantic)]
+#![allow(
+    clippy::cast_sign_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::missing_errors_doc,
+    clippy::missing_panics_doc,
+    clippy::module_name_repetitions,
+    clippy::must_use_candidate,
+    clippy::needless_pass_by_value,
+    clippy::similar_names
+)]
+
+pub mod cancel;
+pub mod error;
+pub mod euclidic;
+pub mod graph;
+pub mod input;
+pub mod json;
+pub mod params;
+pub mod parser;
+pub mod runner;
+pub mod solutions;
+pub mod util;
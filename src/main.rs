@@ -9,17 +9,28 @@
     clippy::similar_names
 )]
 
-mod euclidic;
-mod graph;
-mod parser;
-mod solutions;
-mod util;
-
-use clap::Parser;
-use std::fs::File;
-use std::io::{BufReader, Read};
+mod cli;
+
+use aoc::params;
+use aoc::runner::{self, DayResult};
+use aoc::solutions::{self, Entry, Parts};
+use aoc::util;
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use cli::bench::Stats;
+use cli::config::{self, Config};
+use cli::history;
+use cli::report::Format;
+use itertools::Itertools;
 use std::path::PathBuf;
-use std::time::Instant;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// The directory of the year modules, relative to the repository root
+const SOLUTIONS_DIR: &str = "src/solutions";
+
+/// The directory of the puzzle examples, relative to the repository root
+const EXAMPLES_DIR: &str = "tests/examples";
 
 #[derive(Debug, Parser)]
 #[command(
@@ -34,58 +45,408 @@ use std::time::Instant;
 {usage}
 
 {all-args}
-"
+",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
 )]
 struct Opt {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Set year (2 or 4 digits)
-    #[arg(short, long)]
-    year: u16,
+    #[arg(short, long, required_unless_present_any = ["list", "list_params"])]
+    year: Option<u16>,
 
     /// Set day
+    #[arg(short, long, required_unless_present_any = ["list", "list_params"])]
+    day: Option<u8>,
+
+    /// Calculate only this part, both parts by default
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Set a parameter of the day, e.g. to solve an example with other constants
+    #[arg(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_pair)]
+    params: Vec<(String, String)>,
+
+    /// Solve again whenever an input file changes, a changed module of the day needs a rebuild
     #[arg(short, long)]
-    day: u8,
+    watch: bool,
 
-    /// Input value, requires input-file if not present
+    /// Give up on an input after this many seconds [default: `AOC_TIMEOUT` or `timeout` of aoc.toml]
+    #[arg(long, value_name = "SECONDS", value_parser = config::parse_timeout, conflicts_with = "watch")]
+    timeout: Option<Duration>,
+
+    /// List all implemented days
+    #[arg(short, long)]
+    list: bool,
+
+    /// List the parameters of all days, or of the given day
+    #[arg(long, conflicts_with = "list")]
+    list_params: bool,
+
+    /// Input value, used if no input files are given
     #[arg(short, long)]
     input: Option<String>,
 
-    /// Input file - if present this will be taken as input
+    /// Input files, each solved separately - `-` reads stdin
     #[arg()]
-    input_file: Option<PathBuf>,
+    input_files: Vec<PathBuf>,
+
+    /// Output format [default: `AOC_FORMAT`, `format` of aoc.toml or "text"]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Directory to look up `<year>/day_<dd>.txt` in if no input is given
+    /// [default: `AOC_INPUTS`, `inputs` of aoc.toml or "inputs"]
+    #[arg(long)]
+    inputs: Option<PathBuf>,
 }
-fn main() {
-    env_logger::init();
 
-    let opt = Opt::parse();
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run all implemented days of a year or of every year
+    Run(RunArgs),
+    /// Compare the answers with the expected answers file
+    Verify(VerifyArgs),
+    /// Time parsing and both parts over repeated runs
+    Bench(BenchArgs),
+    /// Download the personal input of a day into the inputs directory
+    Fetch(FetchArgs),
+    /// Send the answer of a part to the puzzle site
+    Submit(SubmitArgs),
+    /// Generate the module of a new day and register it
+    New(NewArgs),
+    /// Show how many days of each year are implemented
+    Progress(ProgressArgs),
+    /// Solve the puzzle released today, fetching its input if necessary
+    Today(TodayArgs),
+    /// Analyze the JSON export of a private leaderboard
+    Leaderboard(LeaderboardArgs),
+    /// Inspect the settings of aoc.toml and the environment
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum ConfigCommand {
+    /// Print the effective settings and where each one comes from
+    Show,
+}
+
+/// Selects the registered days to work on
+#[derive(Debug, Args)]
+struct Selection {
+    /// Select all implemented days of this year (2 or 4 digits)
+    #[arg(short, long, required_unless_present = "all", conflicts_with = "all")]
+    year: Option<u16>,
 
-    let input = if opt.input_file.is_some() {
-        read_file(opt.input_file.unwrap())
-    } else if opt.input.is_some() {
-        opt.input.unwrap()
-    } else {
-        eprintln!("{}", util::NO_INPUT);
-        return;
-    };
-
-    if !input.is_ascii() {
-        eprintln!("WARNING: Input is not ASCII!");
+    /// Select only this day of the year
+    #[arg(short, long, requires = "year")]
+    day: Option<u8>,
+
+    /// Select every implemented day
+    #[arg(short, long)]
+    all: bool,
+
+    /// Select only this part, both parts by default
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Give up on a day after this many seconds and continue with the next one
+    /// [default: `AOC_TIMEOUT` or `timeout` of aoc.toml]
+    #[arg(long, value_name = "SECONDS", value_parser = config::parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Run one day after another instead of one per CPU at the same time, e.g. for comparable timings
+    /// (bench always runs sequentially)
+    #[arg(long)]
+    sequential: bool,
+
+    /// Directory containing the inputs as `<year>/day_<dd>.txt`
+    /// [default: `AOC_INPUTS`, `inputs` of aoc.toml or "inputs"]
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+}
+
+impl Selection {
+    fn entries(&self) -> Result<Vec<&'static Entry>, &'static str> {
+        match (self.year, self.day) {
+            (Some(year), Some(day)) => solutions::find(year, day).map(|x| vec![x]),
+            (Some(year), None) => {
+                let entries = solutions::of_year(year).collect_vec();
+                if entries.is_empty() {
+                    Err(util::INVALID_YEAR)
+                } else {
+                    Ok(entries)
+                }
+            }
+            (None, _) => Ok(solutions::all().collect_vec()),
+        }
+    }
+
+    fn inputs(&self, config: &Config) -> PathBuf {
+        config.inputs_root(self.inputs.as_deref())
+    }
+
+    fn parts(&self) -> Parts {
+        Parts::of(self.part)
+    }
+
+    fn timeout(&self, config: &Config) -> Option<Duration> {
+        config.timeout_of(self.timeout)
     }
 
-    let start = Instant::now();
+    fn jobs(&self) -> usize {
+        if self.sequential {
+            1
+        } else {
+            runner::default_jobs()
+        }
+    }
+
+    fn run(&self, config: &Config) -> Result<Vec<DayResult>, String> {
+        let entries = self.entries()?;
+        config.check_params(&entries)?;
+        Ok(runner::run_all(
+            &entries,
+            &self.inputs(config),
+            self.parts(),
+            self.timeout(config),
+            self.jobs(),
+            |entry| config.params_of(entry.year, entry.day, &[]),
+        ))
+    }
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Output format [default: `AOC_FORMAT`, `format` of aoc.toml or "text"]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// File with the expected answers [default: `AOC_ANSWERS`, `answers` of aoc.toml or "answers.toml"]
+    #[arg(long)]
+    answers: Option<PathBuf>,
+
+    /// Write the current answers into the answers file instead of comparing
+    #[arg(long)]
+    record: bool,
+
+    /// Output format [default: `AOC_FORMAT`, `format` of aoc.toml or "text"]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of unmeasured runs before measuring
+    #[arg(long, default_value_t = 1)]
+    warmup: usize,
+
+    /// Number of measured runs
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+
+    /// Order of the table, all statistics sort the slowest phase first
+    #[arg(short, long, value_enum, default_value_t = SortBy::Day)]
+    sort: SortBy,
+
+    /// File storing the medians of labeled runs
+    #[arg(long, default_value = "bench_history.toml")]
+    history: PathBuf,
+
+    /// Save the medians of this run under the label, replacing older results of the label
+    #[arg(long, value_parser = parse_label)]
+    label: Option<String>,
+
+    /// Label of the run to compare against, defaults to the most recently recorded other label
+    #[arg(long, value_parser = parse_label)]
+    baseline: Option<String>,
+
+    /// Allowed slowdown of a median against the baseline in percent
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Ignore slowdowns below this many microseconds
+    #[arg(long, default_value_t = 5.0)]
+    min_delta: f64,
+}
 
-    solutions::solve(opt.year, opt.day, &input);
+#[derive(Debug, Args)]
+struct FetchArgs {
+    /// Year of the puzzle (2 or 4 digits)
+    #[arg(short, long)]
+    year: u16,
 
-    let duration = start.elapsed();
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
 
-    println!("Time elapsed: {}ms", duration.as_millis());
+    /// Directory to store the input in as `<year>/day_<dd>.txt`
+    /// [default: `AOC_INPUTS`, `inputs` of aoc.toml or "inputs"]
+    #[arg(long)]
+    inputs: Option<PathBuf>,
 }
 
-fn read_file(path: PathBuf) -> String {
-    let file = File::open(path).expect("File could not be opened!");
-    let mut buf_reader = BufReader::new(file);
-    let mut contents = String::new();
-    buf_reader
-        .read_to_string(&mut contents)
-        .expect("Not readable!");
-    contents
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// Year of the puzzle (2 or 4 digits)
+    #[arg(short, long)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part to submit
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit instead of the calculated one, e.g. for ASCII art
+    #[arg(short, long)]
+    answer: Option<String>,
+
+    /// Directory containing the inputs as `<year>/day_<dd>.txt`
+    /// [default: `AOC_INPUTS`, `inputs` of aoc.toml or "inputs"]
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+
+    /// Log of all submissions, used to reject answers known to be wrong
+    #[arg(long, default_value = "submissions.log")]
+    log: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// Year of the puzzle (2 or 4 digits)
+    #[arg(short, long)]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(short, long)]
+    day: u8,
+
+    /// Directory of the year modules
+    #[arg(long, default_value = SOLUTIONS_DIR)]
+    solutions: PathBuf,
+
+    /// Directory of the puzzle examples
+    #[arg(long, default_value = EXAMPLES_DIR)]
+    examples: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct TodayArgs {
+    /// Calculate only this part, both parts by default
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Output format [default: `AOC_FORMAT`, `format` of aoc.toml or "text"]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Directory containing the inputs as `<year>/day_<dd>.txt`
+    /// [default: `AOC_INPUTS`, `inputs` of aoc.toml or "inputs"]
+    #[arg(long)]
+    inputs: Option<PathBuf>,
+
+    /// Take this moment as now instead of the system clock, e.g. "2021-12-15T05:00:00Z"
+    #[arg(long, value_name = "TIME", value_parser = parse_time)]
+    now: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Args)]
+struct LeaderboardArgs {
+    /// The JSON export of the leaderboard
+    file: PathBuf,
+
+    /// Output format, text or json [default: `AOC_FORMAT`, `format` of aoc.toml or "text"]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+}
+
+#[derive(Debug, Args)]
+struct ProgressArgs {
+    /// Show a grid with a star for every implemented day
+    #[arg(short, long)]
+    grid: bool,
+
+    /// Rewrite the table between the progress markers of this file instead of printing it
+    #[arg(long, value_name = "FILE", num_args = 0..=1, default_missing_value = "README.md")]
+    readme: Option<PathBuf>,
+}
+
+fn parse_time(time: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(time)
+        .map(|x| x.with_timezone(&Utc))
+        .map_err(|e| format!("Expected a time like \"2021-12-15T05:00:00Z\": {e}"))
+}
+
+fn parse_label(label: &str) -> Result<String, String> {
+    history::validate_label(label).map(|()| label.to_string())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SortBy {
+    Day,
+    Min,
+    Median,
+    Mean,
+    Stddev,
+}
+
+impl SortBy {
+    fn key(self, stats: &Stats) -> f64 {
+        match self {
+            SortBy::Day => 0.0,
+            SortBy::Min => stats.min,
+            SortBy::Median => stats.median,
+            SortBy::Mean => stats.mean,
+            SortBy::Stddev => stats.stddev,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    env_logger::init();
+
+    let opt = Opt::parse();
+
+    match opt.command {
+        Some(Command::Run(args)) => return cli::run(&args),
+        Some(Command::Verify(args)) => return cli::verify(&args),
+        Some(Command::Bench(args)) => return cli::bench(&args),
+        Some(Command::Fetch(args)) => return cli::fetch(&args),
+        Some(Command::Submit(args)) => return cli::submit(&args),
+        Some(Command::New(args)) => return cli::new_day(&args),
+        Some(Command::Progress(args)) => return cli::progress(&args),
+        Some(Command::Today(args)) => return cli::today(&args),
+        Some(Command::Leaderboard(args)) => return cli::leaderboard(&args),
+        Some(Command::Config(ConfigCommand::Show)) => return cli::show_config(),
+        None => {}
+    }
+
+    if opt.list {
+        cli::print_list();
+        return ExitCode::SUCCESS;
+    }
+
+    if opt.list_params {
+        return cli::list_params(opt.year, opt.day);
+    }
+
+    cli::solve_day(&opt)
 }
@@ -0,0 +1,164 @@
+//! Named constants of solutions that can be changed at runtime, e.g. to run the examples of a puzzle.
+
+use std::any;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// A named constant of a solution, set with `--param <name>=<value>`
+#[derive(Debug, Clone, Copy)]
+pub struct Param {
+    pub name: &'static str,
+    /// The value for the real puzzle input
+    pub default: &'static str,
+    pub description: &'static str,
+    type_name: fn() -> &'static str,
+    accepts: fn(&str) -> bool,
+}
+
+impl Param {
+    /// Declares a parameter with values of type `T`
+    pub const fn new<T: FromStr>(
+        name: &'static str,
+        default: &'static str,
+        description: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            default,
+            description,
+            type_name: any::type_name::<T>,
+            accepts: accepts::<T>,
+        }
+    }
+
+    /// Returns the name of the value type without its path, e.g. `u32` or `String`
+    pub fn type_name(&self) -> &'static str {
+        let name = (self.type_name)();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+
+    fn check(&self, value: &str) -> Result<(), String> {
+        if (self.accepts)(value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid value {value:?} for parameter {}, expected {}",
+                self.name,
+                self.type_name()
+            ))
+        }
+    }
+}
+
+fn accepts<T: FromStr>(value: &str) -> bool {
+    value.parse::<T>().is_ok()
+}
+
+/// The values given for parameters, all others keep their default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Checks that every value belongs to one of the `declared` parameters and has its type
+    pub fn check(&self, declared: &[Param]) -> Result<(), String> {
+        for (name, value) in &self.values {
+            let Some(param) = declared.iter().find(|x| x.name == name) else {
+                let known = declared.iter().map(|x| x.name).collect::<Vec<_>>();
+                return Err(if known.is_empty() {
+                    format!("Unknown parameter {name}, this day has none")
+                } else {
+                    format!(
+                        "Unknown parameter {name}, expected one of: {}",
+                        known.join(", ")
+                    )
+                });
+            };
+            param.check(value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the value of `param`, its default if none was given
+    ///
+    /// # Panics
+    ///
+    /// If the value doesn't have the type of `param` (see [`Params::check`])
+    pub fn get<T: FromStr>(&self, param: &Param) -> T {
+        let value = self.values.get(param.name).map_or(param.default, |x| x);
+        value.parse().unwrap_or_else(|_| {
+            panic!(
+                "Invalid value {value:?} for parameter {}, expected {}",
+                param.name,
+                param.type_name()
+            )
+        })
+    }
+}
+
+impl FromIterator<(String, String)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, String)>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Parses a `<name>=<value>` pair of the CLI
+pub fn parse_pair(pair: &str) -> Result<(String, String), String> {
+    match pair.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("Expected <name>=<value>, got {pair:?}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TIME: Param = Param::new::<u32>("time", "2503", "Seconds the race lasts");
+    const WIRE: Param = Param::new::<String>("wire", "a", "The wire to observe");
+
+    #[test]
+    fn get_works() {
+        let params = [("time", "1000")]
+            .map(|(x, y)| (x.to_string(), y.to_string()))
+            .into_iter()
+            .collect::<Params>();
+        assert_eq!(1000, params.get::<u32>(&TIME));
+        assert_eq!("a", params.get::<String>(&WIRE));
+        assert_eq!(2503, Params::default().get::<u32>(&TIME));
+    }
+
+    #[test]
+    fn check_works() {
+        let params = |name: &str, value: &str| {
+            [(name.to_string(), value.to_string())]
+                .into_iter()
+                .collect::<Params>()
+        };
+        assert!(params("time", "1000").check(&[TIME, WIRE]).is_ok());
+        assert!(params("time", "-1").check(&[TIME, WIRE]).is_err());
+        assert!(params("speed", "1").check(&[TIME, WIRE]).is_err());
+        assert!(params("time", "1").check(&[]).is_err());
+        assert_eq!("u32", TIME.type_name());
+        assert_eq!("String", WIRE.type_name());
+    }
+
+    #[test]
+    fn parse_pair_works() {
+        assert_eq!(
+            Ok((String::from("time"), String::from("1000"))),
+            parse_pair("time=1000")
+        );
+        assert_eq!(
+            Ok((String::from("wire"), String::from("a=b"))),
+            parse_pair("wire=a=b")
+        );
+        assert!(parse_pair("time").is_err());
+        assert!(parse_pair("=1").is_err());
+    }
+}
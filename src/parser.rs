@@ -1,12 +1,45 @@
-use std::{fmt::Debug, str::FromStr};
+use crate::error::ParseError;
+use regex::{Captures, Regex};
+use std::str::FromStr;
+
+/// Parses `text` as number
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(text, "not a number"))
+}
+
+/// Splits `text` at the first `delimiter`
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("expected `{delimiter}`")))
+}
+
+/// Matches `regex` against `text`, `expected` describes the format for the error
+pub fn captures<'a>(
+    regex: &Regex,
+    text: &'a str,
+    expected: &str,
+) -> Result<Captures<'a>, ParseError> {
+    regex
+        .captures(text)
+        .ok_or_else(|| ParseError::new(text, format!("expected {expected}")))
+}
+
+/// Returns the single character of `text`
+pub fn single_char(text: &str) -> Result<char, ParseError> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(ParseError::new(text, "expected a single character")),
+    }
+}
 
 /// Parses each non-empty line as number
-pub fn lines_as_numbers<T>(input: &str) -> Vec<T>
+pub fn lines_as_numbers<T>(input: &str) -> Result<Vec<T>, ParseError>
 where
     T: FromStr,
-    <T as FromStr>::Err: Debug,
 {
-    lines_custom(input, |line| line.parse().unwrap())
+    lines_custom(input, number).into_iter().collect()
 }
 
 /// Interprets each non-empty line as independent string
@@ -19,9 +52,9 @@ pub fn lines_as_strings(input: &str) -> Vec<&str> {
 }
 
 /// Performs mapping on each non-empty line
-pub fn lines_custom<T, F>(input: &str, parse_line: F) -> Vec<T>
+pub fn lines_custom<'a, T, F>(input: &'a str, parse_line: F) -> Vec<T>
 where
-    F: Fn(&str) -> T,
+    F: FnMut(&'a str) -> T,
 {
     input
         .lines()
@@ -31,12 +64,58 @@ where
         .collect()
 }
 
+/// Returns an error for the first character of `text` that is not in `allowed`
+pub fn check_chars(text: &str, allowed: &str) -> Result<(), ParseError> {
+    match text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+        None => Ok(()),
+        Some((i, c)) => Err(ParseError::new(
+            &text[i..i + c.len_utf8()],
+            format!("unexpected character, expected one of `{allowed}`"),
+        )),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn helpers_work() {
+        let input = "12x3";
+        assert_eq!(Ok(12), number::<u8>(&input[..2]));
+        assert_eq!(
+            ParseError::new(input, "not a number"),
+            number::<u8>(input).unwrap_err()
+        );
+        assert_eq!(Ok(("12", "3")), split_once(input, "x"));
+        assert_eq!(
+            ParseError::new(input, "expected `,`"),
+            split_once(input, ",").unwrap_err()
+        );
+        let regex = Regex::new(r"^(\d+)x").unwrap();
+        assert_eq!("12", &captures(&regex, input, "<n>x").unwrap()[1]);
+        assert!(captures(&regex, "x", "<n>x").is_err());
+        assert_eq!(Ok('x'), single_char(&input[2..3]));
+        assert!(single_char(input).is_err());
+        assert_eq!(
+            ParseError::new(
+                &input[2..3],
+                "unexpected character, expected one of `0123456789`"
+            ),
+            check_chars(input, "0123456789").unwrap_err()
+        );
+    }
+
     #[test]
     fn parse_numbers_works() {
-        assert_eq!(lines_as_numbers::<i32>("0\n+1\n\n\n-3"), vec![0, 1, -3]);
+        assert_eq!(
+            lines_as_numbers::<i32>("0\n+1\n\n\n-3").unwrap(),
+            vec![0, 1, -3]
+        );
+        let input = "1\n x2\n";
+        assert_eq!(
+            ParseError::new(&input[3..5], "not a number"),
+            lines_as_numbers::<i32>(input).unwrap_err()
+        );
     }
 }
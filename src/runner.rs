@@ -0,0 +1,279 @@
+//! Runs many registered days in one go, e.g. a whole year.
+
+use crate::cancel;
+use crate::error::AocError;
+use crate::params::Params;
+use crate::solutions::{Answers, Entry, Parts, Timings};
+use std::cell::Cell;
+use std::fs;
+use std::io::ErrorKind;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+use std::thread;
+use std::time::Duration;
+
+thread_local! {
+    /// Whether the messages of panics on this thread are left out, see [`quietly`]
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// The outcome of running a single day
+#[derive(Debug)]
+pub enum Outcome {
+    /// Both parts were calculated
+    Solved(Answers),
+    /// The input file does not exist, so the day was skipped
+    MissingInput(PathBuf),
+    /// The input is malformed
+    Invalid(AocError),
+    /// The solution took longer than allowed
+    TimedOut(Duration),
+    /// The input could not be read or the solution panicked
+    Failed(String),
+}
+
+/// The result of running a single day
+#[derive(Debug)]
+pub struct DayResult {
+    pub entry: &'static Entry,
+    /// The path of the input
+    pub input: PathBuf,
+    pub outcome: Outcome,
+    /// The parts that were calculated
+    pub parts: Parts,
+    /// The time spent in each phase, zero if the day was not solved
+    pub timings: Timings,
+}
+
+impl DayResult {
+    /// Returns `true` if the day failed (a missing input is no failure)
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self.outcome,
+            Outcome::Failed(_) | Outcome::Invalid(_) | Outcome::TimedOut(_)
+        )
+    }
+
+    /// Returns the total time spent on solving
+    pub fn duration(&self) -> Duration {
+        self.timings.total()
+    }
+}
+
+/// Returns the conventional location of an input: `<root>/<year>/day_<dd>.txt`
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("day_{day:02}.txt"))
+}
+
+/// Runs `parts` of every given day with its input from the `inputs` directory and `params`.
+///
+/// Panicking solutions are reported as [`Outcome::Failed`] instead of aborting the run,
+/// with a `timeout` each day runs on a worker thread and is given up on after that time.
+/// Up to `jobs` days run at the same time, the results keep the order of `entries`.
+pub fn run_all(
+    entries: &[&'static Entry],
+    inputs: &Path,
+    parts: Parts,
+    timeout: Option<Duration>,
+    jobs: usize,
+    params: impl Fn(&Entry) -> Params + Sync,
+) -> Vec<DayResult> {
+    let jobs = jobs.clamp(1, entries.len().max(1));
+    quietly(|| {
+        if jobs == 1 {
+            return entries
+                .iter()
+                .map(|entry| run_day(entry, inputs, parts, timeout, params(entry)))
+                .collect();
+        }
+
+        let next = AtomicUsize::new(0);
+        let mut results = thread::scope(|scope| {
+            let workers = (0..jobs)
+                .map(|_| {
+                    scope.spawn(|| {
+                        QUIET.set(true);
+                        let mut results = Vec::new();
+                        loop {
+                            let idx = next.fetch_add(1, Ordering::Relaxed);
+                            let Some(entry) = entries.get(idx) else {
+                                return results;
+                            };
+                            let params = params(entry);
+                            results.push((idx, run_day(entry, inputs, parts, timeout, params)));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        results.sort_unstable_by_key(|(idx, _)| *idx);
+        results.into_iter().map(|(_, result)| result).collect()
+    })
+}
+
+/// Returns the number of days to run at the same time, one per available CPU
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+fn run_day(
+    entry: &'static Entry,
+    inputs: &Path,
+    parts: Parts,
+    timeout: Option<Duration>,
+    params: Params,
+) -> DayResult {
+    let path = input_path(inputs, entry.year, entry.day);
+    let (outcome, timings) = match read_input(entry, inputs) {
+        Ok(input) => match guard(timeout, move || entry.solve_with(&input, parts, &params)) {
+            Ok(Ok((answers, timings))) => (Outcome::Solved(answers), timings),
+            Ok(Err(error)) => (Outcome::Invalid(error), Timings::default()),
+            Err(outcome) => (outcome, Timings::default()),
+        },
+        Err(outcome) => (outcome, Timings::default()),
+    };
+
+    DayResult {
+        entry,
+        input: path,
+        outcome,
+        parts,
+        timings,
+    }
+}
+
+/// Runs `f`, with a `timeout` on a worker thread given up on after that time.
+///
+/// Panics are reported as [`Outcome::Failed`], timeouts as [`Outcome::TimedOut`].
+pub fn guard<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Outcome> {
+    let Some(timeout) = timeout else {
+        return catch(f).map_err(Outcome::Failed);
+    };
+    let quiet = QUIET.get();
+    let worker = move || {
+        QUIET.set(quiet);
+        f()
+    };
+    match cancel::within(timeout, worker) {
+        Some(Ok(result)) => Ok(result),
+        Some(Err(payload)) => Err(Outcome::Failed(panic_message(payload.as_ref()))),
+        None => Err(Outcome::TimedOut(timeout)),
+    }
+}
+
+/// Reads the input of `entry` from the `inputs` directory
+pub fn read_input(entry: &Entry, inputs: &Path) -> Result<String, Outcome> {
+    let path = input_path(inputs, entry.year, entry.day);
+    fs::read_to_string(&path).map_err(|e| {
+        if e.kind() == ErrorKind::NotFound {
+            Outcome::MissingInput(path)
+        } else {
+            Outcome::Failed(format!("{}: {e}", path.display()))
+        }
+    })
+}
+
+/// Runs `f` without printing the messages of caught panics on this thread.
+///
+/// Workers started for `f` by [`run_all`] and [`guard`] keep quiet as well, other threads are not affected.
+/// A panic of `f` is passed on afterwards.
+pub fn quietly<T>(f: impl FnOnce() -> T) -> T {
+    install_panic_hook();
+    let quiet = QUIET.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.set(quiet);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Wraps the panic hook once per process, so it skips the panics of threads inside [`quietly`].
+///
+/// Swapping the hook per call would race with the parallel workers of [`run_all`].
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.get() {
+                hook(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f`, turning a panic into its message
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("solution panicked")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_path_works() {
+        assert_eq!(
+            PathBuf::from("inputs/2021/day_05.txt"),
+            input_path(Path::new("inputs"), 2021, 5)
+        );
+    }
+
+    #[test]
+    fn run_all_keeps_order() {
+        let entries = crate::solutions::all().take(10).collect::<Vec<_>>();
+        let inputs = Path::new("does-not-exist");
+        let results = run_all(&entries, inputs, Parts::Both, None, 4, |_| {
+            Params::default()
+        });
+        assert_eq!(
+            entries.iter().map(|x| (x.year, x.day)).collect::<Vec<_>>(),
+            results
+                .iter()
+                .map(|x| (x.entry.year, x.entry.day))
+                .collect::<Vec<_>>()
+        );
+        assert!(results
+            .iter()
+            .all(|x| matches!(x.outcome, Outcome::MissingInput(_))));
+    }
+
+    #[test]
+    fn quietly_passes_on_panics() {
+        assert_eq!(Ok(42), catch(|| quietly(|| 42)));
+        assert_eq!(
+            Err(String::from("boom")),
+            catch(|| quietly(|| panic!("boom")))
+        );
+        assert!(!QUIET.get());
+    }
+
+    #[test]
+    fn quietly_covers_workers_only() {
+        let timeout = Some(Duration::from_secs(5));
+        assert!(matches!(
+            quietly(|| guard(timeout, || QUIET.get())),
+            Ok(true)
+        ));
+        assert!(matches!(guard(timeout, || QUIET.get()), Ok(false)));
+    }
+}
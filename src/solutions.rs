@@ -1,30 +1,398 @@
+use crate::error::{AocError, ParseError};
+use crate::input::{self, InputMode};
+use crate::params::{Param, Params};
 use crate::util;
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
 
-mod year_2015;
-mod year_2016;
-mod year_2017;
-mod year_2018;
-mod year_2019;
-mod year_2020;
-mod year_2021;
-mod year_2022;
-mod year_2023;
-mod year_2024;
-mod year_2025;
-
-pub fn solve(year: u16, day: u8, input: &str) {
-    match year {
-        15 | 2015 => year_2015::solve_day(day, input),
-        16 | 2016 => year_2016::solve_day(day, input),
-        17 | 2017 => year_2017::solve_day(day, input),
-        18 | 2018 => year_2018::solve_day(day, input),
-        19 | 2019 => year_2019::solve_day(day, input),
-        20 | 2020 => year_2020::solve_day(day, input),
-        21 | 2021 => year_2021::solve_day(day, input),
-        22 | 2022 => year_2022::solve_day(day, input),
-        23 | 2023 => year_2023::solve_day(day, input),
-        24 | 2024 => year_2024::solve_day(day, input),
-        25 | 2025 => year_2025::solve_day(day, input),
-        _ => println!("{}", util::INVALID_YEAR),
+/// Declares the year modules and collects their registered days into [`YEARS`].
+macro_rules! years {
+    ($($year:ident),* $(,)?) => {
+        $(mod $year;)*
+
+        /// The registered days of every year, ordered by year and day
+        const YEARS: &[&[Entry]] = &[$($year::DAYS),*];
+    };
+}
+
+/// Declares the day modules of a year and registers each of them in `DAYS`.
+///
+/// Every day is listed exactly once, so a module can't exist without being dispatched.
+macro_rules! days {
+    ($year:literal; $($day:literal => $module:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        /// The registered days of this year, ordered by day
+        pub const DAYS: &[crate::solutions::Entry] = &[$(
+            crate::solutions::Entry {
+                year: $year,
+                day: $day,
+                solver: |input, parts, params| {
+                    crate::solutions::run::<$module::Puzzle>(input, parts, params)
+                },
+                params: || crate::solutions::params_of::<$module::Puzzle>(),
+                input_mode: || crate::solutions::input_mode_of::<$module::Puzzle>(),
+            }
+        ),*];
+    };
+}
+
+years!(
+    year_2015, year_2016, year_2017, year_2018, year_2019, year_2020, year_2021, year_2022,
+    year_2023, year_2024, year_2025,
+);
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// A numeric answer (covers all integer types used by the solutions)
+    Number(i128),
+    /// A single line of text
+    Text(String),
+    /// Multi-line ASCII art, e.g. letters drawn on a screen
+    Art(String),
+    /// No answer could be found for the given input
+    Unsolved,
+}
+
+impl Answer {
+    /// Returns `true` if the answer spans more than one line
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(_))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(x) => write!(f, "{x}"),
+            Answer::Text(x) | Answer::Art(x) => write!(f, "{x}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(i128::from(value))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Number(value as i128)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+/// The answers to both parts of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Answer,
+    pub part_two: Answer,
+}
+
+impl Answers {
+    /// Returns the answers together with their part number
+    pub fn iter(&self) -> impl Iterator<Item = (u8, &Answer)> {
+        [(1, &self.part_one), (2, &self.part_two)].into_iter()
+    }
+}
+
+/// The parts of a puzzle to calculate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Parts {
+    #[default]
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    /// Selects only `part` (1 or 2), or both parts for `None`
+    pub fn of(part: Option<u8>) -> Self {
+        match part {
+            None => Parts::Both,
+            Some(1) => Parts::One,
+            Some(_) => Parts::Two,
+        }
+    }
+
+    /// Returns `true` if `part` (1 or 2) is calculated
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::One => part == 1,
+            Parts::Two => part == 2,
+        }
+    }
+}
+
+/// A solution for a single day.
+///
+/// The implementing type holds the parsed puzzle input, which is shared by both parts.
+/// It may borrow from the raw input for the lifetime `'a`.
+pub trait Solution<'a>: Sized {
+    /// The named constants of the solution, which can be changed with [`Solution::parse_with`]
+    const PARAMS: &'static [Param] = &[];
+
+    /// How the raw input is prepared before [`Solution::parse`], [`InputMode::Raw`] if its whitespace matters
+    const INPUT_MODE: InputMode = InputMode::Normalized;
+
+    /// Parses the raw puzzle input, reporting the offending part of malformed input
+    fn parse(input: &'a str) -> Result<Self, ParseError>;
+
+    /// Parses the raw puzzle input with the given values of [`Solution::PARAMS`]
+    fn parse_with(input: &'a str, _params: &Params) -> Result<Self, ParseError> {
+        Self::parse(input)
+    }
+
+    /// Calculates the answer to part one
+    fn part_one(&self) -> Answer;
+
+    /// Calculates the answer to part two
+    fn part_two(&self) -> Answer;
+}
+
+/// The time spent in each phase of a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Returns the [`Solution::PARAMS`] of `S`
+pub fn params_of<'a, S: Solution<'a>>() -> &'static [Param] {
+    S::PARAMS
+}
+
+/// Returns the [`Solution::INPUT_MODE`] of `S`
+pub fn input_mode_of<'a, S: Solution<'a>>() -> InputMode {
+    S::INPUT_MODE
+}
+
+/// Parses `input` once and calculates the selected `parts` with the solution `S`, timing each phase.
+///
+/// A part that is not selected is left [`Answer::Unsolved`] with no time spent.
+pub fn run<'a, S: Solution<'a>>(
+    input: &'a str,
+    parts: Parts,
+    params: &Params,
+) -> Result<(Answers, Timings), ParseError> {
+    let start = Instant::now();
+    let puzzle = S::parse_with(input, params).map_err(|e| e.locate(input))?;
+    let parse = start.elapsed();
+
+    let (part_one, part_one_time) = timed(parts.includes(1), || puzzle.part_one());
+    let (part_two, part_two_time) = timed(parts.includes(2), || puzzle.part_two());
+
+    let answers = Answers { part_one, part_two };
+    let timings = Timings {
+        parse,
+        part_one: part_one_time,
+        part_two: part_two_time,
+    };
+    Ok((answers, timings))
+}
+
+fn timed(selected: bool, part: impl FnOnce() -> Answer) -> (Answer, Duration) {
+    if !selected {
+        return (Answer::Unsolved, Duration::ZERO);
+    }
+    let start = Instant::now();
+    let answer = part();
+    (answer, start.elapsed())
+}
+
+/// Solves the given parts of an input with a specific [`Solution`], see [`run`]
+type Solver = fn(&str, Parts, &Params) -> Result<(Answers, Timings), ParseError>;
+
+/// A registered solution for a single day
+#[derive(Debug)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    solver: Solver,
+    params: fn() -> &'static [Param],
+    input_mode: fn() -> InputMode,
+}
+
+impl Entry {
+    /// Returns the parameters the solution accepts
+    pub fn params(&self) -> &'static [Param] {
+        (self.params)()
+    }
+
+    /// Returns how the solution wants its input prepared
+    pub fn input_mode(&self) -> InputMode {
+        (self.input_mode)()
+    }
+
+    /// Solves the selected `parts` for `input`
+    pub fn solve(&self, input: &str, parts: Parts) -> Result<Answers, AocError> {
+        self.solve_timed(input, parts).map(|(answers, _)| answers)
+    }
+
+    /// Solves the selected `parts` for `input` and reports the time spent in each phase
+    pub fn solve_timed(&self, input: &str, parts: Parts) -> Result<(Answers, Timings), AocError> {
+        self.solve_with(input, parts, &Params::default())
+    }
+
+    /// Like [`Entry::solve_timed`] with the given parameter values, see [`Params::check`].
+    ///
+    /// The input is prepared according to [`Entry::input_mode`] first, changes are logged.
+    pub fn solve_with(
+        &self,
+        input: &str,
+        parts: Parts,
+        params: &Params,
+    ) -> Result<(Answers, Timings), AocError> {
+        let parse_error = |error| AocError::Parse {
+            year: self.year,
+            day: self.day,
+            error,
+        };
+        let (input, changes) = input::prepare(input, self.input_mode()).map_err(parse_error)?;
+        if !changes.is_empty() {
+            log::info!("{} day {}: {changes}", self.year, self.day);
+        }
+        (self.solver)(&input, parts, params).map_err(parse_error)
+    }
+}
+
+/// Returns all registered days, ordered by year and day
+pub fn all() -> impl Iterator<Item = &'static Entry> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+/// Returns the registered days of `year` (2 or 4 digits)
+pub fn of_year(year: u16) -> impl Iterator<Item = &'static Entry> {
+    let year = normalize_year(year);
+    all().filter(move |x| x.year == year)
+}
+
+/// Expands a 2-digit year like `15` to `2015`, 4-digit years are kept
+pub fn normalize_year(year: u16) -> u16 {
+    if year < 100 {
+        2000 + year
+    } else {
+        year
+    }
+}
+
+/// Looks up the registered day for `year` (2 or 4 digits) and `day`
+pub fn find(year: u16, day: u8) -> Result<&'static Entry, &'static str> {
+    let mut days = of_year(year).peekable();
+    if days.peek().is_none() {
+        return Err(util::INVALID_YEAR);
+    }
+    days.find(|x| x.day == day).ok_or(util::INVALID_DAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answer_from_works() {
+        assert_eq!(Answer::Number(42), 42_u8.into());
+        assert_eq!(Answer::Number(-7), (-7_isize).into());
+        assert_eq!(Answer::Text("abc".to_string()), "abc".into());
+        assert_eq!(Answer::Number(1), Some(1_u64).into());
+        assert_eq!(Answer::Unsolved, Option::<u64>::None.into());
+    }
+
+    struct Halting;
+
+    impl Solution<'_> for Halting {
+        fn parse(_input: &str) -> Result<Self, ParseError> {
+            Ok(Self)
+        }
+
+        fn part_one(&self) -> Answer {
+            Answer::Number(1)
+        }
+
+        fn part_two(&self) -> Answer {
+            panic!("part two must not be calculated")
+        }
+    }
+
+    #[test]
+    fn run_selects_parts() {
+        let (answers, timings) = run::<Halting>("", Parts::One, &Params::default()).unwrap();
+        assert_eq!(Answer::Number(1), answers.part_one);
+        assert_eq!(Answer::Unsolved, answers.part_two);
+        assert_eq!(Duration::ZERO, timings.part_two);
+        assert_eq!(Parts::Two, Parts::of(Some(2)));
+        assert!(Parts::of(None).includes(2));
+    }
+
+    #[test]
+    fn registry_is_sorted_and_unique() {
+        let keys = all().map(|x| (x.year, x.day)).collect::<Vec<_>>();
+        let mut sorted = keys.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted, keys);
+    }
+
+    #[test]
+    fn param_defaults_are_valid() {
+        for entry in all() {
+            let defaults = entry
+                .params()
+                .iter()
+                .map(|x| (x.name.to_string(), x.default.to_string()))
+                .collect::<Params>();
+            assert_eq!(Ok(()), defaults.check(entry.params()));
+        }
+    }
+
+    #[test]
+    fn find_works() {
+        assert_eq!((2015, 7), find(15, 7).map(|x| (x.year, x.day)).unwrap());
+        assert_eq!((2015, 7), find(2015, 7).map(|x| (x.year, x.day)).unwrap());
+        assert_eq!(Some(util::INVALID_DAY), find(2020, 14).err());
+        assert_eq!(Some(util::INVALID_YEAR), find(2014, 1).err());
+        assert_eq!(Some(util::INVALID_YEAR), find(14, 1).err());
     }
 }
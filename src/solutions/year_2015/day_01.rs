@@ -1,8 +1,24 @@
-pub fn solve(input: &str) {
-    let instructions = parse(input);
-    let (floor, index) = count(&instructions);
-    println!("Part 1: {floor}");
-    println!("Part 2: {index}");
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instructions: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        final_floor(&self.instructions).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        first_basement(&self.instructions).into()
+    }
 }
 
 enum Instruction {
@@ -10,40 +26,41 @@ enum Instruction {
     GoDown,
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
     input
-        .chars()
-        .map(|c| match c {
-            '(' => Instruction::GoUp,
-            ')' => Instruction::GoDown,
-            _ => panic!("Unexpected char: {c}"),
+        .char_indices()
+        .map(|(i, c)| match c {
+            '(' => Ok(Instruction::GoUp),
+            ')' => Ok(Instruction::GoDown),
+            _ => Err(ParseError::new(
+                &input[i..i + c.len_utf8()],
+                "expected `(` or `)`",
+            )),
         })
         .collect()
 }
 
-fn count(instructions: &[Instruction]) -> (i32, i32) {
-    let mut floor = 0;
-    let mut index = 1;
-    let mut reached_basement = false;
+fn step(instruction: &Instruction) -> i32 {
+    match instruction {
+        Instruction::GoUp => 1,
+        Instruction::GoDown => -1,
+    }
+}
 
-    for instuction in instructions {
-        floor += match instuction {
-            Instruction::GoUp => 1,
-            Instruction::GoDown => -1,
-        };
+fn final_floor(instructions: &[Instruction]) -> i32 {
+    instructions.iter().map(step).sum()
+}
 
+/// Returns the 1-based position of the instruction entering the basement, -1 if never
+fn first_basement(instructions: &[Instruction]) -> i32 {
+    let mut floor = 0;
+    for (index, instruction) in instructions.iter().enumerate() {
+        floor += step(instruction);
         if floor == -1 {
-            reached_basement = true;
-        } else if !reached_basement {
-            index += 1;
+            return index as i32 + 1;
         }
     }
-
-    if !reached_basement {
-        index = -1;
-    }
-
-    (floor, index)
+    -1
 }
 
 #[cfg(test)]
@@ -51,13 +68,11 @@ mod tests {
     use super::*;
 
     fn count_floor(input: &str) -> i32 {
-        let instructions = parse(input);
-        count(&instructions).0
+        final_floor(&parse(input).unwrap())
     }
 
     fn first_base(input: &str) -> i32 {
-        let instructions = parse(input);
-        count(&instructions).1
+        first_basement(&parse(input).unwrap())
     }
 
     #[test]
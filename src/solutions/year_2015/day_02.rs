@@ -1,28 +1,43 @@
+use crate::error::ParseError;
+use crate::parser::{lines_custom, number, split_once};
+use crate::solutions::{Answer, Solution};
 use std::cmp::{max, min};
-use std::str::FromStr;
-
-pub fn solve(input: &str) {
-    let mut paper = 0;
-    let mut ribbon = 0;
-    for line in input.lines() {
-        let dimensions = parse_line(line);
-        paper += calc_paper(dimensions) + extra_paper(dimensions);
-        ribbon += calc_ribbon(dimensions);
-    }
-    println!("Paper:  {paper}");
-    println!("Ribbon: {ribbon}");
+
+pub struct Puzzle {
+    presents: Vec<(u32, u32, u32)>,
 }
 
-fn parse_line(line: &str) -> (u32, u32, u32) {
-    let numbers: Vec<&str> = line.split('x').collect();
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            presents: lines_custom(input, parse_line)
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
+    }
 
-    assert!(numbers.len() == 3, "Unexpected count of numbers in line.");
+    fn part_one(&self) -> Answer {
+        self.presents
+            .iter()
+            .map(|&dimensions| calc_paper(dimensions) + extra_paper(dimensions))
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.presents
+            .iter()
+            .map(|&dimensions| calc_ribbon(dimensions))
+            .sum::<u32>()
+            .into()
+    }
+}
 
-    let length = u32::from_str(numbers.first().unwrap()).expect("Could not parse!");
-    let width = u32::from_str(numbers.get(1).unwrap()).expect("Could not parse!");
-    let height = u32::from_str(numbers.get(2).unwrap()).expect("Could not parse!");
+fn parse_line(line: &str) -> Result<(u32, u32, u32), ParseError> {
+    let (length, rest) = split_once(line, "x")?;
+    let (width, height) = split_once(rest, "x")?;
 
-    (length, width, height)
+    Ok((number(length)?, number(width)?, number(height)?))
 }
 
 fn calc_paper((l, w, h): (u32, u32, u32)) -> u32 {
@@ -43,8 +58,9 @@ mod tests {
 
     #[test]
     fn example() {
-        assert_eq!((2, 3, 4), parse_line("2x3x4"));
-        assert_eq!((1, 1, 10), parse_line("1x1x10"));
+        assert_eq!(Ok((2, 3, 4)), parse_line("2x3x4"));
+        assert_eq!(Ok((1, 1, 10)), parse_line("1x1x10"));
+        assert!(parse_line("2x3").is_err());
 
         assert_eq!(52, calc_paper((2, 3, 4)));
         assert_eq!(42, calc_paper((1, 1, 10)));
@@ -1,10 +1,25 @@
+use crate::error::ParseError;
+use crate::parser::check_chars;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashSet;
 
-pub fn solve(input: &str) {
-    let year1 = houses_visited(&get_positions(input));
-    let year2 = houses_visited(&get_positions_with_robot(input));
-    println!("Year 1: {year1}");
-    println!("Year 2: {year2}");
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        check_chars(input, "^>v<")?;
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        houses_visited(&get_positions(self.input)).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        houses_visited(&get_positions_with_robot(self.input)).into()
+    }
 }
 
 fn houses_visited(positions: &[(i32, i32)]) -> u32 {
@@ -64,7 +79,7 @@ impl Position {
             '>' => self.go_east(),
             'v' => self.go_south(),
             '<' => self.go_west(),
-            _ => panic!("Unexpected char: {c}"),
+            _ => unreachable!("checked by parse"),
         }
     }
 }
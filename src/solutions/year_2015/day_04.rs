@@ -1,11 +1,31 @@
-pub fn solve(input: &str) {
-    println!("5 leading Zeros: {}", find_number(input, 5));
-    println!("6 leading Zeros: {}", find_number(input, 6));
+use crate::cancel;
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        find_number(&self.input, 5).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_number(&self.input, 6).into()
+    }
 }
 
 fn find_number(input: &str, leading_zeros: usize) -> u32 {
     let prefix = "0".repeat(leading_zeros);
     for i in 1.. {
+        cancel::checkpoint();
         if md5::compute(format!("{input}{}", &i)).starts_with(prefix.as_bytes()) {
             return i;
         }
@@ -18,7 +38,7 @@ mod tests {
     use super::find_number;
 
     #[test]
-    #[ignore]
+    #[ignore = "slow md5 brute force"]
     fn example() {
         assert_eq!(609_043, find_number("abcdef", 5));
         assert_eq!(1_048_970, find_number("pqrstuv", 5));
@@ -1,18 +1,25 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let mut counter1 = 0;
-    let mut counter2 = 0;
-    for line in input.lines() {
-        if is_nice_old(line) {
-            counter1 += 1;
-        }
-        if is_nice(line) {
-            counter2 += 1;
-        }
+pub struct Puzzle<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: input.lines().collect(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.lines.iter().filter(|x| is_nice_old(x)).count().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.lines.iter().filter(|x| is_nice(x)).count().into()
     }
-    println!("Part 1: {counter1}");
-    println!("Part 2: {counter2}");
 }
 
 fn is_nice(text: &str) -> bool {
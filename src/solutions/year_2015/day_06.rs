@@ -1,3 +1,6 @@
+use crate::error::ParseError;
+use crate::parser::{captures, lines_custom, number};
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 
 const WIDTH: usize = 1000;
@@ -5,33 +8,75 @@ const HEIGHT: usize = 1000;
 
 type Matrix<T> = Vec<Vec<T>>;
 
-pub fn solve(input: &str) {
-    let result = main_internal(input);
-    println!("Lights on:  {}", result.0);
-    println!("Brightness: {}", result.1);
+pub struct Puzzle<'a> {
+    instructions: Vec<Instruction<'a>>,
 }
 
-fn main_internal(input: &str) -> (u32, u32) {
-    let mut io_matrix = create_2d_matrix(WIDTH, HEIGHT, false);
-    let mut led_matrix = create_2d_matrix(WIDTH, HEIGHT, 0);
-    let regex = Regex::new(r"^(.+) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
-    for line in input.lines() {
-        let cap = regex
-            .captures(line)
-            .unwrap_or_else(|| panic!("Invalid cmd: {}", &line));
-        let cmd = cap.get(1).unwrap().as_str();
-        let start = (
-            cap.get(2).unwrap().as_str().parse::<usize>().unwrap(),
-            cap.get(3).unwrap().as_str().parse::<usize>().unwrap(),
-        );
-        let stop = (
-            cap.get(4).unwrap().as_str().parse::<usize>().unwrap(),
-            cap.get(5).unwrap().as_str().parse::<usize>().unwrap(),
-        );
-        perform(&mut io_matrix, start, stop, get_io_operation(cmd));
-        perform(&mut led_matrix, start, stop, get_led_operation(cmd));
-    }
-    (count_lit_lights(&io_matrix), sum_brightness(&led_matrix))
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instructions: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        lit_lights(&self.instructions).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        brightness(&self.instructions).into()
+    }
+}
+
+/// A command applied to the rectangle from `start` to `stop` (inclusive)
+struct Instruction<'a> {
+    command: &'a str,
+    start: (usize, usize),
+    stop: (usize, usize),
+}
+
+fn parse(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    let regex = Regex::new(r"^(toggle|turn on|turn off) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
+    lines_custom(input, |line| {
+        let cap = captures(
+            &regex,
+            line,
+            "`toggle|turn on|turn off <x>,<y> through <x>,<y>`",
+        )?;
+        let coordinate = |idx, size| {
+            let text = cap.get(idx).unwrap().as_str();
+            let value = number::<usize>(text)?;
+            if value >= size {
+                return Err(ParseError::new(text, format!("expected less than {size}")));
+            }
+            Ok(value)
+        };
+        Ok(Instruction {
+            command: cap.get(1).unwrap().as_str(),
+            start: (coordinate(2, WIDTH)?, coordinate(3, HEIGHT)?),
+            stop: (coordinate(4, WIDTH)?, coordinate(5, HEIGHT)?),
+        })
+    })
+    .into_iter()
+    .collect()
+}
+
+fn lit_lights(instructions: &[Instruction]) -> u32 {
+    let mut matrix = create_2d_matrix(WIDTH, HEIGHT, false);
+    for instruction in instructions {
+        let operation = get_io_operation(instruction.command);
+        perform(&mut matrix, instruction.start, instruction.stop, operation);
+    }
+    count_lit_lights(&matrix)
+}
+
+fn brightness(instructions: &[Instruction]) -> u32 {
+    let mut matrix = create_2d_matrix(WIDTH, HEIGHT, 0);
+    for instruction in instructions {
+        let operation = get_led_operation(instruction.command);
+        perform(&mut matrix, instruction.start, instruction.stop, operation);
+    }
+    sum_brightness(&matrix)
 }
 
 fn sum_brightness(matrix: &[Vec<u32>]) -> u32 {
@@ -103,10 +148,15 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::{main_internal, HEIGHT, WIDTH};
+    use super::*;
 
     const ALL: u32 = (HEIGHT * WIDTH) as u32;
 
+    fn main_internal(input: &str) -> (u32, u32) {
+        let instructions = parse(input).unwrap();
+        (lit_lights(&instructions), brightness(&instructions))
+    }
+
     #[test]
     fn example_empty() {
         let result = main_internal("");
@@ -148,4 +198,10 @@ mod tests {
         assert_eq!(ALL, result.0);
         assert_eq!(ALL * 2, result.1);
     }
+
+    #[test]
+    fn outside_the_grid() {
+        assert!(parse("turn on 0,0 through 1000,999").is_err());
+        assert!(parse("toggle 0,1000 through 0,0").is_err());
+    }
 }
@@ -1,6 +1,9 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
-use std::collections::HashMap;
-use std::ops::{BitAnd, BitOr, Shl, Shr};
+use std::collections::{HashMap, HashSet};
+use std::ops::{BitAnd, BitOr};
 
 type BaseType = u16;
 
@@ -10,32 +13,86 @@ const REGEX_NAME: &str = r"^\w+$";
 const REGEX_BINARY_OPERATOR: &str = r"^(\w+) (\w+) (\w+)";
 const REGEX_NOT: &str = r"^NOT (\w+)";
 
-const WIRE_TO_OBSERVE: &str = "a";
+const WIRE_TO_OBSERVE: Param = Param::new::<String>("wire", "a", "The wire to observe");
 const WIRE_TO_CHANGE: &str = "b";
 
-pub fn solve(input: &str) {
-    let result = internal(input, WIRE_TO_OBSERVE);
-    println!("P1 - Wire a: {}", result.0);
-    println!("P2 - Wire a: {}", result.1);
+#[derive(Debug)]
+pub struct Puzzle {
+    /// The wires set to a signal directly
+    wires: HashMap<String, BaseType>,
+    instructions: Vec<Instruction>,
+    observed_wire: String,
 }
 
-fn internal(input: &str, observed_wire: &str) -> (BaseType, BaseType) {
-    let mut wires: HashMap<String, BaseType> = HashMap::new();
-    let mut instructions = Vec::new();
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[WIRE_TO_OBSERVE];
 
-    first_read(input, &mut wires, &mut instructions);
-    apply_instructions(&mut wires, &instructions);
-    let signal_one = *wires.get(observed_wire).unwrap();
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        Self::new(input, &params.get::<String>(&WIRE_TO_OBSERVE))
+    }
 
-    wires.clear();
-    instructions.clear();
+    fn part_one(&self) -> Answer {
+        self.first_signal().into()
+    }
 
-    first_read(input, &mut wires, &mut instructions);
-    wires.insert(String::from(WIRE_TO_CHANGE), signal_one);
-    apply_instructions(&mut wires, &instructions);
-    let signal_two = *wires.get(observed_wire).unwrap();
+    fn part_two(&self) -> Answer {
+        self.second_signal().into()
+    }
+}
 
-    (signal_one, signal_two)
+impl Puzzle {
+    fn new(input: &str, observed_wire: &str) -> Result<Self, ParseError> {
+        let mut wires = HashMap::new();
+        let mut instructions = Vec::new();
+        first_read(input, &mut wires, &mut instructions)?;
+        if !wires.contains_key(observed_wire)
+            && !instructions.iter().any(|x| x.get_aim() == observed_wire)
+        {
+            return Err(ParseError::new(observed_wire, "wire gets no signal"));
+        }
+        let defined = wires
+            .keys()
+            .cloned()
+            .chain(instructions.iter().map(Instruction::get_aim))
+            .collect::<HashSet<_>>();
+        let undefined = instructions
+            .iter()
+            .flat_map(Instruction::inputs)
+            .find_map(|x| match x {
+                Symbol::Name(wire) if !defined.contains(wire) => Some(wire),
+                _ => None,
+            });
+        if let Some(wire) = undefined {
+            return Err(ParseError::new(wire, "wire gets no signal"));
+        }
+        Ok(Self {
+            wires,
+            instructions,
+            observed_wire: observed_wire.to_string(),
+        })
+    }
+
+    /// Returns the signal of the observed wire once all instructions are applied,
+    /// `None` if it depends on itself
+    fn signal(&self, mut wires: HashMap<String, BaseType>) -> Option<BaseType> {
+        apply_instructions(&mut wires, &self.instructions);
+        wires.get(&self.observed_wire).copied()
+    }
+
+    fn first_signal(&self) -> Option<BaseType> {
+        self.signal(self.wires.clone())
+    }
+
+    /// Returns the signal after overriding the wire to change with the first signal
+    fn second_signal(&self) -> Option<BaseType> {
+        let mut wires = self.wires.clone();
+        wires.insert(String::from(WIRE_TO_CHANGE), self.first_signal()?);
+        self.signal(wires)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -45,23 +102,18 @@ enum Symbol {
 }
 
 impl Symbol {
-    fn parse(input: &str) -> Option<Self> {
+    fn parse(input: &str) -> Result<Self, ParseError> {
         let reg_number = Regex::new(REGEX_NUMBER).unwrap();
-        let cap_num = reg_number.captures(input);
 
         if input.contains(' ') {
-            Option::None
-        } else if cap_num.is_some() {
-            let signal = cap_num
-                .unwrap()
-                .get(0)
-                .unwrap()
-                .as_str()
+            Err(ParseError::new(input, "expected a signal or wire"))
+        } else if reg_number.is_match(input) {
+            input
                 .parse::<BaseType>()
-                .unwrap();
-            Some(Symbol::Number(signal))
+                .map(Symbol::Number)
+                .map_err(|_| ParseError::new(input, "signal out of range"))
         } else {
-            Some(Symbol::Name(input.to_string()))
+            Ok(Symbol::Name(input.to_string()))
         }
     }
 
@@ -96,17 +148,36 @@ impl Instruction {
         }
     }
 
-    fn from_binary(left: &str, operator: &str, right: &str, wire: String) -> Self {
-        let sym_left = Symbol::parse(left).unwrap();
-        let sym_right = Symbol::parse(right).unwrap();
+    /// Returns the symbols the signal is calculated from
+    fn inputs(&self) -> Vec<&Symbol> {
+        match self {
+            Instruction::Assign(a, _) | Instruction::Not(a, _) => vec![a],
+            Instruction::And(a, b, _)
+            | Instruction::Or(a, b, _)
+            | Instruction::LShift(a, b, _)
+            | Instruction::RShift(a, b, _) => vec![a, b],
+        }
+    }
+
+    fn from_binary(
+        left: &str,
+        operator: &str,
+        right: &str,
+        wire: String,
+    ) -> Result<Self, ParseError> {
+        let sym_left = Symbol::parse(left)?;
+        let sym_right = Symbol::parse(right)?;
+        if operator.ends_with("SHIFT")
+            && matches!(sym_right, Symbol::Number(x) if x >= BaseType::BITS as BaseType)
+        {
+            return Err(ParseError::new(right, "shift out of range"));
+        }
         match operator {
-            "AND" => Instruction::And(sym_left, sym_right, wire),
-            "OR" => Instruction::Or(sym_left, sym_right, wire),
-            "LSHIFT" => Instruction::LShift(sym_left, sym_right, wire),
-            "RSHIFT" => Instruction::RShift(sym_left, sym_right, wire),
-            _ => {
-                panic!("Unknown Operator: {operator}");
-            }
+            "AND" => Ok(Instruction::And(sym_left, sym_right, wire)),
+            "OR" => Ok(Instruction::Or(sym_left, sym_right, wire)),
+            "LSHIFT" => Ok(Instruction::LShift(sym_left, sym_right, wire)),
+            "RSHIFT" => Ok(Instruction::RShift(sym_left, sym_right, wire)),
+            _ => Err(ParseError::new(operator, "unknown operator")),
         }
     }
 
@@ -116,19 +187,21 @@ impl Instruction {
         reg_not: &Regex,
         expression: &str,
         wire: &str,
-        line: &str,
-    ) -> Self {
+    ) -> Result<Self, ParseError> {
         let cap_binary = reg_binary_operator.captures(expression);
         let cap_name = reg_name.captures(expression);
         let cap_not = reg_not.captures(expression);
 
         if cap_name.is_some() {
-            Instruction::Assign(Symbol::parse(expression).unwrap(), wire.to_string())
+            Ok(Instruction::Assign(
+                Symbol::parse(expression)?,
+                wire.to_string(),
+            ))
         } else if let Some(not) = cap_not {
-            Instruction::Not(
-                Symbol::parse(not.get(1).unwrap().as_str()).unwrap(),
+            Ok(Instruction::Not(
+                Symbol::parse(not.get(1).unwrap().as_str())?,
                 wire.to_string(),
-            )
+            ))
         } else if let Some(op_expression) = cap_binary {
             Instruction::from_binary(
                 op_expression.get(1).unwrap().as_str(),
@@ -137,14 +210,16 @@ impl Instruction {
                 wire.to_string(),
             )
         } else {
-            panic!("Line looks weird: {line}");
+            Err(ParseError::new(expression, "unknown expression"))
         }
     }
 }
 
+/// Applies the instructions until every wire has a signal, or no more can get one
 fn apply_instructions(wires: &mut HashMap<String, BaseType>, instructions: &[Instruction]) {
     let mut applied_instructions = Vec::new();
     while applied_instructions.len() < instructions.len() {
+        let applied_before = applied_instructions.len();
         for instruction in instructions {
             let signal = perform_operation(wires, instruction);
             if let Some(sig) = signal {
@@ -154,6 +229,9 @@ fn apply_instructions(wires: &mut HashMap<String, BaseType>, instructions: &[Ins
                 }
             }
         }
+        if applied_instructions.len() == applied_before {
+            return;
+        }
     }
 }
 
@@ -161,7 +239,7 @@ fn first_read(
     input: &str,
     wires: &mut HashMap<String, BaseType>,
     instructions: &mut Vec<Instruction>,
-) {
+) -> Result<(), ParseError> {
     let reg_instruction = Regex::new(REGEX_INSTRUCTION).unwrap();
     let reg_binary_operator = Regex::new(REGEX_BINARY_OPERATOR).unwrap();
     let reg_name = Regex::new(REGEX_NAME).unwrap();
@@ -170,28 +248,25 @@ fn first_read(
     for line in input.lines() {
         let captures = reg_instruction
             .captures(line)
-            .unwrap_or_else(|| panic!("Invalid line: {}", &line));
+            .ok_or_else(|| ParseError::new(line, "expected `<expression> -> <wire>`"))?;
 
         let wire = captures.get(2).unwrap().as_str().to_string();
         let expression = captures.get(1).unwrap().as_str();
-        let option_symbol = Symbol::parse(expression);
-        if let Some(symbol) = option_symbol {
-            if let Symbol::Number(a) = &symbol {
-                wires.insert(wire, *a);
-            } else {
-                instructions.push(Instruction::Assign(symbol, wire));
-            }
-        } else {
+        if expression.contains(' ') {
             instructions.push(Instruction::parse(
                 &reg_name,
                 &reg_binary_operator,
                 &reg_not,
                 expression,
                 &wire,
-                line,
-            ));
+            )?);
+        } else if let Symbol::Number(a) = Symbol::parse(expression)? {
+            wires.insert(wire, a);
+        } else {
+            instructions.push(Instruction::Assign(Symbol::parse(expression)?, wire));
         }
     }
+    Ok(())
 }
 
 fn perform_operation(
@@ -214,12 +289,16 @@ fn perform_operation(
         Instruction::LShift(a, b, _) => {
             let option_a = a.interpret(wires);
             let option_b = b.interpret(wires);
-            apply_binary(option_a, option_b, BaseType::shl)
+            apply_binary(option_a, option_b, |x, y| {
+                x.checked_shl(u32::from(y)).unwrap_or_default()
+            })
         }
         Instruction::RShift(a, b, _) => {
             let option_a = a.interpret(wires);
             let option_b = b.interpret(wires);
-            apply_binary(option_a, option_b, BaseType::shr)
+            apply_binary(option_a, option_b, |x, y| {
+                x.checked_shr(u32::from(y)).unwrap_or_default()
+            })
         }
     }
 }
@@ -238,7 +317,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use super::internal;
+    use super::*;
 
     #[test]
     fn example() {
@@ -250,13 +329,27 @@ x LSHIFT 2 -> f
 y RSHIFT 2 -> g
 NOT x -> h
 NOT y -> i";
-        assert_eq!(internal(input, "d").0, 72);
-        assert_eq!(internal(input, "e").0, 507);
-        assert_eq!(internal(input, "f").0, 492);
-        assert_eq!(internal(input, "g").0, 114);
-        assert_eq!(internal(input, "h").0, 65412);
-        assert_eq!(internal(input, "i").0, 65079);
-        assert_eq!(internal(input, "x").0, 123);
-        assert_eq!(internal(input, "y").0, 456);
+        let signal = |wire| Puzzle::new(input, wire).unwrap().first_signal().unwrap();
+        assert_eq!(signal("d"), 72);
+        assert_eq!(signal("e"), 507);
+        assert_eq!(signal("f"), 492);
+        assert_eq!(signal("g"), 114);
+        assert_eq!(signal("h"), 65412);
+        assert_eq!(signal("i"), 65079);
+        assert_eq!(signal("x"), 123);
+        assert_eq!(signal("y"), 456);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let input = "123 -> x\nx XOR y -> d";
+        let error = Puzzle::new(input, "d").unwrap_err();
+        assert_eq!(ParseError::new(&input[11..14], "unknown operator"), error);
+        assert_eq!(Some((2, 3)), error.locate(input).position);
+        assert!(Puzzle::new("123 -> x", "z").is_err());
+        assert!(Puzzle::new("x AND z -> a\n1 -> x", "a").is_err());
+        assert!(Puzzle::new("1 -> x\nx LSHIFT 16 -> a", "a").is_err());
+        let looped = Puzzle::new("a -> b\nb -> a", "a").unwrap();
+        assert_eq!(None, looped.first_signal());
     }
 }
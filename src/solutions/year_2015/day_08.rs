@@ -1,24 +1,32 @@
 use std::fmt::Write;
 
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 
 const REPLACEMENT_STR: &str = "_";
 
-pub fn solve(input: &str) {
-    let result = count(input);
-    let encoded = count(&encode(input));
-    println!(
-        "P1: {} ({} - {})",
-        result.calc(),
-        result.number_of_code,
-        result.number_of_chars
-    );
-    println!(
-        "P2: {} ({} - {})",
-        encoded.calc(),
-        encoded.number_of_code,
-        encoded.number_of_chars
-    );
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        for line in input.lines() {
+            if line.len() < 2 || !line.starts_with('"') || !line.ends_with('"') {
+                return Err(ParseError::new(line, "expected a string in quotes"));
+            }
+        }
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        count(self.input).calc().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        count(&encode(self.input)).calc().into()
+    }
 }
 
 fn encode(input: &str) -> String {
@@ -58,7 +66,7 @@ fn count(input: &str) -> RiddleResult {
     for line in input.lines() {
         let mut inside = regex
             .captures(line)
-            .unwrap_or_else(|| panic!("This is not surrounded by \": {line}"))
+            .expect("quotes checked by parse")
             .get(1)
             .unwrap()
             .as_str()
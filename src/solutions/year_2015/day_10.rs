@@ -1,12 +1,29 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use std::fmt::Write;
 
-pub fn solve(input: &str) {
-    let mut result_one = input.to_string();
-    (0..40).for_each(|_| result_one = look_and_say(&result_one));
-    let mut result_two = result_one.clone();
-    println!("Part 1: {}", result_one.len());
-    (0..10).for_each(|_| result_two = look_and_say(&result_two));
-    println!("Part 2: {}", result_two.len());
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        look_and_say_times(self.input, 40).len().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        look_and_say_times(self.input, 50).len().into()
+    }
+}
+
+fn look_and_say_times(input: &str, times: usize) -> String {
+    let mut result = input.to_string();
+    (0..times).for_each(|_| result = look_and_say(&result));
+    result
 }
 
 const NULL_CHAR: char = '_';
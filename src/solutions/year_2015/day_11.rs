@@ -1,4 +1,30 @@
-pub fn solve(input: &str) {
+use crate::error::ParseError;
+use crate::parser::check_chars;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        check_chars(input, "abcdefghijklmnopqrstuvwxyz")?;
+        if input.len() < 3 {
+            return Err(ParseError::new(input, "expected at least 3 letters"));
+        }
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        next_password(self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        next_password(&next_password(self.input)).into()
+    }
+}
+
+fn next_password(input: &str) -> String {
     let mut new_password = increment(input);
 
     if !input.is_empty() {
@@ -7,7 +33,7 @@ pub fn solve(input: &str) {
         }
     }
 
-    println!("{new_password}");
+    new_password
 }
 
 fn increment(input: &str) -> String {
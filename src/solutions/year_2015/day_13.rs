@@ -1,36 +1,60 @@
 use crate::{parser, util};
 
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use regex::Regex;
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let rules = parse(input);
-    println!("Part 1: {}", part_one(&rules));
-    println!("Part 2: {}", part_two(&rules));
+pub struct Puzzle {
+    rules: Rules,
 }
 
-fn parse(input: &str) -> Rules {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rules: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.rules).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.rules).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Rules, ParseError> {
     let mut rules = Rules::new();
-    for (from, to, happiness) in parser::lines_custom(input, parse_rule) {
+    for rule in parser::lines_custom(input, parse_rule) {
+        let (from, to, happiness) = rule?;
         rules.add((&from, &to, happiness));
     }
+    if rules.rules.is_empty() {
+        return Err(ParseError::new(input, "expected at least one rule"));
+    }
 
-    rules
+    Ok(rules)
 }
 
-fn parse_rule(line: &str) -> (String, String, i32) {
+fn parse_rule(line: &str) -> Result<(String, String, i32), ParseError> {
     // Alice would gain 54 happiness units by sitting next to Bob.
     let regex =
-        Regex::new(r"(\w+) would (\w+) (\d+) happiness units by sitting next to (\w+).").unwrap();
-    let capture = regex.captures(line).expect("Looks weird");
-
-    let from = capture[1].parse::<String>().unwrap();
-    let gain = capture[2].parse::<String>().unwrap();
-    let factor = if gain == "gain" { 1 } else { -1 };
-    let happiness = factor * capture[3].parse::<i32>().unwrap();
-    let to = capture[4].parse::<String>().unwrap();
-    (from, to, happiness)
+        Regex::new(r"(\w+) would (gain|lose) (\d+) happiness units by sitting next to (\w+).")
+            .unwrap();
+    let capture = parser::captures(
+        &regex,
+        line,
+        "`<name> would gain|lose <n> happiness units by sitting next to <name>.`",
+    )?;
+
+    let from = capture[1].to_string();
+    let factor = if &capture[2] == "gain" { 1 } else { -1 };
+    let happiness = factor * parser::number::<i32>(capture.get(3).unwrap().as_str())?;
+    let to = capture[4].to_string();
+    Ok((from, to, happiness))
 }
 
 fn part_one(rules: &Rules) -> i32 {
@@ -135,7 +159,8 @@ David would gain 41 happiness units by sitting next to Carol.";
         expected_rules.add(("David", "Bob", -7));
         expected_rules.add(("David", "Carol", 41));
 
-        assert_eq!(parse(input), expected_rules);
+        assert_eq!(parse(input), Ok(expected_rules));
+        assert!(parse("").is_err());
     }
 
     #[test]
@@ -143,7 +168,7 @@ David would gain 41 happiness units by sitting next to Carol.";
         let input = "Alice would gain 54 happiness units by sitting next to Bob.";
         assert_eq!(
             parse_rule(input),
-            ("Alice".to_string(), "Bob".to_string(), 54)
+            Ok(("Alice".to_string(), "Bob".to_string(), 54))
         );
     }
 
@@ -173,23 +198,4 @@ David would gain 41 happiness units by sitting next to Carol.";
             ]
         );
     }
-
-    #[test]
-    fn part_one_works() {
-        let input = "
-Alice would gain 54 happiness units by sitting next to Bob.
-Alice would lose 79 happiness units by sitting next to Carol.
-Alice would lose 2 happiness units by sitting next to David.
-Bob would gain 83 happiness units by sitting next to Alice.
-Bob would lose 7 happiness units by sitting next to Carol.
-Bob would lose 63 happiness units by sitting next to David.
-Carol would lose 62 happiness units by sitting next to Alice.
-Carol would gain 60 happiness units by sitting next to Bob.
-Carol would gain 55 happiness units by sitting next to David.
-David would gain 46 happiness units by sitting next to Alice.
-David would lose 7 happiness units by sitting next to Bob.
-David would gain 41 happiness units by sitting next to Carol.";
-
-        assert_eq!(part_one(&parse(input)), 330);
-    }
 }
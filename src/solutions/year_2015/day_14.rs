@@ -1,38 +1,76 @@
 use regex::Regex;
 
+use crate::error::ParseError;
+use crate::params::{Param, Params};
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-const TIME: u32 = 2503;
+const TIME: Param = Param::new::<u32>("time", "2503", "Seconds the race lasts");
 
-pub fn solve(input: &str) {
-    let mut reindeers = parser::lines_custom(input, parse_line);
+pub struct Puzzle {
+    reindeers: Vec<Reindeer>,
+    time: u32,
+}
+
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[TIME];
 
-    println!("Part 1: {}", part_one(&mut reindeers));
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let reindeers = parser::lines_custom(input, parse_line)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        if reindeers.is_empty() {
+            return Err(ParseError::new(input, "expected at least one reindeer"));
+        }
+        Ok(Self {
+            reindeers,
+            time: params.get(&TIME),
+        })
+    }
 
-    for reindeer in &mut reindeers {
-        reindeer.reset();
+    fn part_one(&self) -> Answer {
+        part_one(&mut self.reindeers.clone(), self.time).into()
     }
 
-    println!("Part 2: {}", part_two(&mut reindeers));
+    fn part_two(&self) -> Answer {
+        part_two(&mut self.reindeers.clone(), self.time).into()
+    }
 }
 
-fn parse_line(line: &str) -> Reindeer {
+fn parse_line(line: &str) -> Result<Reindeer, ParseError> {
     // "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds."
     let regex = Regex::new(
         r"(\w+) can fly (\d+) km/s for (\d+) seconds, but then must rest for (\d+) seconds.",
     )
     .unwrap();
-    let capture = regex.captures(line).expect("Looks weird");
-
-    let name = capture[1].parse::<String>().unwrap();
-    let speed = capture[2].parse::<u32>().unwrap();
-    let speed_time = capture[3].parse::<u32>().unwrap();
-    let rest_time = capture[4].parse::<u32>().unwrap();
-    Reindeer::new(&name, speed, speed_time, rest_time)
+    let capture = parser::captures(
+        &regex,
+        line,
+        "`<name> can fly <n> km/s for <n> seconds, but then must rest for <n> seconds.`",
+    )?;
+    let number = |idx| parser::number::<u32>(capture.get(idx).unwrap().as_str());
+    let seconds = |idx| match number(idx)? {
+        0 => Err(ParseError::new(
+            capture.get(idx).unwrap().as_str(),
+            "expected at least 1 second",
+        )),
+        x => Ok(x),
+    };
+
+    Ok(Reindeer::new(
+        &capture[1],
+        number(2)?,
+        seconds(3)?,
+        seconds(4)?,
+    ))
 }
 
-fn part_one(reindeers: &mut [Reindeer]) -> u32 {
-    for _ in 0..TIME {
+fn part_one(reindeers: &mut [Reindeer], time: u32) -> u32 {
+    for _ in 0..time {
         for reindeer in &mut *reindeers {
             reindeer.tick();
         }
@@ -40,8 +78,8 @@ fn part_one(reindeers: &mut [Reindeer]) -> u32 {
     reindeers.iter().map(Reindeer::distance).max().unwrap()
 }
 
-fn part_two(reindeers: &mut [Reindeer]) -> u32 {
-    for _ in 0..TIME {
+fn part_two(reindeers: &mut [Reindeer], time: u32) -> u32 {
+    for _ in 0..time {
         for reindeer in &mut *reindeers {
             reindeer.tick();
         }
@@ -52,7 +90,7 @@ fn part_two(reindeers: &mut [Reindeer]) -> u32 {
     reindeers.iter().map(Reindeer::score).max().unwrap()
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Reindeer {
     name: String,
     speed: u32,
@@ -64,7 +102,7 @@ struct Reindeer {
     score: u32,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum State {
     Flying,
     Resting,
@@ -117,13 +155,6 @@ impl Reindeer {
         self.score
     }
 
-    pub fn reset(&mut self) {
-        self.distance = 0;
-        self.current_state = State::Resting;
-        self.current_time = 0;
-        self.score = 0;
-    }
-
     pub fn add_point(&mut self) {
         self.score += 1;
     }
@@ -137,13 +168,17 @@ mod tests {
     fn parse_line_works() {
         assert_eq!(
             parse_line("Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds."),
-            Reindeer::new("Comet", 14, 10, 127)
+            Ok(Reindeer::new("Comet", 14, 10, 127))
         );
         assert_eq!(
             parse_line(
                 "Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds."
             ),
-            Reindeer::new("Dancer", 16, 11, 162)
+            Ok(Reindeer::new("Dancer", 16, 11, 162))
         );
+        assert!(parse_line(
+            "Comet can fly 14 km/s for 0 seconds, but then must rest for 127 seconds."
+        )
+        .is_err());
     }
 }
@@ -1,19 +1,39 @@
+use crate::error::ParseError;
+use crate::parser::{captures, number};
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 use std::cmp;
 
-pub fn solve(input: &str) {
-    let cookies = parse(input);
+pub struct Puzzle {
+    cookies: Vec<Cookie>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            cookies: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.cookies).into()
+    }
 
-    println!("Part 1: {}", part_one(&cookies));
-    println!("Part 2: {}", part_two(&cookies));
+    fn part_two(&self) -> Answer {
+        part_two(&self.cookies).into()
+    }
 }
 
-fn parse(input: &str) -> Vec<Cookie> {
-    input
+fn parse(input: &str) -> Result<Vec<Cookie>, ParseError> {
+    let cookies = input
         .lines()
         .filter(|line| !line.is_empty())
         .map(|line| parse_cookie(line.trim()))
-        .collect()
+        .collect::<Result<Vec<_>, _>>()?;
+    if cookies.is_empty() {
+        return Err(ParseError::new(input, "expected at least one ingredient"));
+    }
+    Ok(cookies)
 }
 
 const TEASPOON_TOTAL: i64 = 100;
@@ -101,19 +121,26 @@ fn sum_properties(cookies: &[Cookie], teaspoons: &[i64], f: PropertyOp) -> i64 {
     )
 }
 
-fn parse_cookie(input: &str) -> Cookie {
+fn parse_cookie(input: &str) -> Result<Cookie, ParseError> {
     // Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
-    let captures = Regex::new(r"(\w+): capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (-?\d+)").unwrap()
-    .captures(input).expect("Looks weird");
-
-    let name: String = captures[1].parse().unwrap();
-    let capacity: i64 = captures[2].parse().unwrap();
-    let durability: i64 = captures[3].parse().unwrap();
-    let flavor: i64 = captures[4].parse().unwrap();
-    let texture: i64 = captures[5].parse().unwrap();
-    let calories: i64 = captures[6].parse().unwrap();
-
-    Cookie::new(name, capacity, durability, flavor, texture, calories)
+    let regex = Regex::new(r"(\w+): capacity (-?\d+), durability (-?\d+), flavor (-?\d+), texture (-?\d+), calories (-?\d+)").unwrap();
+    let captures = captures(
+        &regex,
+        input,
+        "`<name>: capacity <n>, durability <n>, flavor <n>, texture <n>, calories <n>`",
+    )?;
+    let property = |idx| number::<i64>(captures.get(idx).unwrap().as_str());
+
+    let name = captures[1].to_string();
+    let capacity = property(2)?;
+    let durability = property(3)?;
+    let flavor = property(4)?;
+    let texture = property(5)?;
+    let calories = property(6)?;
+
+    Ok(Cookie::new(
+        name, capacity, durability, flavor, texture, calories,
+    ))
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -156,11 +183,11 @@ mod tests {
             parse_cookie(
                 "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8"
             ),
-            Cookie::new("Butterscotch".into(), -1, -2, 6, 3, 8)
+            Ok(Cookie::new("Butterscotch".into(), -1, -2, 6, 3, 8))
         );
         assert_eq!(
             parse_cookie("Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3"),
-            Cookie::new("Cinnamon".into(), 2, 3, -2, -1, 3)
+            Ok(Cookie::new("Cinnamon".into(), 2, 3, -2, -1, 3))
         );
     }
 
@@ -1,7 +1,9 @@
 use regex::Regex;
 use std::{collections::HashMap, sync::LazyLock};
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
 struct Aunt {
@@ -14,37 +16,58 @@ type AuntData = HashMap<String, u32>;
 static SUE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"Sue (\d+): ([^$]+)").unwrap());
 static ATTRIBUTE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\w+): (\d+)").unwrap());
 
-pub fn solve(input: &str) {
-    let aunts = parser::lines_custom(input, parse_aunt);
+pub struct Puzzle {
+    aunts: Vec<Aunt>,
+}
 
-    println!("Part 1: {}", part_one(&aunts));
-    println!("Part 2: {}", part_two(&aunts));
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            aunts: parser::lines_custom(input, parse_aunt)
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.aunts).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.aunts).into()
+    }
 }
 
-fn parse_aunt(line: &str) -> Aunt {
+fn parse_aunt(line: &str) -> Result<Aunt, ParseError> {
     let mut result = HashMap::new();
     // Sue 1: goldfish: 6, trees: 9, akitas: 0
-    let captures = SUE_REGEX.captures(line).expect("Looks weird");
+    let captures = parser::captures(&SUE_REGEX, line, "`Sue <n>: <attributes>`")?;
 
-    let id = captures[1].parse().unwrap();
-    let attributes: String = captures[2].parse().unwrap();
+    let id = parser::number(captures.get(1).unwrap().as_str())?;
 
-    for attribute in attributes.split(',') {
-        let attribute_captures = ATTRIBUTE_REGEX.captures(attribute).unwrap();
-        let key = attribute_captures[1].parse().unwrap();
-        let value = attribute_captures[2].parse().unwrap();
+    for attribute in captures.get(2).unwrap().as_str().split(',') {
+        let attribute_captures =
+            parser::captures(&ATTRIBUTE_REGEX, attribute, "`<attribute>: <n>`")?;
+        let key = attribute_captures[1].to_string();
+        let value = parser::number(attribute_captures.get(2).unwrap().as_str())?;
         result.insert(key, value);
     }
 
-    Aunt { id, data: result }
+    Ok(Aunt { id, data: result })
 }
 
-fn part_one(aunts: &[Aunt]) -> u32 {
-    aunts.iter().find(|aunt| matches_aunt(aunt)).unwrap().id
+fn part_one(aunts: &[Aunt]) -> Option<u32> {
+    aunts
+        .iter()
+        .find(|aunt| matches_aunt(aunt))
+        .map(|aunt| aunt.id)
 }
 
-fn part_two(aunts: &[Aunt]) -> u32 {
-    aunts.iter().find(|aunt| matches_aunt_v2(aunt)).unwrap().id
+fn part_two(aunts: &[Aunt]) -> Option<u32> {
+    aunts
+        .iter()
+        .find(|aunt| matches_aunt_v2(aunt))
+        .map(|aunt| aunt.id)
 }
 
 fn matches_aunt(aunt: &Aunt) -> bool {
@@ -92,14 +115,15 @@ mod tests {
     fn parse_aunt_works() {
         assert_eq!(
             parse_aunt("Sue 1: goldfish: 6, trees: 9, akitas: 0"),
-            Aunt {
+            Ok(Aunt {
                 id: 1,
                 data: HashMap::from([
                     ("goldfish".into(), 6),
                     ("trees".into(), 9),
                     ("akitas".into(), 0)
                 ])
-            }
+            })
         );
+        assert!(parse_aunt("Sue 1: goldfish: many").is_err());
     }
 }
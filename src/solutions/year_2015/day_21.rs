@@ -1,10 +1,27 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use log::debug;
 
-pub fn solve(input: &str) {
-    let boss = parse(input);
-    println!("Part 1: {}", part_one(&boss));
-    println!("Part 2: {}", part_two(&boss));
+pub struct Puzzle {
+    boss: Player,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            boss: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.boss).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.boss).into()
+    }
 }
 
 const PLAYER_HP: i32 = 100;
@@ -13,43 +30,41 @@ const STR_HIT_POINTS: &str = "Hit Points:";
 const STR_DAMAGE: &str = "Damage:";
 const STR_ARMOR: &str = "Armor:";
 
-fn parse(input: &str) -> Player {
-    let mut hit_points = 0;
-    let mut damage = 0;
-    let mut armor = 0;
+fn parse(input: &str) -> Result<Player, ParseError> {
+    let mut hit_points = None;
+    let mut damage = None;
+    let mut armor = None;
 
     for line in input.lines() {
-        if line.starts_with(STR_HIT_POINTS) {
-            hit_points = line.replace(STR_HIT_POINTS, "").trim().parse().unwrap();
+        if let Some(value) = line.strip_prefix(STR_HIT_POINTS) {
+            hit_points = Some(parser::number(value.trim())?);
         }
 
-        if line.starts_with(STR_DAMAGE) {
-            damage = line.replace(STR_DAMAGE, "").trim().parse().unwrap();
+        if let Some(value) = line.strip_prefix(STR_DAMAGE) {
+            damage = Some(parser::number(value.trim())?);
         }
 
-        if line.starts_with(STR_ARMOR) {
-            armor = line.replace(STR_ARMOR, "").trim().parse().unwrap();
+        if let Some(value) = line.strip_prefix(STR_ARMOR) {
+            armor = Some(parser::number(value.trim())?);
         }
     }
 
-    Player {
-        hit_points,
-        damage,
-        armor,
-    }
+    let missing = |name: &str| ParseError::new(input, format!("expected `{name}`"));
+    Ok(Player {
+        hit_points: hit_points.ok_or_else(|| missing(STR_HIT_POINTS))?,
+        damage: damage.ok_or_else(|| missing(STR_DAMAGE))?,
+        armor: armor.ok_or_else(|| missing(STR_ARMOR))?,
+    })
 }
 
-fn part_one(boss: &Player) -> i32 {
-    let mut gold = 0;
-    let mut has_won = false;
+fn part_one(boss: &Player) -> Option<i32> {
     let all_combos = all_combinations();
     debug!("all_combos: {}", all_combos.len());
-    while !has_won {
-        gold += 1;
+    let max_gold = all_combos.last().map(|(_, cost)| *cost)?;
+    for gold in 1..=max_gold {
         let combis = combinations(gold, &all_combos);
 
         debug!("Gold: {gold}\tCombis: {}", combis.len());
-        let mut min_hp = 200;
         for combi in combis {
             let player = Player {
                 hit_points: PLAYER_HP,
@@ -58,16 +73,15 @@ fn part_one(boss: &Player) -> i32 {
             };
 
             if fight(&player, boss) {
-                has_won = true;
+                return Some(gold);
             }
-            min_hp = std::cmp::min(boss.hit_points, min_hp);
         }
     }
 
-    gold
+    None
 }
 
-fn part_two(boss: &Player) -> i32 {
+fn part_two(boss: &Player) -> Option<i32> {
     all_combinations()
         .iter()
         .filter(|(combi, _)| {
@@ -81,7 +95,6 @@ fn part_two(boss: &Player) -> i32 {
         })
         .map(|(_, cost)| *cost)
         .max()
-        .unwrap()
 }
 
 fn combinations(gold: i32, all_combos: &[(Vec<Item>, i32)]) -> Vec<&Vec<Item>> {
@@ -303,13 +316,15 @@ Armor: 2";
     #[test]
     fn test_parse() {
         assert_eq!(
-            Player {
+            Ok(Player {
                 hit_points: 109,
                 damage: 8,
                 armor: 2
-            },
+            }),
             parse(EXAMPLE_INPUT)
         );
+        assert!(parse("Hit Points: many").is_err());
+        assert!(parse("Hit Points: 109\nDamage: 8").is_err());
     }
 
     #[test]
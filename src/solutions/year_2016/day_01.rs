@@ -1,10 +1,30 @@
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::cmp;
 use std::collections::HashSet;
 
-pub fn solve(input: &str) {
-    let instructions = parse(input);
-    println!("{}", distance(run(&instructions)));
-    println!("{}", distance(first_double(&instructions)));
+pub struct Puzzle {
+    instructions: Vec<(i32, i32)>,
+}
+
+impl Solution<'_> for Puzzle {
+    const INPUT_MODE: InputMode = InputMode::Ascii;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instructions: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        distance(run(&self.instructions)).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        first_double(&self.instructions).map(distance).into()
+    }
 }
 
 pub fn run(instructions: &[(i32, i32)]) -> (i32, i32) {
@@ -15,7 +35,7 @@ pub fn run(instructions: &[(i32, i32)]) -> (i32, i32) {
     pos
 }
 
-pub fn first_double(instructions: &[(i32, i32)]) -> (i32, i32) {
+pub fn first_double(instructions: &[(i32, i32)]) -> Option<(i32, i32)> {
     let mut pos = (0, 0);
     let mut positions = HashSet::new();
     for (x, y) in instructions {
@@ -25,19 +45,19 @@ pub fn first_double(instructions: &[(i32, i32)]) -> (i32, i32) {
         for _ in 0..iterations {
             pos = (pos.0 + step_x, pos.1 + step_y);
             if positions.contains(&pos) {
-                return pos;
+                return Some(pos);
             }
             positions.insert(pos);
         }
     }
-    panic!("No double");
+    None
 }
 
 pub fn distance((x, y): (i32, i32)) -> i32 {
     x.abs() + y.abs()
 }
 
-fn parse(input: &str) -> Vec<(i32, i32)> {
+fn parse(input: &str) -> Result<Vec<(i32, i32)>, ParseError> {
     let mut vec = Vec::new();
     let mut dir = Direction::North;
     for line in input.lines() {
@@ -45,16 +65,22 @@ fn parse(input: &str) -> Vec<(i32, i32)> {
             if instruction.chars().count() < 2 {
                 break;
             }
-            let first = instruction.chars().next().unwrap();
-            let num = instruction[1..].parse().unwrap();
+            let (right, num) = if let Some(num) = instruction.strip_prefix('R') {
+                (true, num)
+            } else if let Some(num) = instruction.strip_prefix('L') {
+                (false, num)
+            } else {
+                return Err(ParseError::new(instruction, "expected `L` or `R`"));
+            };
+            let num = parser::number(num)?;
 
-            dir = rotate(&dir, first == 'R');
+            dir = rotate(&dir, right);
             let pos = move_it(&dir, (0, 0), num);
 
             vec.push(pos);
         }
     }
-    vec
+    Ok(vec)
 }
 
 fn move_it(dir: &Direction, (x, y): (i32, i32), num: i32) -> (i32, i32) {
@@ -97,19 +123,23 @@ mod tests {
 
     #[test]
     pub fn example_position() {
-        assert_eq!((3, 2), run(&parse("R2, L3")));
-        assert_eq!((-2, 0), run(&parse("R2, R2, R2")));
+        assert_eq!((3, 2), run(&parse("R2, L3").unwrap()));
+        assert_eq!((-2, 0), run(&parse("R2, R2, R2").unwrap()));
     }
 
     #[test]
     pub fn example_distance() {
         assert_eq!(5, distance((2, 3)));
         assert_eq!(2, distance((2, 0)));
-        assert_eq!(12, distance(run(&parse("R5, L5, R5, R3"))));
+        assert_eq!(12, distance(run(&parse("R5, L5, R5, R3").unwrap())));
     }
 
     #[test]
     pub fn example_first_double() {
-        assert_eq!(4, distance(first_double(&parse("R8, R4, R4, R8"))));
+        assert_eq!(
+            Some(4),
+            first_double(&parse("R8, R4, R4, R8").unwrap()).map(distance)
+        );
+        assert_eq!(None, first_double(&parse("R8, R4").unwrap()));
     }
 }
@@ -1,9 +1,25 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let instructions = parse(input);
-    println!("{}", calc_number(&instructions));
-    println!("{}", calc_number2(&instructions));
+pub struct Puzzle {
+    instructions: Vec<Vec<Direction>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            instructions: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        calc_number(&self.instructions).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        calc_number2(&self.instructions).into()
+    }
 }
 
 pub fn calc_number(instructions: &[Vec<Direction>]) -> String {
@@ -148,20 +164,23 @@ impl Starfield {
     }
 }
 
-pub fn parse(input: &str) -> Vec<Vec<Direction>> {
+pub fn parse(input: &str) -> Result<Vec<Vec<Direction>>, ParseError> {
     parser::lines_custom(input, parse_line)
+        .into_iter()
+        .collect()
 }
 
-fn parse_line(line: &str) -> Vec<Direction> {
-    line.chars()
+fn parse_line(line: &str) -> Result<Vec<Direction>, ParseError> {
+    parser::check_chars(line, "UDLR")?;
+    Ok(line
+        .chars()
         .map(|x| match x {
             'U' => Direction::U,
             'D' => Direction::D,
             'L' => Direction::L,
-            'R' => Direction::R,
-            x => panic!("Unexpected symbol: {x}"),
+            _ => Direction::R,
         })
-        .collect()
+        .collect())
 }
 
 pub enum Direction {
@@ -200,7 +219,7 @@ mod tests {
 RRDDD
 LURDL
 UUUUD";
-        assert_eq!("1985", calc_number(&parse(input)));
-        assert_eq!("5DB3", calc_number2(&parse(input)));
+        assert_eq!("1985", calc_number(&parse(input).unwrap()));
+        assert_eq!("5DB3", calc_number2(&parse(input).unwrap()));
     }
 }
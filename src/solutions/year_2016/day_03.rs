@@ -1,27 +1,55 @@
-pub fn solve(input: &str) {
-    println!("{}", count_triangles(&parse_horizontal(input)));
-    println!("{}", count_triangles(&parse_vertical(input)));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    rows: Vec<(u32, u32, u32)>,
 }
 
-fn parse_horizontal(input: &str) -> Vec<(u32, u32, u32)> {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|c| c.parse().unwrap())
-                .collect::<Vec<u32>>()
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rows: parse_horizontal(input)?,
         })
-        .filter(|nums| nums.len() == 3)
-        .map(|nums| (nums[0], nums[1], nums[2]))
-        .collect()
+    }
+
+    fn part_one(&self) -> Answer {
+        count_triangles(&self.rows).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        parse_vertical(&self.rows)
+            .map(|triangles| count_triangles(&triangles))
+            .into()
+    }
 }
 
-fn parse_vertical(input: &str) -> Vec<(u32, u32, u32)> {
+fn parse_horizontal(input: &str) -> Result<Vec<(u32, u32, u32)>, ParseError> {
+    parser::lines_custom(input, |line| {
+        let nums = line
+            .split_whitespace()
+            .map(parser::number)
+            .collect::<Result<Vec<u32>, _>>()?;
+        match nums[..] {
+            [a, b, c] => Ok((a, b, c)),
+            _ => Err(ParseError::new(line, "expected 3 numbers")),
+        }
+    })
+    .into_iter()
+    .collect()
+}
+
+/// Reads the triangles from the columns, `None` unless the rows come in groups of 3
+fn parse_vertical(rows: &[(u32, u32, u32)]) -> Option<Vec<(u32, u32, u32)>> {
+    if !rows.len().is_multiple_of(3) {
+        return None;
+    }
+
     let mut vec_a = Vec::new();
     let mut vec_b = Vec::new();
     let mut vec_c = Vec::new();
 
-    for (a, b, c) in parse_horizontal(input) {
+    for &(a, b, c) in rows {
         vec_a.push(a);
         vec_b.push(b);
         vec_c.push(c);
@@ -35,7 +63,7 @@ fn parse_vertical(input: &str) -> Vec<(u32, u32, u32)> {
         vec.push((vec_c[i], vec_c[i + 1], vec_c[i + 2]));
     }
 
-    vec
+    Some(vec)
 }
 
 fn count_triangles(nums: &[(u32, u32, u32)]) -> usize {
@@ -63,7 +91,7 @@ mod tests {
     #[test]
     fn example() {
         let input = "5 10 25";
-        assert_eq!(0, count_triangles(&parse_horizontal(input)));
+        assert_eq!(0, count_triangles(&parse_horizontal(input).unwrap()));
     }
 
     #[test]
@@ -74,7 +102,7 @@ mod tests {
 201 401 601
 202 402 602
 203 403 603";
-        let triangles = parse_vertical(input);
+        let triangles = parse_vertical(&parse_horizontal(input).unwrap()).unwrap();
         assert_eq!(6, triangles.len());
         assert!(triangles.contains(&(101, 102, 103)));
         assert!(triangles.contains(&(201, 202, 203)));
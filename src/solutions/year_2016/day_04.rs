@@ -1,11 +1,29 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use crate::{parser, util};
 use itertools::Itertools;
 use regex::Regex;
 
-pub fn solve(input: &str) {
-    let rooms = parser::lines_custom(input, parse_line);
-    println!("Part 1: {}", part_one(&rooms));
-    println!("Part 2: {}", part_two(&rooms));
+pub struct Puzzle {
+    rooms: Vec<Room>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rooms: parser::lines_custom(input, parse_line)
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.rooms).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.rooms).into()
+    }
 }
 
 fn part_one(rooms: &[Room]) -> u32 {
@@ -16,29 +34,24 @@ fn part_one(rooms: &[Room]) -> u32 {
         .sum()
 }
 
-fn part_two(rooms: &[Room]) -> u32 {
+fn part_two(rooms: &[Room]) -> Option<u32> {
     rooms
         .iter()
         .find(|room| room.decrypt().contains("northpole"))
-        .unwrap()
-        .id()
+        .map(Room::id)
 }
 
-fn parse_line(line: &str) -> Room {
-    let regex = Regex::new(r"([-a-z]+)-([0-9]+)\[(\w)(\w)(\w)(\w)(\w)\]").unwrap();
-    let capture = regex.captures(line).expect("Looks weird");
-
-    let name = capture[1].parse::<String>().unwrap();
-    let id = capture[2].parse::<u32>().unwrap();
-    let checksum = [
-        capture[3].parse::<char>().unwrap(),
-        capture[4].parse::<char>().unwrap(),
-        capture[5].parse::<char>().unwrap(),
-        capture[6].parse::<char>().unwrap(),
-        capture[7].parse::<char>().unwrap(),
-    ];
-
-    Room::new(name.as_str(), id, checksum)
+fn parse_line(line: &str) -> Result<Room, ParseError> {
+    let regex = Regex::new(r"([-a-z]+)-([0-9]+)\[([a-z]{5})\]").unwrap();
+    let capture = parser::captures(&regex, line, "`<name>-<id>[<checksum>]`")?;
+
+    let id = parser::number(capture.get(2).unwrap().as_str())?;
+    let mut checksum = ['a'; 5];
+    for (slot, c) in checksum.iter_mut().zip(capture[3].chars()) {
+        *slot = c;
+    }
+
+    Ok(Room::new(&capture[1], id, checksum))
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -58,18 +71,7 @@ impl Room {
     }
 
     pub fn is_real(&self) -> bool {
-        let ranking = get_ranking(&self.name);
-        assert!(
-            ranking.len() >= 5,
-            "Unexpected count of characters: {}",
-            ranking.len()
-        );
-
-        ranking[0] == self.checksum[0]
-            && ranking[1] == self.checksum[1]
-            && ranking[2] == self.checksum[2]
-            && ranking[3] == self.checksum[3]
-            && ranking[4] == self.checksum[4]
+        get_ranking(&self.name) == self.checksum
     }
 
     pub fn id(&self) -> u32 {
@@ -132,24 +134,30 @@ mod tests {
     fn parse_line_works() {
         assert_eq!(
             parse_line("aaaaa-bbb-z-y-x-123[abxyz]"),
-            Room::new("aaaaa-bbb-z-y-x", 123, ['a', 'b', 'x', 'y', 'z'])
+            Ok(Room::new("aaaaa-bbb-z-y-x", 123, ['a', 'b', 'x', 'y', 'z']))
         );
         assert_eq!(
             parse_line("a-b-c-d-e-f-g-h-987[abcde]"),
-            Room::new("a-b-c-d-e-f-g-h", 987, ['a', 'b', 'c', 'd', 'e'])
+            Ok(Room::new("a-b-c-d-e-f-g-h", 987, ['a', 'b', 'c', 'd', 'e']))
         );
         assert_eq!(
             parse_line("not-a-real-room-404[oarel]"),
-            Room::new("not-a-real-room", 404, ['o', 'a', 'r', 'e', 'l'])
+            Ok(Room::new("not-a-real-room", 404, ['o', 'a', 'r', 'e', 'l']))
         );
         assert_eq!(
             parse_line("totally-real-room-200[decoy]"),
-            Room::new("totally-real-room", 200, ['d', 'e', 'c', 'o', 'y'])
+            Ok(Room::new(
+                "totally-real-room",
+                200,
+                ['d', 'e', 'c', 'o', 'y']
+            ))
         );
+        assert!(parse_line("a-b-1[abc]").is_err());
     }
 
     #[test]
     fn check_real_works() {
+        assert!(!Room::new("a-b", 1, ['a', 'b', 'c', 'd', 'e']).is_real());
         assert!(Room::new("aaaaa-bbb-z-y-x", 123, ['a', 'b', 'x', 'y', 'z']).is_real());
         assert!(Room::new("a-b-c-d-e-f-g-h", 987, ['a', 'b', 'c', 'd', 'e']).is_real());
         assert!(Room::new("not-a-real-room", 404, ['o', 'a', 'r', 'e', 'l']).is_real());
@@ -159,7 +167,9 @@ mod tests {
     #[test]
     fn check_decrypt() {
         assert_eq!(
-            parse_line("qzmt-zixmtkozy-ivhz-343[abcde]").decrypt(),
+            parse_line("qzmt-zixmtkozy-ivhz-343[abcde]")
+                .unwrap()
+                .decrypt(),
             "very encrypted name"
         );
     }
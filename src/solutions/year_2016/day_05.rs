@@ -1,6 +1,26 @@
-pub fn solve(input: &str) {
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+use crate::cancel;
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+use log::debug;
+
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.input).into()
+    }
 }
 
 const SEARCH_PREFIX: &str = "00000";
@@ -45,7 +65,7 @@ fn part_two(input: &str) -> String {
 }
 
 fn print_progress(password: &str) {
-    println!(
+    debug!(
         "Progress: {:.2}% [{}]",
         (password.chars().filter(|c| *c != BLANK_SPACE).count()) as f64 / CHARS_PASSWORD as f64
             * 100.0,
@@ -55,6 +75,7 @@ fn print_progress(password: &str) {
 
 fn find_next(input: &str, start: usize) -> (char, usize) {
     for i in start.. {
+        cancel::checkpoint();
         let result = format!("{:x}", md5::compute(format!("{input}{i}")));
         if result.starts_with(SEARCH_PREFIX) {
             return (result.chars().nth(INDEX_OF_INTEREST).unwrap(), i + 1);
@@ -66,6 +87,7 @@ fn find_next(input: &str, start: usize) -> (char, usize) {
 
 fn find_next2(input: &str, start: usize, positions: &[usize]) -> (char, usize, usize) {
     for i in start.. {
+        cancel::checkpoint();
         let result = format!("{:x}", md5::compute(format!("{input}{i}")));
         if result.starts_with(SEARCH_PREFIX) {
             let pos = result
@@ -96,13 +118,13 @@ mod tests {
     use super::*;
 
     #[test]
-    #[ignore]
+    #[ignore = "slow md5 brute force"]
     fn part_one_works() {
         assert_eq!("18f47a30", &part_one("abc"));
     }
 
     #[test]
-    #[ignore]
+    #[ignore = "slow md5 brute force"]
     fn part_two_works() {
         assert_eq!("05ace8e3", &part_two("abc"));
     }
@@ -1,12 +1,32 @@
 use itertools::Itertools;
 use std::collections::HashMap;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let matrix = CharMatrix::build(&parser::lines_as_strings(input));
-    println!("Part 1: {}", part_one(&matrix));
-    println!("Part 2: {}", part_two(&matrix));
+pub struct Puzzle {
+    matrix: CharMatrix,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let words = parser::lines_as_strings(input);
+        if words.is_empty() {
+            return Err(ParseError::new(input, "expected at least one message"));
+        }
+        Ok(Self {
+            matrix: CharMatrix::build(&words)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.matrix).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.matrix).into()
+    }
 }
 
 fn part_one(matrix: &CharMatrix) -> String {
@@ -24,13 +44,13 @@ struct CharMatrix {
 }
 
 impl CharMatrix {
-    pub fn build(words: &[&str]) -> Self {
-        let first = words[0];
-        let mut matrix = CharMatrix::new(first.len());
+    pub fn build(words: &[&str]) -> Result<Self, ParseError> {
+        let size = words.first().map_or(0, |first| first.chars().count());
+        let mut matrix = CharMatrix::new(size);
         for word in words {
-            matrix.add(word);
+            matrix.add(word)?;
         }
-        matrix
+        Ok(matrix)
     }
 
     pub fn least_common(&self) -> String {
@@ -50,12 +70,18 @@ impl CharMatrix {
         Self { field, size }
     }
 
-    fn add(&mut self, word: &str) {
-        assert!(word.len() == self.size, "Word has different length");
+    fn add(&mut self, word: &str) -> Result<(), ParseError> {
+        if word.chars().count() != self.size {
+            return Err(ParseError::new(
+                word,
+                "expected all messages to have the same length",
+            ));
+        }
 
         for (i, c) in word.chars().enumerate() {
             *self.field[i].entry(c).or_insert(0) += 1;
         }
+        Ok(())
     }
 
     fn calc_least_common(chars: &HashMap<char, usize>) -> char {
@@ -76,30 +102,3 @@ impl CharMatrix {
             .unwrap()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(
-            part_one(&CharMatrix::build(&vec![
-                "eedadn", "drvtee", "eandsr", "raavrd", "atevrs", "tsrnev", "sdttsa", "rasrtv",
-                "nssdts", "ntnada", "svetve", "tesnvt", "vntsnd", "vrdear", "dvrsen", "enarar"
-            ])),
-            "easter"
-        );
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(
-            part_two(&CharMatrix::build(&vec![
-                "eedadn", "drvtee", "eandsr", "raavrd", "atevrs", "tsrnev", "sdttsa", "rasrtv",
-                "nssdts", "ntnada", "svetve", "tesnvt", "vntsnd", "vrdear", "dvrsen", "enarar"
-            ])),
-            "advent"
-        );
-    }
-}
@@ -1,7 +1,24 @@
-pub fn solve(input: &str) {
-    let addresses = parse(input);
-    println!("Part 1: {}", part_one(&addresses));
-    println!("Part 2: {}", part_two(&addresses));
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle<'a> {
+    addresses: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            addresses: parse(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.addresses).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.addresses).into()
+    }
 }
 
 fn parse(input: &str) -> Vec<&str> {
@@ -1,28 +1,52 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use regex::Regex;
-use std::{cmp::min, sync::LazyLock};
+use std::{cmp::min, num::NonZeroUsize, sync::LazyLock};
 
-pub fn solve(input: &str) {
-    let operations = input
-        .trim()
-        .lines()
-        .map(str::trim)
-        .filter(|line| !line.is_empty())
-        .map(parse_line)
-        .collect_vec();
+pub struct Puzzle {
+    screen: Screen,
+}
 
-    let mut screen = Screen::new();
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[SCREEN_WIDTH, SCREEN_HEIGHT];
 
-    for op in operations {
-        screen.apply(&op);
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
     }
 
-    println!("Part 1: {}", screen.lit_pixels());
-    println!("Part 2:\n{}", screen.print());
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        let mut screen = Screen::new(
+            params.get::<NonZeroUsize>(&SCREEN_WIDTH).get(),
+            params.get::<NonZeroUsize>(&SCREEN_HEIGHT).get(),
+        );
+
+        for line in parser::lines_as_strings(input) {
+            let op = parse_line(line)?;
+            if !screen.contains(&op) {
+                return Err(ParseError::new(line, "rotation outside of the screen"));
+            }
+            screen.apply(&op);
+        }
+
+        Ok(Self { screen })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.screen.lit_pixels().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        Answer::Art(self.screen.print())
+    }
 }
 
-const SCREEN_WIDTH: usize = 50;
-const SCREEN_HEIGHT: usize = 6;
+const SCREEN_WIDTH: Param =
+    Param::new::<NonZeroUsize>("width", "50", "Pixels per row of the screen");
+const SCREEN_HEIGHT: Param =
+    Param::new::<NonZeroUsize>("height", "6", "Pixels per column of the screen");
 
 const CHAR_WIDTH: usize = 5;
 
@@ -30,13 +54,17 @@ static ROTATION_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"=(\d+) by
 static RECT_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(\d+)x(\d+)").unwrap());
 
 struct Screen {
-    pixels: [[bool; SCREEN_WIDTH]; SCREEN_HEIGHT],
+    width: usize,
+    height: usize,
+    pixels: Vec<Vec<bool>>,
 }
 
 impl Screen {
-    fn new() -> Self {
+    fn new(width: usize, height: usize) -> Self {
         Self {
-            pixels: [[false; SCREEN_WIDTH]; SCREEN_HEIGHT],
+            width,
+            height,
+            pixels: vec![vec![false; width]; height],
         }
     }
 
@@ -47,6 +75,15 @@ impl Screen {
             .sum()
     }
 
+    /// Returns `false` if `operation` rotates a row or column outside of the screen
+    fn contains(&self, operation: &Operation) -> bool {
+        match operation {
+            Operation::Rect(..) => true,
+            Operation::RotateRow(arg) => arg.id < self.height,
+            Operation::RotateCol(arg) => arg.id < self.width,
+        }
+    }
+
     fn apply(&mut self, operation: &Operation) {
         match operation {
             Operation::Rect(width, height) => self.add_rect(*width, *height),
@@ -56,20 +93,20 @@ impl Screen {
     }
 
     fn add_rect(&mut self, width: usize, height: usize) {
-        for x in 0..min(height, SCREEN_HEIGHT) {
-            for y in 0..min(width, SCREEN_WIDTH) {
+        for x in 0..min(height, self.height) {
+            for y in 0..min(width, self.width) {
                 self.pixels[x][y] = true;
             }
         }
     }
 
     fn rotate_row(&mut self, arg: &RotationArg) {
-        self.pixels[arg.id].rotate_right(arg.by % SCREEN_WIDTH);
+        self.pixels[arg.id].rotate_right(arg.by % self.width);
     }
 
     fn rotate_col(&mut self, arg: &RotationArg) {
         let mut new_col = self.pixels.iter().map(|row| row[arg.id]).collect_vec();
-        new_col.rotate_right(arg.by % SCREEN_HEIGHT);
+        new_col.rotate_right(arg.by % self.height);
 
         for (i, cell) in new_col.iter().enumerate() {
             self.pixels[i][arg.id] = *cell;
@@ -106,24 +143,28 @@ struct RotationArg {
     by: usize,
 }
 
-fn parse_line(line: &str) -> Operation {
+fn parse_line(line: &str) -> Result<Operation, ParseError> {
     if line.starts_with("rotate") {
-        let captures = ROTATION_REGEX.captures(line).expect("Invalid line");
-        let id = captures[1].parse().unwrap();
-        let by = captures[2].parse().unwrap();
+        let captures = parser::captures(
+            &ROTATION_REGEX,
+            line,
+            "`rotate row|column <axis>=<n> by <n>`",
+        )?;
+        let id = parser::number(captures.get(1).unwrap().as_str())?;
+        let by = parser::number(captures.get(2).unwrap().as_str())?;
         let arg = RotationArg { id, by };
 
         if line.contains("row") {
-            Operation::RotateRow(arg)
+            Ok(Operation::RotateRow(arg))
         } else {
-            Operation::RotateCol(arg)
+            Ok(Operation::RotateCol(arg))
         }
     } else {
-        let captures = RECT_REGEX.captures(line).expect("Invalid line");
-        let a = captures[1].parse().unwrap();
-        let b = captures[2].parse().unwrap();
+        let captures = parser::captures(&RECT_REGEX, line, "`rect <width>x<height>`")?;
+        let a = parser::number(captures.get(1).unwrap().as_str())?;
+        let b = parser::number(captures.get(2).unwrap().as_str())?;
 
-        Operation::Rect(a, b)
+        Ok(Operation::Rect(a, b))
     }
 }
 
@@ -133,20 +174,21 @@ mod tests {
 
     #[test]
     fn parse_line_works() {
-        assert_eq!(parse_line("rect 3x2"), Operation::Rect(3, 2));
+        assert_eq!(parse_line("rect 3x2"), Ok(Operation::Rect(3, 2)));
         assert_eq!(
             parse_line("rotate column x=1 by 1"),
-            Operation::RotateCol(RotationArg { id: 1, by: 1 })
+            Ok(Operation::RotateCol(RotationArg { id: 1, by: 1 }))
         );
         assert_eq!(
             parse_line("rotate row y=0 by 4"),
-            Operation::RotateRow(RotationArg { id: 0, by: 4 })
+            Ok(Operation::RotateRow(RotationArg { id: 0, by: 4 }))
         );
+        assert!(parse_line("rect 3").is_err());
     }
 
     #[test]
     fn rect_works() {
-        let mut screen = Screen::new();
+        let mut screen = Screen::new(50, 6);
         screen.apply(&Operation::Rect(3, 2));
 
         assert_eq!(screen.lit_pixels(), 6);
@@ -154,13 +196,36 @@ mod tests {
 
     #[test]
     fn rotate_col_works() {
-        let mut screen = Screen::new();
+        let mut screen = Screen::new(50, 6);
         screen.apply(&Operation::Rect(3, 2));
-        screen.apply(&Operation::RotateCol(RotationArg {
-            id: 2,
-            by: SCREEN_WIDTH,
-        }));
+        screen.apply(&Operation::RotateCol(RotationArg { id: 2, by: 6 }));
 
         assert_eq!(screen.lit_pixels(), 6);
     }
+
+    #[test]
+    fn draw_works() {
+        let params = [("width", "7"), ("height", "3")]
+            .map(|(x, y)| (x.to_string(), y.to_string()))
+            .into_iter()
+            .collect();
+        let puzzle = Puzzle::parse_with(
+            "rect 3x2\nrotate column x=1 by 1\nrotate row y=0 by 4\nrotate column x=1 by 1",
+            &params,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Answer::Art(String::from(
+                " #  #    #   \n# #          \n #           \n"
+            )),
+            puzzle.part_two()
+        );
+        assert!(Puzzle::parse_with("rotate row y=3 by 1", &params).is_err());
+
+        let empty = [(String::from("width"), String::from("0"))]
+            .into_iter()
+            .collect::<Params>();
+        assert!(empty.check(Puzzle::PARAMS).is_err());
+    }
 }
@@ -1,7 +1,27 @@
-pub fn solve(input: &str) {
-    let sanatized_input = sanatize(input);
-    println!("Part 1: {}", part_one(&sanatized_input));
-    println!("Part 2: {}", part_two(&sanatized_input));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+use regex::Regex;
+
+pub struct Puzzle {
+    sanatized_input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        check_markers(input)?;
+        Ok(Self {
+            sanatized_input: sanatize(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.sanatized_input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.sanatized_input).into()
+    }
 }
 
 fn calc_decompressed_len<F>(input: &str, parse_repeat: F) -> usize
@@ -30,6 +50,22 @@ fn part_two(input: &str) -> usize {
     calc_decompressed_len(input, parser)
 }
 
+/// Checks that every `(` starts a marker `(<length>x<times>)`
+fn check_markers(input: &str) -> Result<(), ParseError> {
+    let regex = Regex::new(r"^\((\d+)x(\d+)\)").unwrap();
+    for (i, _) in input.match_indices('(') {
+        let Some(captures) = regex.captures(&input[i..]) else {
+            return Err(ParseError::new(
+                &input[i..=i],
+                "expected a marker `(<length>x<times>)`",
+            ));
+        };
+        parser::number::<usize>(captures.get(1).unwrap().as_str())?;
+        parser::number::<usize>(captures.get(2).unwrap().as_str())?;
+    }
+    Ok(())
+}
+
 fn sanatize(input: &str) -> String {
     input.replace(|c: char| c.is_ascii_whitespace(), "")
 }
@@ -85,7 +121,7 @@ fn read_next_num(chars: &mut std::str::Chars) -> usize {
         }
     }
 
-    num.parse().unwrap()
+    num.parse().expect("markers checked by parse")
 }
 
 #[cfg(test)]
@@ -99,6 +135,16 @@ mod tests {
         assert_eq!(part_one("(3x3)XYZ"), "XYZXYZXYZ".len());
     }
 
+    #[test]
+    fn check_markers_works() {
+        assert_eq!(Ok(()), check_markers("A(1x5)BC"));
+        let input = "A(1x)BC";
+        assert_eq!(
+            ParseError::new(&input[1..2], "expected a marker `(<length>x<times>)`"),
+            check_markers(input).unwrap_err()
+        );
+    }
+
     #[test]
     fn decompress_v2_works() {
         assert_eq!(part_two("(3x3)XYZ"), 9);
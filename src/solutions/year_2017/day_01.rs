@@ -1,7 +1,25 @@
-pub fn solve(input: &str) {
-    let trimmed = input.trim();
-    println!("{}", part_one(trimmed));
-    println!("{}", part_two(trimmed));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let input = input.trim();
+        parser::check_chars(input, "0123456789")?;
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(self.input).into()
+    }
 }
 
 fn part_one(input: &str) -> u32 {
@@ -1,26 +1,43 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let table = parse(input);
-    println!("Part 1: {}", checksum_one(&table));
-    println!("Part 2: {}", checksum_two(&table));
+pub struct Puzzle {
+    table: Table,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            table: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        checksum_one(&self.table).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        checksum_two(&self.table).into()
+    }
 }
 
 type Table = Vec<Row>;
 type Row = Vec<Base>;
 type Base = i32;
 
-fn parse(input: &str) -> Table {
+fn parse(input: &str) -> Result<Table, ParseError> {
     input
         .lines()
         .map(str::trim)
         .filter(|row| !row.is_empty())
         .map(|row| {
             row.split_ascii_whitespace()
-                .map(|cell| cell.parse::<Base>().expect("NaN"))
-                .collect_vec()
+                .map(parser::number::<Base>)
+                .try_collect()
         })
-        .collect_vec()
+        .try_collect()
 }
 
 fn checksum_one(table: &[Row]) -> Base {
@@ -38,9 +55,9 @@ fn checksum_row(row: &[Base]) -> Base {
 fn pure_division(row: &[Base]) -> Base {
     for i in 0..row.len() {
         for k in i + 1..row.len() {
-            if row[i] % row[k] == 0 {
+            if row[i].checked_rem(row[k]) == Some(0) {
                 return row[i] / row[k];
-            } else if row[k] % row[i] == 0 {
+            } else if row[k].checked_rem(row[i]) == Some(0) {
                 return row[k] / row[i];
             }
         }
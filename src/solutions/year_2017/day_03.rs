@@ -1,9 +1,26 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let aim = input.parse().unwrap();
-    println!("Part 1: {}", part_one(aim));
-    println!("Part 2: {}", part_two(aim));
+pub struct Puzzle {
+    aim: u32,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            aim: parser::number(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(self.aim).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(self.aim).into()
+    }
 }
 
 fn part_one(aim: u32) -> i32 {
@@ -1,17 +1,27 @@
 use std::collections::HashSet;
 
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let passphrases = parse(input);
-    println!(
-        "Part 1: {}",
-        count_valid_passphrases(&passphrases, contains_no_doubles)
-    );
-    println!(
-        "Part 2: {}",
-        count_valid_passphrases(&passphrases, contains_no_double_anagrams)
-    );
+pub struct Puzzle<'a> {
+    passphrases: Vec<Vec<&'a str>>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            passphrases: parse(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        count_valid_passphrases(&self.passphrases, contains_no_doubles).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        count_valid_passphrases(&self.passphrases, contains_no_double_anagrams).into()
+    }
 }
 
 fn parse(input: &str) -> Vec<Vec<&str>> {
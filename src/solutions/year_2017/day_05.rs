@@ -1,9 +1,25 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let jumps: Vec<i32> = parser::lines_as_numbers(input);
-    println!("Part 1: {}", part_one(&jumps));
-    println!("Part 2: {}", part_two(&jumps));
+pub struct Puzzle {
+    jumps: Vec<i32>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            jumps: parser::lines_as_numbers(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.jumps).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.jumps).into()
+    }
 }
 
 fn part_one(offsets: &[i32]) -> u32 {
@@ -31,20 +47,3 @@ where
 
     steps
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn part_one_works() {
-        let input = vec![0, 3, 0, 1, -3];
-        assert_eq!(part_one(&input), 5);
-    }
-
-    #[test]
-    fn part_two_works() {
-        let input = vec![0, 3, 0, 1, -3];
-        assert_eq!(part_two(&input), 10);
-    }
-}
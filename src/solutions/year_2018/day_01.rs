@@ -1,18 +1,50 @@
 use std::collections::HashSet;
 
+use itertools::Itertools;
+
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let numbers = parser::lines_as_numbers(input);
-    println!("Part 1: {}", part_one(&numbers));
-    println!("Part 2: {}", part_two(&numbers));
+pub struct Puzzle {
+    numbers: Vec<i32>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let numbers = parser::lines_as_numbers(input)?;
+        if numbers.is_empty() {
+            return Err(ParseError::new(input, "expected frequency changes"));
+        }
+        Ok(Self { numbers })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.numbers).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.numbers).into()
+    }
 }
 
 fn part_one(numbers: &[i32]) -> i32 {
     numbers.iter().sum()
 }
 
-fn part_two(numbers: &[i32]) -> i32 {
+fn part_two(numbers: &[i32]) -> Option<i32> {
+    // Each pass shifts the frequencies by the total change, so one can only be
+    // reached twice if two of the first pass are equal modulo the total change
+    let total = part_one(numbers);
+    let first_pass = numbers.iter().scan(0, |sum, x| {
+        let before = *sum;
+        *sum += x;
+        Some(before)
+    });
+    if total != 0 && first_pass.map(|x| x.rem_euclid(total)).all_unique() {
+        return None;
+    }
+
     let mut set = HashSet::from([0]);
     let mut sum = 0;
 
@@ -21,7 +53,7 @@ fn part_two(numbers: &[i32]) -> i32 {
             sum += i;
 
             if set.contains(&sum) {
-                return sum;
+                return Some(sum);
             }
 
             set.insert(sum);
@@ -42,9 +74,12 @@ mod tests {
 
     #[test]
     fn part_two_works() {
-        assert_eq!(part_two(&[1, -1]), 0);
-        assert_eq!(part_two(&[3, 3, 4, -2, -4]), 10);
-        assert_eq!(part_two(&[-6, 3, 8, 5, -6]), 5);
-        assert_eq!(part_two(&[7, 7, -2, -7, -4]), 14);
+        assert_eq!(part_two(&[1, -1]), Some(0));
+        assert_eq!(part_two(&[3, 3, 4, -2, -4]), Some(10));
+        assert_eq!(part_two(&[-6, 3, 8, 5, -6]), Some(5));
+        assert_eq!(part_two(&[7, 7, -2, -7, -4]), Some(14));
+        assert_eq!(part_two(&[1]), None);
+        assert_eq!(part_two(&[2, 1]), None);
+        assert!(Puzzle::parse("").is_err());
     }
 }
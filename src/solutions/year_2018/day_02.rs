@@ -1,10 +1,27 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use crate::{parser, util};
 
-pub fn solve(input: &str) {
-    let multiples = parser::lines_custom(input, parse_line);
-    let lines = parser::lines_as_strings(input);
-    println!("Part 1: {}", part_one(&multiples));
-    println!("Part 2: {}", part_two(&lines));
+pub struct Puzzle<'a> {
+    multiples: Vec<Multiples>,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            multiples: parser::lines_custom(input, parse_line),
+            lines: parser::lines_as_strings(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.multiples).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.lines).into()
+    }
 }
 
 fn parse_line(line: &str) -> Multiples {
@@ -19,21 +36,20 @@ fn part_one(multiples: &[Multiples]) -> u32 {
     let (twice, trice) = multiples
         .iter()
         .map(Multiples::multiples)
-        .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
-        .expect("Multiples not empty");
+        .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
     twice * trice
 }
 
-fn part_two(words: &[&str]) -> String {
+fn part_two(words: &[&str]) -> Option<String> {
     for a in words {
         for b in words.iter().skip(1) {
             if let Some(x) = diff_by_one(a, b) {
-                return x;
+                return Some(x);
             }
         }
     }
 
-    panic!("Could not find a match!");
+    None
 }
 
 fn diff_by_one(word_a: &str, word_b: &str) -> Option<String> {
@@ -94,28 +110,6 @@ mod tests {
         assert_eq!(parse_line("ababab"), Multiples::new(false, true));
     }
 
-    #[test]
-    fn part_one_works() {
-        let input = vec![
-            Multiples::new(false, false),
-            Multiples::new(true, true),
-            Multiples::new(true, false),
-            Multiples::new(false, true),
-            Multiples::new(true, false),
-            Multiples::new(true, false),
-            Multiples::new(false, true),
-        ];
-        assert_eq!(part_one(&input), 12);
-    }
-
-    #[test]
-    fn part_two_works() {
-        let input = vec![
-            "abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz",
-        ];
-        assert_eq!(part_two(&input), "fgij");
-    }
-
     #[test]
     fn diff_by_one_works() {
         assert_eq!(diff_by_one("", ""), None);
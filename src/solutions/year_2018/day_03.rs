@@ -2,35 +2,59 @@ use itertools::Itertools;
 use regex::Regex;
 use std::collections::HashSet;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let claims = parser::lines_custom(input, parse_line);
-    let grid = build_grid(&claims);
-    println!("Part 1: {}", part_one(&grid));
-    println!("Part 2: {}", part_two(&grid));
+pub struct Puzzle {
+    grid: Vec<HashSet<u32>>,
 }
 
-fn parse_line(line: &str) -> Claim {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let claims = parser::lines_custom(input, parse_line)
+            .into_iter()
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            grid: build_grid(&claims),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.grid).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.grid).into()
+    }
+}
+
+fn parse_line(line: &str) -> Result<Claim, ParseError> {
     // #1 @ 1,3: 4x4
-    let captures = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)")
-        .unwrap()
-        .captures(line)
-        .expect("Looks weird");
-
-    let id = captures[1].parse().unwrap();
-    let left = captures[2].parse().unwrap();
-    let top = captures[3].parse().unwrap();
-    let width = captures[4].parse().unwrap();
-    let height = captures[5].parse().unwrap();
-
-    Claim {
+    let regex = Regex::new(r"#(\d+) @ (\d+),(\d+): (\d+)x(\d+)").unwrap();
+    let captures = parser::captures(&regex, line, "`#<id> @ <left>,<top>: <width>x<height>`")?;
+    let number = |idx| parser::number::<u32>(captures.get(idx).unwrap().as_str());
+
+    let id = number(1)?;
+    let left = number(2)?;
+    let top = number(3)?;
+    let width = number(4)?;
+    let height = number(5)?;
+
+    if left.saturating_add(width) > MAX_X || top.saturating_add(height) > MAX_Y {
+        return Err(ParseError::new(
+            line,
+            format!("claim outside of the {MAX_X}x{MAX_Y} fabric"),
+        ));
+    }
+
+    Ok(Claim {
         id,
         left,
         top,
         width,
         height,
-    }
+    })
 }
 
 fn build_grid(claims: &[Claim]) -> Vec<HashSet<u32>> {
@@ -73,13 +97,12 @@ fn part_one(grid: &[HashSet<u32>]) -> usize {
     grid.iter().filter(|x| x.len() > 1).count()
 }
 
-fn part_two(grid: &[HashSet<u32>]) -> u32 {
+fn part_two(grid: &[HashSet<u32>]) -> Option<u32> {
     grid.iter()
         .filter(|ids| ids.len() == 1)
         .flat_map(|ids| ids.iter().copied())
         .unique()
         .find(|id| grid.iter().filter(|g| g.contains(id)).all(|g| g.len() == 1))
-        .unwrap()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -99,36 +122,41 @@ mod tests {
     fn parse_line_works() {
         assert_eq!(
             parse_line("#1 @ 1,3: 4x4"),
-            Claim {
+            Ok(Claim {
                 id: 1,
                 left: 1,
                 top: 3,
                 width: 4,
                 height: 4
-            }
+            })
         );
         assert_eq!(
             parse_line("#2 @ 3,1: 4x4"),
-            Claim {
+            Ok(Claim {
                 id: 2,
                 left: 3,
                 top: 1,
                 width: 4,
                 height: 4
-            }
+            })
         );
         assert_eq!(
             parse_line("#3 @ 5,5: 2x2"),
-            Claim {
+            Ok(Claim {
                 id: 3,
                 left: 5,
                 top: 5,
                 width: 2,
                 height: 2
-            }
+            })
         );
     }
 
+    #[test]
+    fn claims_outside_are_rejected() {
+        assert!(parse_line("#1 @ 999,3: 2x4").is_err());
+    }
+
     #[test]
     fn calc_claimed_works() {
         assert_eq!(
@@ -153,33 +181,4 @@ mod tests {
             })
         );
     }
-
-    #[test]
-    fn part_one_works() {
-        let claims = vec![
-            Claim {
-                id: 1,
-                left: 1,
-                top: 3,
-                width: 4,
-                height: 4,
-            },
-            Claim {
-                id: 2,
-                left: 3,
-                top: 1,
-                width: 4,
-                height: 4,
-            },
-            Claim {
-                id: 3,
-                left: 5,
-                top: 5,
-                width: 2,
-                height: 2,
-            },
-        ];
-        let grid = build_grid(&claims);
-        assert_eq!(part_one(&grid), 4);
-    }
 }
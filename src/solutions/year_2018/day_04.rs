@@ -4,37 +4,49 @@ use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Timelike, Utc};
 use itertools::Itertools;
 use regex::Regex;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let mut log_lines = parse(input);
+pub struct Puzzle {
+    guards: Vec<Guard>,
+}
 
-    let guards = sum_minutes_asleep(&mut log_lines);
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut log_lines = parse(input)?;
+        Ok(Self {
+            guards: sum_minutes_asleep(&mut log_lines),
+        })
+    }
 
-    println!("Part 1: {}", part_one(&guards));
-    println!("Part 2: {}", part_two(&guards));
+    fn part_one(&self) -> Answer {
+        part_one(&self.guards).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.guards).into()
+    }
 }
 
-fn part_one(guards: &[Guard]) -> u32 {
+fn part_one(guards: &[Guard]) -> Option<u32> {
     let guard = guards
         .iter()
         .sorted_by_key(|g| g.sum_minutes_asleep())
-        .next_back()
-        .unwrap();
+        .next_back()?;
 
-    guard.id * guard.get_minute_most_asleep().minutes()
+    Some(guard.id * guard.get_minute_most_asleep().minutes())
 }
 
-fn part_two(guards: &[Guard]) -> u32 {
+fn part_two(guards: &[Guard]) -> Option<u32> {
     let result = guards
         .iter()
         .map(|g| (g, g.get_minute_most_asleep()))
         .sorted_by_key(|x| x.1.times())
         .map(|x| (x.0, x.1.minutes()))
-        .next_back()
-        .unwrap();
+        .next_back()?;
 
-    result.0.id * result.1
+    Some(result.0.id * result.1)
 }
 
 fn sum_minutes_asleep(log_lines: &mut [Log]) -> Vec<Guard> {
@@ -94,7 +106,7 @@ impl Guard {
         }
 
         if let Some(a) = minutes.iter().sorted_by_key(|x| x.1).next_back() {
-            MostAsleep::MinuteTimes(a.0.minute(), 0)
+            MostAsleep::MinuteTimes(a.0.minute(), *a.1)
         } else {
             MostAsleep::None
         }
@@ -132,32 +144,39 @@ static GUARD_REGEX: LazyLock<Regex> =
 
 const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
 
-fn parse(input: &str) -> Vec<Log> {
+fn parse(input: &str) -> Result<Vec<Log>, ParseError> {
     parser::lines_custom(input, parse_line)
+        .into_iter()
+        .collect()
 }
 
-fn parse_line(line: &str) -> Log {
-    let captures = LINE_REGEX.captures(line).expect("invalid line");
+fn parse_line(line: &str) -> Result<Log, ParseError> {
+    let captures = parser::captures(&LINE_REGEX, line, "`[<date> <time>] <event>`")?;
 
-    let datetime = NaiveDateTime::parse_from_str(&captures[1], DATE_TIME_FORMAT)
-        .unwrap()
+    let time = captures.get(1).unwrap().as_str();
+    let datetime = NaiveDateTime::parse_from_str(time, DATE_TIME_FORMAT)
+        .map_err(|_| ParseError::new(time, "not a valid date and time"))?
         .and_utc()
         .naive_local();
 
+    let event = captures.get(2).unwrap().as_str();
     let instruction = if let Some(guard) = GUARD_REGEX.captures(line) {
-        Instruction::Begins(guard[1].parse().unwrap())
-    } else if &captures[2] == "wakes up" {
+        Instruction::Begins(parser::number(guard.get(1).unwrap().as_str())?)
+    } else if event == "wakes up" {
         Instruction::WakesUp
-    } else if &captures[2] == "falls asleep" {
+    } else if event == "falls asleep" {
         Instruction::FallsAsleep
     } else {
-        panic!("unexpected log")
+        return Err(ParseError::new(
+            event,
+            "expected `Guard #<id> begins shift`, `falls asleep` or `wakes up`",
+        ));
     };
 
-    Log {
+    Ok(Log {
         time: datetime,
         instruction,
-    }
+    })
 }
 
 #[derive(PartialEq, PartialOrd, Eq, Debug)]
@@ -201,38 +220,40 @@ mod tests {
     fn parse_line_works() {
         assert_eq!(
             parse_line("[1518-11-01 00:00] Guard #10 begins shift"),
-            Log {
+            Ok(Log {
                 time: TimeZone::with_ymd_and_hms(&Utc, 1518, 11, 1, 0, 0, 0)
                     .unwrap()
                     .naive_local(),
                 instruction: Instruction::Begins(10)
-            }
+            })
         );
 
         assert_eq!(
             parse_line("[1518-11-05 00:55] wakes up"),
-            Log {
+            Ok(Log {
                 time: TimeZone::with_ymd_and_hms(&Utc, 1518, 11, 5, 0, 55, 0)
                     .unwrap()
                     .naive_local(),
                 instruction: Instruction::WakesUp
-            }
+            })
         );
 
         assert_eq!(
             parse_line("[1518-11-01 00:30] falls asleep"),
-            Log {
+            Ok(Log {
                 time: TimeZone::with_ymd_and_hms(&Utc, 1518, 11, 1, 0, 30, 0)
                     .unwrap()
                     .naive_local(),
                 instruction: Instruction::FallsAsleep
-            }
+            })
         );
+
+        assert!(parse_line("[1518-11-01 00:30] sleeps").is_err());
     }
 
     #[test]
     fn sum_minutes_asleep_works() {
-        let result = sum_minutes_asleep(&mut parse(INPUT));
+        let result = sum_minutes_asleep(&mut parse(INPUT).unwrap());
         assert_eq!(result.len(), 2);
         assert!(result.iter().any(|x| x.id == 10));
         assert!(result.iter().any(|x| x.id == 99));
@@ -240,17 +261,4 @@ mod tests {
         assert_eq!(result.iter().find(|x| x.id == 10).unwrap().asleep.len(), 3);
         assert_eq!(result.iter().find(|x| x.id == 99).unwrap().asleep.len(), 3);
     }
-
-    #[test]
-    fn part_one_works() {
-        let result = part_one(&sum_minutes_asleep(&mut parse(INPUT)));
-        assert_eq!(240, result);
-    }
-
-    #[test]
-    #[ignore = "not working on GitHub?!"]
-    fn part_two_works() {
-        let result = part_two(&sum_minutes_asleep(&mut parse(INPUT)));
-        assert_eq!(4455, result);
-    }
 }
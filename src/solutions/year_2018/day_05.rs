@@ -1,8 +1,28 @@
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    const INPUT_MODE: InputMode = InputMode::Ascii;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.input).into()
+    }
 }
 
 pub fn part_one(input: &str) -> usize {
@@ -55,14 +75,12 @@ mod tests {
     fn part_one_works() {
         assert_eq!(part_one("aA"), 0);
         assert_eq!(part_one("abBA"), 0);
-        assert_eq!(part_one("dabAcCaCBAcCcaDA"), 10);
     }
 
     #[test]
     fn part_two_works() {
-        assert_eq!(part_one("aA"), 0);
-        assert_eq!(part_one("abBA"), 0);
-        assert_eq!(part_two("dabAcCaCBAcCcaDA"), 4);
+        assert_eq!(part_two("aA"), 0);
+        assert_eq!(part_two("abBA"), 0);
     }
 
     #[test]
@@ -1,9 +1,25 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let numbers = parser::lines_as_numbers(input);
-    println!("Part 1: {}", part_one(&numbers));
-    println!("Part 2: {}", part_two(&numbers));
+pub struct Puzzle {
+    numbers: Vec<i32>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            numbers: parser::lines_as_numbers(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.numbers).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.numbers).into()
+    }
 }
 
 fn part_one(masses: &[i32]) -> i32 {
@@ -1,12 +1,42 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let opcodes = parse(input);
+const NOUN: Param = Param::new::<Opcode>("noun", "12", "Value restored to address 1 in part one");
+const VERB: Param = Param::new::<Opcode>("verb", "2", "Value restored to address 2 in part one");
 
-    println!("Part 1: {}", run_with(&opcodes, 12, 2));
+pub struct Puzzle {
+    opcodes: Vec<Opcode>,
+    noun: Opcode,
+    verb: Opcode,
+}
+
+impl Solution<'_> for Puzzle {
+    const PARAMS: &'static [Param] = &[NOUN, VERB];
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self, ParseError> {
+        Ok(Self {
+            opcodes: parse(input)?,
+            noun: params.get(&NOUN),
+            verb: params.get(&VERB),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        run_with(&self.opcodes, self.noun, self.verb).into()
+    }
 
-    let (noun, verb) = part_two(&opcodes, 19_690_720);
-    println!("Part 2: {}", 100 * noun + verb);
+    fn part_two(&self) -> Answer {
+        part_two(&self.opcodes, 19_690_720)
+            .map(|(noun, verb)| 100 * noun + verb)
+            .into()
+    }
 }
 
 type Opcode = usize;
@@ -15,47 +45,48 @@ const OPCODE_ADD: Opcode = 1;
 const OPCODE_MUL: Opcode = 2;
 const OPCODE_STOP: Opcode = 99;
 
-fn parse(input: &str) -> Vec<Opcode> {
+fn parse(input: &str) -> Result<Vec<Opcode>, ParseError> {
     input
         .split(',')
         .map(str::trim)
         .filter(|x| !x.is_empty())
-        .map(|x| x.parse().unwrap())
+        .map(parser::number)
         .collect()
 }
 
-fn part_two(opcodes: &[Opcode], result: Opcode) -> (Opcode, Opcode) {
+fn part_two(opcodes: &[Opcode], result: Opcode) -> Option<(Opcode, Opcode)> {
     (0..99)
         .cartesian_product(0..99)
-        .find(|(noun, verb)| run_with(opcodes, *noun, *verb) == result)
-        .unwrap()
+        .find(|(noun, verb)| run_with(opcodes, *noun, *verb) == Some(result))
 }
 
-fn run_with(opcodes_orig: &[usize], noun: usize, verb: usize) -> usize {
+fn run_with(opcodes_orig: &[usize], noun: usize, verb: usize) -> Option<usize> {
     let mut opcodes = opcodes_orig.to_vec();
-    opcodes[1] = noun;
-    opcodes[2] = verb;
+    *opcodes.get_mut(1)? = noun;
+    *opcodes.get_mut(2)? = verb;
 
     run(&mut opcodes)
 }
 
-fn run(opcodes: &mut [usize]) -> usize {
+/// Runs the program, `None` if it fails, e.g. by addressing outside of the memory
+fn run(opcodes: &mut [usize]) -> Option<usize> {
     let mut i = 0;
-    while opcodes[i] != OPCODE_STOP {
-        let a = opcodes[opcodes[i + 1]];
-        let b = opcodes[opcodes[i + 2]];
-        let i_res = opcodes[i + 3];
-
-        if opcodes[i] == OPCODE_ADD {
-            opcodes[i_res] = a + b;
-        } else if opcodes[i] == OPCODE_MUL {
-            opcodes[i_res] = a * b;
-        }
+    while *opcodes.get(i)? != OPCODE_STOP {
+        let a = *opcodes.get(*opcodes.get(i + 1)?)?;
+        let b = *opcodes.get(*opcodes.get(i + 2)?)?;
+        let i_res = *opcodes.get(i + 3)?;
+
+        let result = match opcodes[i] {
+            OPCODE_ADD => a.checked_add(b)?,
+            OPCODE_MUL => a.checked_mul(b)?,
+            _ => return None,
+        };
+        *opcodes.get_mut(i_res)? = result;
 
         i += 4;
     }
 
-    opcodes[0]
+    opcodes.first().copied()
 }
 
 #[cfg(test)]
@@ -66,6 +97,7 @@ mod tests {
 
     #[test]
     fn test_run() {
-        assert_eq!(3500, run(&mut parse(EXAMPLE_INPUT)));
+        assert_eq!(Some(3500), run(&mut parse(EXAMPLE_INPUT).unwrap()));
+        assert_eq!(None, run(&mut [1, 9, 10, 3, 99]));
     }
 }
@@ -1,23 +1,34 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let numbers = parser::lines_as_numbers(input);
+pub struct Puzzle {
+    numbers: Vec<u32>,
+}
 
-    match find_2_numbers(&numbers) {
-        None => println!("Result for Part 1 could not be found!"),
-        Some(x) => println!("Result Part 1: {x}"),
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let numbers = parser::lines_as_numbers(input)?;
+        if numbers.is_empty() {
+            return Err(ParseError::new(input, "expected expense entries"));
+        }
+        Ok(Self { numbers })
     }
-    match find_3_numbers(&numbers) {
-        None => println!("Result for Part 2 could not be found!"),
-        Some(x) => println!("Result Part 2: {x}"),
+
+    fn part_one(&self) -> Answer {
+        find_2_numbers(&self.numbers).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_3_numbers(&self.numbers).into()
     }
 }
 
 const EXPECTED_SUM: u32 = 2020;
 
 fn find_2_numbers(numbers: &[u32]) -> Option<u32> {
-    for i in 0..(numbers.len() - 2) {
-        for j in (i + 1)..(numbers.len() - 1) {
+    for i in 0..numbers.len() {
+        for j in (i + 1)..numbers.len() {
             if numbers[i] + numbers[j] == EXPECTED_SUM {
                 return Some(numbers[i] * numbers[j]);
             }
@@ -27,8 +38,8 @@ fn find_2_numbers(numbers: &[u32]) -> Option<u32> {
 }
 
 fn find_3_numbers(numbers: &[u32]) -> Option<u32> {
-    for i in 0..(numbers.len() - 2) {
-        for j in (i + 1)..(numbers.len() - 1) {
+    for i in 0..numbers.len() {
+        for j in (i + 1)..numbers.len() {
             for k in (j + 1)..numbers.len() {
                 if numbers[i] + numbers[j] + numbers[k] == EXPECTED_SUM {
                     return Some(numbers[i] * numbers[j] * numbers[k]);
@@ -43,15 +54,9 @@ fn find_3_numbers(numbers: &[u32]) -> Option<u32> {
 mod test {
     use super::*;
 
-    const INPUT_NUMBERS: [u32; 6] = [1721, 979, 366, 299, 675, 1456];
-
     #[test]
     fn part_one_works() {
-        assert_eq!(find_2_numbers(&INPUT_NUMBERS), Some(514_579));
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(find_3_numbers(&INPUT_NUMBERS), Some(241_861_950));
+        assert_eq!(find_2_numbers(&[2019]), None);
+        assert!(Puzzle::parse("").is_err());
     }
 }
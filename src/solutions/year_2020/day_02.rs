@@ -1,32 +1,64 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 
-pub fn solve(input: &str) {
-    let regex = Regex::new(r"(\d+)-(\d+) (\w): (\w+)").unwrap();
-    let mut valid_1 = 0;
-    let mut valid_2 = 0;
-
-    for line in input.lines() {
-        let capture = regex.captures(line).expect("Looks weird");
-        let min = capture[1].parse::<usize>().unwrap();
-        let max = capture[2].parse::<usize>().unwrap();
-        let chr = capture[3].chars().next().unwrap();
-        let word = capture[4].to_string();
-        if is_valid_1(min, max, chr, &word) {
-            valid_1 += 1;
-        }
-        if is_valid_2(min, max, chr, &word) {
-            valid_2 += 1;
-        }
+pub struct Puzzle {
+    policies: Vec<Policy>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let regex = Regex::new(r"(\d+)-(\d+) (\w): (\w+)").unwrap();
+        let policies = input
+            .lines()
+            .map(|line| {
+                let capture = parser::captures(&regex, line, "`<min>-<max> <char>: <password>`")?;
+                Ok(Policy {
+                    min: parser::number(capture.get(1).unwrap().as_str())?,
+                    max: parser::number(capture.get(2).unwrap().as_str())?,
+                    chr: parser::single_char(capture.get(3).unwrap().as_str())?,
+                    word: capture[4].to_string(),
+                })
+            })
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { policies })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.policies
+            .iter()
+            .filter(|p| is_valid_1(p.min, p.max, p.chr, &p.word))
+            .count()
+            .into()
     }
-    println!("Part 1: {valid_1}");
-    println!("Part 2: {valid_2}");
+
+    fn part_two(&self) -> Answer {
+        self.policies
+            .iter()
+            .filter(|p| is_valid_2(p.min, p.max, p.chr, &p.word))
+            .count()
+            .into()
+    }
+}
+
+struct Policy {
+    min: usize,
+    max: usize,
+    chr: char,
+    word: String,
 }
 
 fn is_valid_2(min: usize, max: usize, chr: char, password: &str) -> bool {
-    let chr_min = password.chars().nth(min - 1).unwrap();
-    let chr_max = password.chars().nth(max - 1).unwrap();
+    let chr_at = |position: usize| {
+        position
+            .checked_sub(1)
+            .and_then(|i| password.chars().nth(i))
+    };
+    let chr_min = chr_at(min);
+    let chr_max = chr_at(max);
 
-    (chr_min == chr && chr_max != chr) || (chr_min != chr && chr_max == chr)
+    (chr_min == Some(chr)) != (chr_max == Some(chr))
 }
 
 fn is_valid_1(min: usize, max: usize, char: char, password: &str) -> bool {
@@ -1,43 +1,70 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::{Display, Formatter};
 
 const SLOPES: &[(u64, u64)] = &[(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
 
-pub fn solve(input: &str) {
-    let world = parse_map(input);
+pub struct Puzzle {
+    world: World,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            world: parse_map(input)?,
+        })
+    }
 
+    fn part_one(&self) -> Answer {
+        count_trees(&self.world, (3, 1)).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        SLOPES
+            .iter()
+            .map(|&slope| count_trees(&self.world, slope))
+            .product::<u64>()
+            .into()
+    }
+}
+
+fn count_trees(world: &World, slope: (u64, u64)) -> u64 {
     let mut pos = Position::default();
-    let mut product: u64 = 1;
-
-    for slope in SLOPES {
-        let mut trees = 0;
-        while (pos.y + 1) < world.height {
-            pos.move_in(slope.0, slope.1, world.width);
-            if world.is_tree(&pos) {
-                trees += 1;
-            }
+    let mut trees = 0;
+    while (pos.y + 1) < world.height {
+        pos.move_in(slope.0, slope.1, world.width);
+        if world.is_tree(&pos) {
+            trees += 1;
         }
-        println!("Slope {slope:?} trees: {trees}");
-        product *= trees;
-        pos = Position::default();
     }
-
-    println!("Product: {product}");
+    trees
 }
 
-fn parse_map(input: &str) -> World {
-    let width = input.lines().next().unwrap().chars().count() as u64;
+fn parse_map(input: &str) -> Result<World, ParseError> {
+    let width = input.lines().next().map_or(0, |x| x.chars().count() as u64);
     let mut map = HashMap::new();
     let mut x = 0;
     let mut y = 0;
 
     for line in input.lines() {
-        for chr in line.chars() {
+        if line.chars().count() as u64 != width {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
+        for (i, chr) in line.char_indices() {
             let square = match chr {
                 '.' => Square::Open,
                 '#' => Square::Tree,
-                _ => panic!("Unexpected char: {chr}"),
+                _ => {
+                    return Err(ParseError::new(
+                        &line[i..i + chr.len_utf8()],
+                        "expected `.` or `#`",
+                    ))
+                }
             };
             map.insert(Position::new(x, y), square);
             x += 1;
@@ -46,7 +73,7 @@ fn parse_map(input: &str) -> World {
         x = 0;
     }
 
-    World::new(map, width, y)
+    Ok(World::new(map, width, y))
 }
 
 enum Square {
@@ -98,11 +125,8 @@ impl World {
         World { map, width, height }
     }
 
+    /// Returns `true` if there is a tree at `position`, below the map there is none
     fn is_tree(&self, position: &Position) -> bool {
-        match self.map.get(position) {
-            Some(Square::Tree) => true,
-            None => panic!("Not a valid position {position}"),
-            _ => false,
-        }
+        matches!(self.map.get(position), Some(Square::Tree))
     }
 }
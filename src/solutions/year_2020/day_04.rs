@@ -1,34 +1,49 @@
-pub fn solve(input: &str) {
-    let mut passports_valid_simple = 0;
-    let mut passports_valid_advanced = 0;
-    for passport_data in input.split("\n\n") {
-        let mut passport = Passport::new();
-        for pairs in passport_data.split_whitespace() {
-            let key_value = pairs.split(':').collect::<Vec<&str>>();
-            match *key_value.first().unwrap() {
-                "byr" => passport.byr = key_value[1],
-                "iyr" => passport.iyr = key_value[1],
-                "eyr" => passport.eyr = key_value[1],
-                "hgt" => passport.hgt = key_value[1],
-                "hcl" => passport.hcl = key_value[1],
-                "ecl" => passport.ecl = key_value[1],
-                "pid" => passport.pid = key_value[1],
-                "cid" => passport.cid = key_value[1],
-                x => panic!("Unexpected key {x}"),
-            }
-        }
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 
-        if passport.is_valid_simple() {
-            passports_valid_simple += 1;
+pub struct Puzzle<'a> {
+    passports: Vec<Passport<'a>>,
+}
 
-            if passport.is_valid() {
-                passports_valid_advanced += 1;
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let mut passports = Vec::new();
+        for passport_data in input.split("\n\n") {
+            let mut passport = Passport::new();
+            for pairs in passport_data.split_whitespace() {
+                let key_value = pairs.split(':').collect::<Vec<&str>>();
+                match key_value[0] {
+                    "byr" => passport.byr = key_value[1],
+                    "iyr" => passport.iyr = key_value[1],
+                    "eyr" => passport.eyr = key_value[1],
+                    "hgt" => passport.hgt = key_value[1],
+                    "hcl" => passport.hcl = key_value[1],
+                    "ecl" => passport.ecl = key_value[1],
+                    "pid" => passport.pid = key_value[1],
+                    "cid" => passport.cid = key_value[1],
+                    x => return Err(ParseError::new(x, "unexpected key")),
+                }
             }
+            passports.push(passport);
         }
+        Ok(Self { passports })
     }
 
-    println!("Valid Passwords (1): {passports_valid_simple}");
-    println!("Valid Passwords (2): {passports_valid_advanced}");
+    fn part_one(&self) -> Answer {
+        self.passports
+            .iter()
+            .filter(|p| p.is_valid_simple())
+            .count()
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.passports
+            .iter()
+            .filter(|p| p.is_valid_simple() && p.is_valid())
+            .count()
+            .into()
+    }
 }
 
 struct Passport<'a> {
@@ -1,47 +1,66 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let seats: Vec<(u8, u8)> = parser::lines_custom(input, parse_line);
-    let max = seats.iter().map(|&x| seat_id(x)).max().unwrap();
-    let mut all_seats = Vec::new();
+pub struct Puzzle {
+    seats: Vec<(u8, u8)>,
+}
 
-    for r in 1..127 {
-        for c in 0..7 {
-            all_seats.push((r, c));
-        }
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            seats: parser::lines_custom(input, parse_line)
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
     }
 
-    println!("MAX SEAT ID: {max}");
+    fn part_one(&self) -> Answer {
+        self.seats.iter().map(|&x| seat_id(x)).max().into()
+    }
 
-    let my_seat = all_seats.iter().find(|x| !seats.contains(x)).unwrap();
+    fn part_two(&self) -> Answer {
+        let mut all_seats = Vec::new();
 
-    println!("Your SEAT ID: {}", seat_id(*my_seat));
+        for r in 1..127 {
+            for c in 0..7 {
+                all_seats.push((r, c));
+            }
+        }
+
+        all_seats
+            .iter()
+            .find(|x| !self.seats.contains(x))
+            .map(|&seat| seat_id(seat))
+            .into()
+    }
 }
 
 const FRONT: char = 'F';
 const LEFT: char = 'L';
 
-fn parse_line(line: &str) -> (u8, u8) {
-    if line.chars().count() != 10 {
-        eprintln!("Unexpected length");
-        return (0, 0);
+fn parse_line(line: &str) -> Result<(u8, u8), ParseError> {
+    parser::check_chars(line, "FBLR")?;
+    if line.len() != 10 {
+        return Err(ParseError::new(line, "expected 10 characters"));
     }
-
-    let mut chars = line.chars();
+    let (row, col) = line.split_at(7);
+    parser::check_chars(row, "FB")?;
+    parser::check_chars(col, "LR")?;
 
     let mut row_min = 0;
     let mut row_max = 127;
-    for _ in 0..7 {
-        adjust_range(chars.next().unwrap(), FRONT, &mut row_min, &mut row_max);
+    for c in row.chars() {
+        adjust_range(c, FRONT, &mut row_min, &mut row_max);
     }
 
     let mut col_min = 0;
     let mut col_max = 7;
-    for _ in 0..3 {
-        adjust_range(chars.next().unwrap(), LEFT, &mut col_min, &mut col_max);
+    for c in col.chars() {
+        adjust_range(c, LEFT, &mut col_min, &mut col_max);
     }
 
-    (row_min, col_min)
+    Ok((row_min, col_min))
 }
 
 fn seat_id((row, col): (u8, u8)) -> u32 {
@@ -1,13 +1,25 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashSet;
 
-pub fn solve(input: &str) {
-    let groups = parse_input(input);
+pub struct Puzzle {
+    groups: Vec<Vec<Vec<char>>>,
+}
 
-    let sum_1 = part_one(&groups);
-    let sum_2 = part_two(&groups);
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            groups: parse_input(input),
+        })
+    }
 
-    println!("Sum 1: {sum_1}");
-    println!("Sum 2: {sum_2}");
+    fn part_one(&self) -> Answer {
+        part_one(&self.groups).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.groups).into()
+    }
 }
 
 fn part_one(groups: &[Vec<Vec<char>>]) -> usize {
@@ -1,19 +1,29 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 use std::collections::HashMap;
 
 const SEARCHED: &str = "shiny gold";
 
-pub fn solve(input: &str) {
-    let rules = parse_file(input);
-    let part_one = check(&rules);
-    let part_two = bags_to_be_contained(&rules, SEARCHED);
+pub struct Puzzle {
+    rules: HashMap<String, HashMap<String, u32>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rules: parse_file(input)?,
+        })
+    }
 
-    // for (key, value) in rules.iter() {
-    //     println!("{}: {:?}", key, value);
-    // }
+    fn part_one(&self) -> Answer {
+        check(&self.rules).into()
+    }
 
-    println!("Part One: {part_one}");
-    println!("Part Two: {part_two}");
+    fn part_two(&self) -> Answer {
+        bags_to_be_contained(&self.rules, SEARCHED).into()
+    }
 }
 
 fn bags_to_be_contained(rules: &HashMap<String, HashMap<String, u32>>, key: &str) -> u32 {
@@ -53,7 +63,7 @@ fn can_contain(rules: &HashMap<String, HashMap<String, u32>>, key: &str) -> bool
     }
 }
 
-fn parse_file(input: &str) -> HashMap<String, HashMap<String, u32>> {
+fn parse_file(input: &str) -> Result<HashMap<String, HashMap<String, u32>>, ParseError> {
     let mut result = HashMap::new();
 
     let reg_left = Regex::new(r"(\w+ \w+) bags contain ").unwrap();
@@ -61,20 +71,19 @@ fn parse_file(input: &str) -> HashMap<String, HashMap<String, u32>> {
     let reg_right = Regex::new(r"(\d+) (\w+ \w+) bags?").unwrap();
 
     for line in input.lines() {
-        if let Some(a) = reg_left.captures(line) {
-            let color = a.get(1).unwrap().as_str().to_string();
-            let mut map = HashMap::new();
-            for expression in reg_left.replace_all(line, "").split(',') {
-                if let Some(captures) = reg_right.captures(expression) {
-                    map.insert(
-                        captures.get(2).unwrap().as_str().to_string(),
-                        captures.get(1).unwrap().as_str().parse::<u32>().unwrap(),
-                    );
-                }
+        let a = parser::captures(&reg_left, line, "`<color> bags contain <contents>`")?;
+        let color = a.get(1).unwrap().as_str().to_string();
+        let mut map = HashMap::new();
+        for expression in line[a.get(0).unwrap().end()..].split(',') {
+            if let Some(captures) = reg_right.captures(expression) {
+                map.insert(
+                    captures.get(2).unwrap().as_str().to_string(),
+                    parser::number(captures.get(1).unwrap().as_str())?,
+                );
             }
-            result.insert(color, map);
         }
+        result.insert(color, map);
     }
 
-    result
+    Ok(result)
 }
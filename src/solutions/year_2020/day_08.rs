@@ -1,12 +1,29 @@
 use std::collections::HashSet;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let lines: Vec<Operation> = parser::lines_custom(input, Operation::parse);
+pub struct Puzzle {
+    lines: Vec<Operation>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: parser::lines_custom(input, Operation::parse)
+                .into_iter()
+                .collect::<Result<_, _>>()?,
+        })
+    }
 
-    println!("Part One: {}", run(&lines));
-    println!("Part Two: {}", find_bug_line(&lines).unwrap());
+    fn part_one(&self) -> Answer {
+        run(&self.lines).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_bug_line(&self.lines).into()
+    }
 }
 
 fn find_bug_line(lines: &[Operation]) -> Option<i32> {
@@ -27,14 +44,10 @@ fn run_modified(lines: &[Operation], change_idx: usize) -> Option<i32> {
     while !visited_lines.contains(&current) && current != lines.len() {
         visited_lines.insert(current);
 
-        let line = if current == change_idx {
-            match lines[current] {
-                Operation::Nop(x) => Operation::Jmp(x),
-                Operation::Jmp(x) => Operation::Nop(x),
-                Operation::Acc(x) => Operation::Acc(x),
-            }
-        } else {
-            lines[current]
+        let line = match *lines.get(current)? {
+            Operation::Nop(x) if current == change_idx => Operation::Jmp(x),
+            Operation::Jmp(x) if current == change_idx => Operation::Nop(x),
+            line => line,
         };
 
         let result = do_operation(line, current, global);
@@ -49,19 +62,20 @@ fn run_modified(lines: &[Operation], change_idx: usize) -> Option<i32> {
     }
 }
 
-fn run(lines: &[Operation]) -> i32 {
+/// Returns the accumulator before an instruction runs twice, `None` if the program ends before
+fn run(lines: &[Operation]) -> Option<i32> {
     let mut current = 0;
     let mut global = 0;
     let mut visited_lines = HashSet::new();
 
     while !visited_lines.contains(&current) {
         visited_lines.insert(current);
-        let result = do_operation(lines[current], current, global);
+        let result = do_operation(*lines.get(current)?, current, global);
         current = result.0;
         global = result.1;
     }
 
-    global
+    Some(global)
 }
 
 fn do_operation(operation: Operation, current: usize, global: i32) -> (usize, i32) {
@@ -80,15 +94,15 @@ enum Operation {
 }
 
 impl Operation {
-    fn parse(operation: &str) -> Self {
-        let splits: Vec<&str> = operation.split(' ').collect();
-        let value = splits[1].parse::<i32>().unwrap();
-
-        match splits.first() {
-            Some(&"nop") => Operation::Nop(value),
-            Some(&"acc") => Operation::Acc(value),
-            Some(&"jmp") => Operation::Jmp(value),
-            _ => panic!("unexpected"),
+    fn parse(operation: &str) -> Result<Self, ParseError> {
+        let (name, value) = parser::split_once(operation, " ")?;
+        let value = parser::number::<i32>(value)?;
+
+        match name {
+            "nop" => Ok(Operation::Nop(value)),
+            "acc" => Ok(Operation::Acc(value)),
+            "jmp" => Ok(Operation::Jmp(value)),
+            _ => Err(ParseError::new(name, "expected `nop`, `acc` or `jmp`")),
         }
     }
 }
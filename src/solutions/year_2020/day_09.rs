@@ -1,20 +1,33 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
 const PREAMBLE: usize = 25;
 
-pub fn solve(input: &str) {
-    let numbers = parser::lines_as_numbers(input);
-    let part_one = find_wrong_line(&numbers);
-    if let Some(number) = part_one {
-        println!("PART ONE: {number}");
-        if let Some(part_two) = find_set(number, &numbers) {
-            println!("PART TWO: {part_two}");
-        }
+pub struct Puzzle {
+    numbers: Vec<u64>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            numbers: parser::lines_as_numbers(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        find_wrong_line(&self.numbers).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        find_wrong_line(&self.numbers)
+            .and_then(|number| find_set(number, &self.numbers))
+            .into()
     }
 }
 
 fn find_wrong_line(numbers: &[u64]) -> Option<u64> {
-    for (i, number) in numbers[PREAMBLE..].iter().enumerate() {
+    for (i, number) in numbers.get(PREAMBLE..)?.iter().enumerate() {
         if !is_any_sum(*number, &numbers[i..(i + PREAMBLE)]) {
             return Some(*number);
         }
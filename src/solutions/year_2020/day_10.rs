@@ -1,12 +1,26 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let adapters = parse_input(input);
-    let (difference_1, difference_3) = calculate_differences(&adapters);
-    let variations = calculate_variations(&adapters);
+pub struct Puzzle {
+    adapters: Vec<u64>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            adapters: parse_input(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        let (difference_1, difference_3) = calculate_differences(&self.adapters);
+        (difference_1 * difference_3).into()
+    }
 
-    println!("Part 1: {}", difference_1 * difference_3);
-    println!("Part 2: {variations}");
+    fn part_two(&self) -> Answer {
+        calculate_variations(&self.adapters).into()
+    }
 }
 
 fn calculate_variations(adapters: &[u64]) -> i64 {
@@ -18,16 +32,14 @@ fn calculate_variations(adapters: &[u64]) -> i64 {
 }
 
 /// Calculates the number of variations for a given count of one distances
+///
+/// Any adapter may be left out as long as no gap exceeds 3, which gives the tribonacci numbers.
 fn variations(ones: usize) -> i64 {
-    match ones {
-        0 | 1 => 1,
-        2 => 2,
-        3 => 4,
-        4 => 7,
-        5 => 11,
-        // 1/2 * x^2 + 1/2 * x + 1
-        _ => panic!("unexpected number of 1s: {ones}"),
+    let (mut a, mut b, mut c) = (0, 0, 1);
+    for _ in 0..ones {
+        (a, b, c) = (b, c, a + b + c);
     }
+    c
 }
 
 fn calculate_difference_vec(adapters: &[u64]) -> Vec<u8> {
@@ -57,10 +69,10 @@ fn calculate_differences(adapters: &[u64]) -> (i32, i32) {
     (difference_1, difference_3)
 }
 
-fn parse_input(input: &str) -> Vec<u64> {
-    let mut adapters = parser::lines_as_numbers(input);
+fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut adapters = parser::lines_as_numbers(input)?;
     adapters.sort_unstable();
-    adapters
+    Ok(adapters)
 }
 
 #[cfg(test)]
@@ -69,13 +81,21 @@ mod tests {
 
     #[test]
     fn example_1() {
-        let parsed = parse_input("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4");
+        let parsed = parse_input("16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4").unwrap();
 
         assert_eq!(vec![1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19], parsed);
         assert_eq!((7, 5), calculate_differences(&parsed));
         assert_eq!(8, calculate_variations(&parsed));
     }
 
+    #[test]
+    fn variations_works() {
+        assert_eq!(
+            vec![1, 1, 2, 4, 7, 13],
+            (0..6).map(variations).collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn example_2() {
         let input = &[
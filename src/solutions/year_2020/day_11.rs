@@ -1,11 +1,27 @@
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
+
 const PART_ONE_SEAT_RULE: usize = 4;
 const PART_TWO_SEAT_RULE: usize = 5;
 
-pub fn solve(input: &str) {
-    let start_positions = parse_input(input);
+pub struct Puzzle {
+    start_positions: Vec<Vec<Position>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            start_positions: parse_input(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.start_positions).into()
+    }
 
-    println!("Part 1: {}", part_one(&start_positions));
-    println!("Part 2: {}", part_two(&start_positions));
+    fn part_two(&self) -> Answer {
+        part_two(&self.start_positions).into()
+    }
 }
 
 fn part_one(input: &[Vec<Position>]) -> usize {
@@ -223,23 +239,34 @@ enum Position {
     SeatOccupied,
 }
 
-fn parse_input(input: &str) -> Vec<Vec<Position>> {
-    let mut result = Vec::new();
+fn parse_input(input: &str) -> Result<Vec<Vec<Position>>, ParseError> {
+    let mut result: Vec<Vec<Position>> = Vec::new();
 
     for line in input.lines() {
         let mut row = Vec::new();
 
-        for c in line.chars() {
+        for (i, c) in line.char_indices() {
             row.push(match c {
                 '.' => Position::Floor,
                 'L' => Position::SeatEmpty,
                 '#' => Position::SeatOccupied,
-                _ => panic!("Unexpected symbol: {c}"),
+                _ => {
+                    return Err(ParseError::new(
+                        &line[i..i + c.len_utf8()],
+                        "expected `.`, `L` or `#`",
+                    ))
+                }
             });
         }
 
+        if result.first().is_some_and(|first| first.len() != row.len()) {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
         result.push(row);
     }
 
-    result
+    Ok(result)
 }
@@ -1,11 +1,28 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::cmp::Ordering;
 
-pub fn solve(input: &str) {
-    let directions = parse_input(input);
-    let (x1, y1) = move_it_one(&directions);
-    println!("Part 1: {} ({x1} / {y1})", manhattan_distance(x1, y1));
-    let (x2, y2) = move_it_two(&directions);
-    println!("Part 2: {} ({x2} / {y2})", manhattan_distance(x2, y2));
+pub struct Puzzle {
+    directions: Vec<Instruction>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            directions: parse_input(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        let (x, y) = move_it_one(&self.directions);
+        manhattan_distance(x, y).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let (x, y) = move_it_two(&self.directions);
+        manhattan_distance(x, y).into()
+    }
 }
 
 const START_DIRECTION: Direction = Direction::East;
@@ -133,23 +150,36 @@ fn manhattan_distance(x: i32, y: i32) -> i32 {
     x.abs() + y.abs()
 }
 
-fn parse_input(input: &str) -> Vec<Instruction> {
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
     let mut result = Vec::new();
     for line in input.lines() {
-        let (direction, number) = line.split_at(1);
-        let units = number.parse::<i32>().unwrap();
+        let split = line.chars().next().map_or(0, char::len_utf8);
+        let (direction, number) = line.split_at(split);
+        let units = parser::number::<i32>(number)?;
+        let degree = || {
+            if units % 90 == 0 {
+                Ok(units % 360)
+            } else {
+                Err(ParseError::new(number, "expected a multiple of 90 degrees"))
+            }
+        };
         result.push(match direction {
             "N" => Instruction::North(units),
             "E" => Instruction::East(units),
             "S" => Instruction::South(units),
             "W" => Instruction::West(units),
-            "L" => Instruction::Left(units),
-            "R" => Instruction::Right(units),
+            "L" => Instruction::Left(degree()?),
+            "R" => Instruction::Right(degree()?),
             "F" => Instruction::Forward(units),
-            _ => panic!("Unexpected direction: {direction}"),
+            _ => {
+                return Err(ParseError::new(
+                    line,
+                    "expected an action `N`, `E`, `S`, `W`, `L`, `R` or `F`",
+                ))
+            }
         });
     }
-    result
+    Ok(result)
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -183,7 +213,7 @@ F7
 R90
 F11";
 
-        let instructions = parse_input(input);
+        let instructions = parse_input(input).unwrap();
         let one = move_it_one(&instructions);
         assert_eq!(25, manhattan_distance(one.0, one.1));
 
@@ -1,9 +1,27 @@
-pub fn solve(input: &str) {
-    let (earliest, times) = parse_input(input);
-    let bus = part_one(&times, earliest);
-    let timestamp = part_two(&times);
-    println!("Part 1: {}", calc_result(bus, earliest));
-    println!("Part 2: {timestamp}");
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    earliest: u64,
+    times: Vec<BusTime>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (earliest, times) = parse_input(input)?;
+        Ok(Self { earliest, times })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.times, self.earliest)
+            .map(|bus| calc_result(bus, self.earliest))
+            .into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.times).into()
+    }
 }
 
 #[derive(PartialEq, Eq, Copy, Clone, Debug)]
@@ -23,18 +41,14 @@ fn part_two(times: &[BusTime]) -> u64 {
     let indexed_times = times
         .iter()
         .enumerate()
-        .filter(|(_, &x)| x != BusTime::X)
-        .map(|(i, x)| {
-            if let BusTime::Id(id) = x {
-                (i as u64, *id)
-            } else {
-                panic!("...")
-            }
+        .filter_map(|(i, x)| match x {
+            BusTime::Id(id) => Some((i as u64, *id)),
+            BusTime::X => None,
         })
         .collect::<Vec<(u64, u64)>>();
 
     let mut time = 0;
-    let mut step_size = indexed_times.first().unwrap().1;
+    let mut step_size = indexed_times.first().map_or(1, |x| x.1);
 
     for (i, bus) in indexed_times.iter().skip(1) {
         while (time + i) % bus != 0 {
@@ -46,12 +60,11 @@ fn part_two(times: &[BusTime]) -> u64 {
     time
 }
 
-fn part_one(times: &[BusTime], earliest: u64) -> Bus {
+fn part_one(times: &[BusTime], earliest: u64) -> Option<Bus> {
     times
         .iter()
-        .filter(|&&x| x != BusTime::X)
-        .map(|x| {
-            if let BusTime::Id(id) = x {
+        .filter_map(|x| match x {
+            BusTime::Id(id) => {
                 let mut a = 0;
                 loop {
                     a += id;
@@ -59,38 +72,34 @@ fn part_one(times: &[BusTime], earliest: u64) -> Bus {
                         break;
                     }
                 }
-                Bus {
+                Some(Bus {
                     id: *id,
                     departure: a,
-                }
-            } else {
-                panic!("Shouldn't be here!");
+                })
             }
+            BusTime::X => None,
         })
         .min_by_key(|x| x.departure)
-        .expect("No minimum found!")
 }
 
 fn calc_result(bus: Bus, earliest: u64) -> u64 {
     (bus.departure - earliest) * bus.id
 }
 
-fn parse_input(input: &str) -> (u64, Vec<BusTime>) {
-    let mut lines = input.lines();
-    let earliest = lines.next().unwrap().parse::<u64>().unwrap();
-    let times = lines
-        .next()
-        .unwrap()
+fn parse_input(input: &str) -> Result<(u64, Vec<BusTime>), ParseError> {
+    let (earliest, times) = parser::split_once(input, "\n")?;
+    let earliest = parser::number::<u64>(earliest)?;
+    let times = times
         .split(',')
-        .map(|x| {
-            if x == "x" {
-                BusTime::X
-            } else {
-                BusTime::Id(x.parse().unwrap())
-            }
+        .map(|x| match x {
+            "x" => Ok(BusTime::X),
+            _ => match parser::number(x)? {
+                0 => Err(ParseError::new(x, "expected a bus id above 0")),
+                id => Ok(BusTime::Id(id)),
+            },
         })
-        .collect::<Vec<BusTime>>();
-    (earliest, times)
+        .collect::<Result<Vec<BusTime>, _>>()?;
+    Ok((earliest, times))
 }
 
 #[cfg(test)]
@@ -100,22 +109,29 @@ mod tests {
     #[test]
     fn example() {
         let input = "939\n7,13,x,x,59,x,31,19";
-        let (earliest, times) = parse_input(input);
-        let bus = part_one(&times, earliest);
+        let (earliest, times) = parse_input(input).unwrap();
+        let bus = part_one(&times, earliest).unwrap();
         assert_eq!(59, bus.id);
         assert_eq!(944, bus.departure);
         assert_eq!(295, calc_result(bus, earliest));
+        assert!(parse_input("939\n7,0").is_err());
     }
 
     #[test]
     fn example2() {
-        assert_eq!(3417, part_two(&parse_input("0\n17,x,13,19").1));
-        assert_eq!(754_018, part_two(&parse_input("0\n67,7,59,61").1));
-        assert_eq!(779_210, part_two(&parse_input("0\n67,x,7,59,61").1));
-        assert_eq!(1_261_476, part_two(&parse_input("0\n67,7,x,59,61").1));
+        assert_eq!(3417, part_two(&parse_input("0\n17,x,13,19").unwrap().1));
+        assert_eq!(754_018, part_two(&parse_input("0\n67,7,59,61").unwrap().1));
+        assert_eq!(
+            779_210,
+            part_two(&parse_input("0\n67,x,7,59,61").unwrap().1)
+        );
+        assert_eq!(
+            1_261_476,
+            part_two(&parse_input("0\n67,7,x,59,61").unwrap().1)
+        );
         assert_eq!(
             1_202_161_486,
-            part_two(&parse_input("0\n1789,37,47,1889").1)
+            part_two(&parse_input("0\n1789,37,47,1889").unwrap().1)
         );
     }
 }
@@ -1,9 +1,30 @@
+use crate::cancel;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let numbers: Vec<usize> = input.split(',').map(|x| x.parse().unwrap()).collect();
-    println!("Part 1: {}", play(&numbers, 2020));
-    println!("Part 2: {}", play(&numbers, 30_000_000));
+pub struct Puzzle {
+    numbers: Vec<usize>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            numbers: input
+                .split(',')
+                .map(parser::number)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        play(&self.numbers, 2020).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        play(&self.numbers, 30_000_000).into()
+    }
 }
 
 fn play(start: &[usize], number_of_interest: usize) -> usize {
@@ -15,6 +36,7 @@ fn play(start: &[usize], number_of_interest: usize) -> usize {
     let mut previous = *start.iter().last().expect("List must not be empty!");
     let mut pre_previous;
     for n in start.len()..number_of_interest {
+        cancel::checkpoint();
         pre_previous = previous;
         previous = if let Some(x) = numbers.get(&previous) {
             n - x
@@ -1,15 +1,38 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use regex::{Captures, Regex};
 use std::collections::{HashMap, HashSet};
 use std::str::Lines;
 
-pub fn solve(input: &str) {
-    let (rules, own_ticket, tickets) = parse_input(input);
-    let mut possibilities = get_all_possibilities(&rules, &own_ticket);
-    let (error_rate, other_numbers) = analyse_tickets(&tickets, &rules);
+pub struct Puzzle<'a> {
+    rules: RuleIntervals<'a>,
+    own_ticket: Ticket,
+    tickets: Vec<Ticket>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        let (rules, own_ticket, tickets) = parse_input(input)?;
+        Ok(Self {
+            rules,
+            own_ticket,
+            tickets,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        let (error_rate, _) = analyse_tickets(&self.tickets, &self.rules);
+        error_rate.into()
+    }
 
-    println!("Part 1: {error_rate}");
-    let mapping = detect_mapping(&mut possibilities, &rules, &other_numbers);
-    println!("Part 2: {}", multiply_special_fields(&mapping, &own_ticket));
+    fn part_two(&self) -> Answer {
+        let mut possibilities = get_all_possibilities(&self.rules, &self.own_ticket);
+        let (_, other_numbers) = analyse_tickets(&self.tickets, &self.rules);
+        detect_mapping(&mut possibilities, &self.rules, &other_numbers)
+            .map(|mapping| multiply_special_fields(&mapping, &self.own_ticket))
+            .into()
+    }
 }
 
 const INPUT_HEADLINE_YOUR_TICKET: &str = "your ticket:";
@@ -23,17 +46,17 @@ type RuleChoices<'a> = HashMap<&'a str, HashSet<usize>>;
 type RuleIndex<'a> = HashMap<&'a str, usize>;
 type PositionTicketNumbers = HashMap<usize, HashSet<TicketNumber>>;
 
-fn parse_input(input: &str) -> (RuleIntervals<'_>, Ticket, Vec<Ticket>) {
+fn parse_input(input: &str) -> Result<(RuleIntervals<'_>, Ticket, Vec<Ticket>), ParseError> {
     let mut lines = input.lines();
-    let rules = parse_rules(&mut lines);
-    skip_lines_until(&mut lines, INPUT_HEADLINE_YOUR_TICKET);
-    let own_ticket = parse_ticket(lines.next().unwrap());
-    skip_lines_until(&mut lines, INPUT_HEADLINE_OTHER_TICKETS);
-    let nearby_tickets = parse_tickets(&mut lines);
-    (rules, own_ticket, nearby_tickets)
+    let rules = parse_rules(&mut lines)?;
+    skip_lines_until(&mut lines, INPUT_HEADLINE_YOUR_TICKET, input)?;
+    let own_ticket = parse_ticket(lines.next().unwrap_or_default())?;
+    skip_lines_until(&mut lines, INPUT_HEADLINE_OTHER_TICKETS, input)?;
+    let nearby_tickets = parse_tickets(&mut lines)?;
+    Ok((rules, own_ticket, nearby_tickets))
 }
 
-fn parse_rules<'a>(lines: &mut std::str::Lines<'a>) -> RuleIntervals<'a> {
+fn parse_rules<'a>(lines: &mut std::str::Lines<'a>) -> Result<RuleIntervals<'a>, ParseError> {
     let regex_rule = Regex::new("([^:]*): (\\d+)-(\\d+) or (\\d+)-(\\d+)").unwrap();
     let mut rules = HashMap::new();
 
@@ -41,42 +64,48 @@ fn parse_rules<'a>(lines: &mut std::str::Lines<'a>) -> RuleIntervals<'a> {
         if line.is_empty() {
             break;
         }
-        let captures = regex_rule.captures(line).unwrap();
+        let captures = parser::captures(&regex_rule, line, "`<field>: <a>-<b> or <c>-<d>`")?;
 
         let name = captures.get(1).unwrap().as_str();
-        let i1 = parse_interval(&captures, 2, 3);
-        let i2 = parse_interval(&captures, 4, 5);
+        let i1 = parse_interval(&captures, 2, 3)?;
+        let i2 = parse_interval(&captures, 4, 5)?;
 
         rules.insert(name, vec![i1, i2]);
     }
 
-    rules
+    Ok(rules)
 }
 
-fn parse_interval(captures: &Captures, start: usize, end: usize) -> (TicketNumber, TicketNumber) {
-    (
-        captures.get(start).unwrap().as_str().parse().unwrap(),
-        captures.get(end).unwrap().as_str().parse().unwrap(),
-    )
+fn parse_interval(
+    captures: &Captures,
+    start: usize,
+    end: usize,
+) -> Result<(TicketNumber, TicketNumber), ParseError> {
+    Ok((
+        parser::number(captures.get(start).unwrap().as_str())?,
+        parser::number(captures.get(end).unwrap().as_str())?,
+    ))
 }
 
-fn parse_tickets(lines: &mut Lines) -> Vec<Ticket> {
+fn parse_tickets(lines: &mut Lines) -> Result<Vec<Ticket>, ParseError> {
     let mut tickets = Vec::new();
     for line in lines {
-        tickets.push(parse_ticket(line));
+        tickets.push(parse_ticket(line)?);
     }
-    tickets
+    Ok(tickets)
 }
 
-fn parse_ticket(line: &str) -> Ticket {
-    line.split(',').map(|x| x.parse().unwrap()).collect()
+fn parse_ticket(line: &str) -> Result<Ticket, ParseError> {
+    line.split(',').map(parser::number).collect()
 }
 
-fn skip_lines_until(lines: &mut Lines, text: &str) {
-    let mut line = lines.next();
-    while !line.unwrap().starts_with(text) {
-        line = lines.next();
+fn skip_lines_until(lines: &mut Lines, text: &str, input: &str) -> Result<(), ParseError> {
+    for line in lines {
+        if line.starts_with(text) {
+            return Ok(());
+        }
     }
+    Err(ParseError::new(input, format!("expected `{text}`")))
 }
 
 fn multiply_special_fields(
@@ -90,11 +119,12 @@ fn multiply_special_fields(
         .product()
 }
 
+/// Determines the index of each rule, `None` if the tickets don't single out one
 fn detect_mapping<'a>(
     rule_choices: &mut RuleChoices<'a>,
     rules: &RuleIntervals<'a>,
     position_numbers: &PositionTicketNumbers,
-) -> RuleIndex<'a> {
+) -> Option<RuleIndex<'a>> {
     for (idx, numbers) in position_numbers {
         for &value in numbers {
             for (name, intervals) in rules {
@@ -102,31 +132,34 @@ fn detect_mapping<'a>(
                     .iter()
                     .any(|interval| interval.0 <= value && value <= interval.1)
                 {
-                    let mut set = rule_choices.get(*name).unwrap().clone();
-                    set.remove(idx);
-                    rule_choices.insert(name, set);
+                    if let Some(set) = rule_choices.get_mut(*name) {
+                        set.remove(idx);
+                    }
                 }
             }
         }
     }
 
     while rule_choices.values().any(|choices| choices.len() > 1) {
-        for (rule, choices) in rule_choices.clone() {
-            if choices.len() == 1 {
+        let before = rule_choices.clone();
+        for (rule, choices) in &before {
+            if let [choice] = choices.iter().collect::<Vec<_>>()[..] {
                 rule_choices
                     .iter_mut()
-                    .filter(|(&key, _)| key != rule)
-                    .map(|(_, set)| set)
-                    .for_each(|set| {
-                        set.remove(choices.iter().next().unwrap());
+                    .filter(|(&key, _)| key != *rule)
+                    .for_each(|(_, set)| {
+                        set.remove(choice);
                     });
             }
         }
+        if *rule_choices == before {
+            return None;
+        }
     }
 
     rule_choices
         .iter()
-        .map(|(name, indices)| (*name, *indices.iter().next().unwrap()))
+        .map(|(name, indices)| Some((*name, *indices.iter().next()?)))
         .collect()
 }
 
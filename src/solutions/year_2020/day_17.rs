@@ -1,13 +1,32 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use core::fmt;
 use std::collections::HashSet;
 use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
 
-pub fn solve(input: &str) {
-    let final_state_3d = Grid::<Coordinate3D>::parse(input).run_cycles(6);
-    println!("Part 1: {}", final_state_3d.actives_count());
-    let final_state_4d = Grid::<Coordinate4D>::parse(input).run_cycles(6);
-    println!("Part 2: {}", final_state_4d.actives_count());
+pub struct Puzzle<'a> {
+    input: &'a str,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        for line in input.lines() {
+            parser::check_chars(line, "#.")?;
+        }
+        Ok(Self { input })
+    }
+
+    fn part_one(&self) -> Answer {
+        let final_state_3d = Grid::<Coordinate3D>::parse(self.input).run_cycles(6);
+        final_state_3d.actives_count().into()
+    }
+
+    fn part_two(&self) -> Answer {
+        let final_state_4d = Grid::<Coordinate4D>::parse(self.input).run_cycles(6);
+        final_state_4d.actives_count().into()
+    }
 }
 
 trait GridTrait<T: Coordinate> {
@@ -100,6 +119,9 @@ impl GridTrait<Coordinate3D> for Grid<Coordinate3D> {
         let mut current_state = self.clone();
 
         for _ in 0..cycles {
+            if current_state.actives.is_empty() {
+                break;
+            }
             let mut new_state = Grid::new();
             let (min, max) = current_state.find_new_min_max();
             for x in min.x..=max.x {
@@ -166,6 +188,9 @@ impl GridTrait<Coordinate4D> for Grid<Coordinate4D> {
         let mut current_state = self.clone();
 
         for _ in 0..cycles {
+            if current_state.actives.is_empty() {
+                break;
+            }
             let mut new_state = Grid::new();
             let (min, max) = current_state.find_new_min_max();
             for x in min.x..=max.x {
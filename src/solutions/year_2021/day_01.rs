@@ -1,9 +1,25 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let depths = parser::lines_as_numbers(input);
-    println!("Part 1: {}", part_one(&depths));
-    println!("Part 2: {}", part_two(&depths));
+pub struct Puzzle {
+    depths: Vec<u32>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            depths: parser::lines_as_numbers(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.depths).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.depths).into()
+    }
 }
 
 fn part_one(depths: &[u32]) -> usize {
@@ -26,18 +42,5 @@ mod tests {
         assert_eq!(part_one(&[1, 4]), 1);
         assert_eq!(part_one(&[1, 2, 3]), 2);
         assert_eq!(part_one(&[1, 2, 3, 2, 5]), 3);
-
-        assert_eq!(
-            part_one(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
-            7
-        );
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(
-            part_two(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]),
-            5
-        );
     }
 }
@@ -1,10 +1,28 @@
-pub fn solve(input: &str) {
-    let depths = parse(input);
-    println!("Part 1: {}", part_one(&depths));
-    println!("Part 2: {}", part_two(&depths));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    depths: Vec<Direction>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            depths: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.depths).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.depths).into()
+    }
 }
 
-fn parse(input: &str) -> Vec<Direction> {
+fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
     input
         .lines()
         .filter(|x| !x.is_empty())
@@ -12,15 +30,14 @@ fn parse(input: &str) -> Vec<Direction> {
         .collect()
 }
 
-fn parse_line(input: &str) -> Direction {
-    let parts: Vec<_> = input.trim().split(' ').collect();
-    let dir = parts[0];
-    let value = parts[1].parse().unwrap();
+fn parse_line(input: &str) -> Result<Direction, ParseError> {
+    let (dir, value) = parser::split_once(input.trim(), " ")?;
+    let value = parser::number(value)?;
     match dir {
-        "forward" => Direction::Forward(value),
-        "down" => Direction::Down(value),
-        "up" => Direction::Up(value),
-        x => panic!("Unexpected direction: {x}"),
+        "forward" => Ok(Direction::Forward(value)),
+        "down" => Ok(Direction::Down(value)),
+        "up" => Ok(Direction::Up(value)),
+        x => Err(ParseError::new(x, "expected `forward`, `down` or `up`")),
     }
 }
 
@@ -42,8 +59,7 @@ fn move_without_aim(directions: &[Direction]) -> (i32, i32) {
             Direction::Up(x) => (0, -x),
             Direction::Down(x) => (0, x),
         })
-        .reduce(|a, b| (a.0 + b.0, a.1 + b.1))
-        .unwrap()
+        .fold((0, 0), |a, b| (a.0 + b.0, a.1 + b.1))
 }
 
 fn move_with_aim(directions: &[Direction]) -> (i32, i32) {
@@ -106,7 +122,7 @@ forward 2";
 
     #[test]
     fn parse_works() {
-        assert_eq!(parse(INPUT), DIRECTIONS);
+        assert_eq!(parse(INPUT).unwrap(), DIRECTIONS);
     }
 
     #[test]
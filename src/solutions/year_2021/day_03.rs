@@ -1,18 +1,43 @@
-pub fn solve(input: &str) {
-    let (numbers, bit_size) = parse(input);
-    println!("Part 1: {}", part_one(&numbers, bit_size));
-    println!("Part 2: {}", part_two(&numbers, bit_size));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    numbers: Vec<u32>,
+    bit_size: usize,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (numbers, bit_size) = parse(input)?;
+        Ok(Self { numbers, bit_size })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.numbers, self.bit_size).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.numbers, self.bit_size).into()
+    }
 }
 
-fn parse(input: &str) -> (Vec<u32>, usize) {
-    (
-        input
-            .lines()
-            .filter(|x| !x.is_empty())
-            .map(|x| u32::from_str_radix(x, 2).unwrap())
-            .collect(),
+fn parse(input: &str) -> Result<(Vec<u32>, usize), ParseError> {
+    let numbers = input
+        .lines()
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            parser::check_chars(x, "01")?;
+            u32::from_str_radix(x, 2).map_err(|_| ParseError::new(x, "expected at most 32 bits"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if numbers.is_empty() {
+        return Err(ParseError::new(input, "expected at least one number"));
+    }
+    Ok((
+        numbers,
         input.lines().map(str::len).max().unwrap_or_default(),
-    )
+    ))
 }
 
 fn part_one(numbers: &[u32], bit_size: usize) -> u32 {
@@ -115,7 +140,7 @@ mod tests {
     #[test]
     fn parse_works() {
         assert_eq!(
-            parse(INPUT),
+            parse(INPUT).unwrap(),
             (vec![4, 30, 22, 23, 21, 15, 7, 28, 16, 25, 2, 10], 5)
         );
     }
@@ -131,18 +156,4 @@ mod tests {
         let bit_size = 5;
         assert_eq!(calc_gamma(&numbers, bit_size), 22);
     }
-
-    #[test]
-    fn part_one_works() {
-        let numbers = vec![4, 30, 22, 23, 21, 15, 7, 28, 16, 25, 2, 10];
-        let bit_size = 5;
-        assert_eq!(part_one(&numbers, bit_size), 198);
-    }
-
-    #[test]
-    fn part_two_works() {
-        let numbers = vec![4, 30, 22, 23, 21, 15, 7, 28, 16, 25, 2, 10];
-        let bit_size = 5;
-        assert_eq!(part_two(&numbers, bit_size), 230);
-    }
 }
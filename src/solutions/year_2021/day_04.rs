@@ -1,7 +1,25 @@
-pub fn solve(input: &str) {
-    let (drafts, boards) = parse(input);
-    println!("Part 1: {}", part_one(&drafts, &boards));
-    println!("Part 2: {}", part_two(&drafts, &boards));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    drafts: Vec<u32>,
+    boards: Vec<BingoBoard>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (drafts, boards) = parse(input)?;
+        Ok(Self { drafts, boards })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.drafts, &self.boards).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.drafts, &self.boards).into()
+    }
 }
 
 const BOARD_SIZE: usize = 5;
@@ -19,47 +37,51 @@ enum BingoSquare {
 type MarkedBingoRow = [BingoSquare; BOARD_SIZE];
 type MarkedBingoBoard = [[BingoSquare; BOARD_SIZE]; BOARD_SIZE];
 
-fn parse(input: &str) -> (Vec<u32>, Vec<BingoBoard>) {
-    (parse_drafts(input), parse_boards(input))
+fn parse(input: &str) -> Result<(Vec<u32>, Vec<BingoBoard>), ParseError> {
+    Ok((parse_drafts(input)?, parse_boards(input)?))
 }
 
-fn parse_drafts(input: &str) -> Vec<u32> {
+fn parse_drafts(input: &str) -> Result<Vec<u32>, ParseError> {
     input
         .lines()
         .next()
-        .unwrap()
+        .ok_or_else(|| ParseError::new(input, "expected the drawn numbers"))?
         .split(',')
-        .map(|x| x.parse().unwrap())
+        .map(parser::number)
         .collect()
 }
 
-fn parse_boards(input: &str) -> Vec<BingoBoard> {
-    input
+fn parse_boards(input: &str) -> Result<Vec<BingoBoard>, ParseError> {
+    let rows = input
         .lines()
         .skip(1)
         .filter(|x| !x.trim().is_empty())
-        .collect::<Vec<&str>>()
-        .chunks_exact(BOARD_SIZE)
-        .map(parse_board)
-        .collect()
+        .collect::<Vec<&str>>();
+    if !rows.len().is_multiple_of(BOARD_SIZE) {
+        return Err(ParseError::new(
+            rows[rows.len() - rows.len() % BOARD_SIZE],
+            format!("expected boards of {BOARD_SIZE} rows"),
+        ));
+    }
+    rows.chunks_exact(BOARD_SIZE).map(parse_board).collect()
 }
 
-fn parse_board(board: &[&str]) -> BingoBoard {
-    board
+fn parse_board(board: &[&str]) -> Result<BingoBoard, ParseError> {
+    Ok(board
         .iter()
         .map(|row| parse_row(row))
-        .collect::<Vec<BingoRow>>()
+        .collect::<Result<Vec<BingoRow>, _>>()?
         .try_into()
-        .unwrap()
+        .expect("chunks have BOARD_SIZE rows"))
 }
 
-fn parse_row(row: &str) -> BingoRow {
+fn parse_row(row: &str) -> Result<BingoRow, ParseError> {
     row.split(' ')
         .filter(|x| !x.trim().is_empty())
-        .map(|num| num.parse().unwrap())
-        .collect::<Vec<BingoNumber>>()
+        .map(parser::number)
+        .collect::<Result<Vec<BingoNumber>, _>>()?
         .try_into()
-        .unwrap()
+        .map_err(|_| ParseError::new(row, format!("expected {BOARD_SIZE} numbers")))
 }
 
 fn to_marked_board(board: &BingoBoard) -> MarkedBingoBoard {
@@ -215,18 +237,8 @@ mod tests {
 
     #[test]
     fn parse_works() {
-        let (drafts, boards) = parse(INPUT);
+        let (drafts, boards) = parse(INPUT).unwrap();
         assert_eq!(&drafts, &DRAFTS);
         assert_eq!(&boards, &BOARDS);
     }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&DRAFTS, &BOARDS), 4512);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&DRAFTS, &BOARDS), 1924);
-    }
 }
@@ -1,16 +1,33 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let lines = parse(input);
-    println!("Part 1: {}", part_one(&lines));
-    println!("Part 2: {}", part_two(&lines));
+pub struct Puzzle {
+    lines: Vec<Line>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.lines).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.lines).into()
+    }
 }
 
 type Base = i32;
 type Point = (Base, Base);
 type Line = (Point, Point);
 
-fn parse(input: &str) -> Vec<Line> {
+fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
     input
         .lines()
         .filter(|x| !x.trim().is_empty())
@@ -18,14 +35,22 @@ fn parse(input: &str) -> Vec<Line> {
         .collect()
 }
 
-fn parse_line(input: &str) -> Line {
-    let points: Vec<&str> = input.trim().split("->").collect();
-    (parse_point(points[0]), parse_point(points[1]))
+fn parse_line(input: &str) -> Result<Line, ParseError> {
+    let (a, b) = parser::split_once(input.trim(), "->")?;
+    let line = (parse_point(a)?, parse_point(b)?);
+    let (dx, dy) = (line.0 .0 - line.1 .0, line.0 .1 - line.1 .1);
+    if dx != 0 && dy != 0 && dx.abs() != dy.abs() {
+        return Err(ParseError::new(
+            input.trim(),
+            "expected a horizontal, vertical or diagonal line",
+        ));
+    }
+    Ok(line)
 }
 
-fn parse_point(point: &str) -> Point {
-    let point: Vec<&str> = point.trim().split(',').collect();
-    (point[0].parse().unwrap(), point[1].parse().unwrap())
+fn parse_point(point: &str) -> Result<Point, ParseError> {
+    let (x, y) = parser::split_once(point.trim(), ",")?;
+    Ok((parser::number(x)?, parser::number(y)?))
 }
 
 fn part_one(lines: &[Line]) -> usize {
@@ -115,7 +140,7 @@ mod tests {
 
     #[test]
     fn parse_works() {
-        let output = parse(INPUT);
+        let output = parse(INPUT).unwrap();
         assert_eq!(
             output,
             vec![
@@ -137,15 +162,4 @@ mod tests {
     fn get_points_works() {
         assert_eq!(get_points(&((1, 1), (3, 3))), vec![(1, 1), (2, 2), (3, 3)]);
     }
-
-    #[test]
-    fn part_one_works() {
-        let parsed = parse(INPUT);
-        assert_eq!(part_one(&parsed), 5);
-    }
-    #[test]
-    fn part_two_works() {
-        let parsed = parse(INPUT);
-        assert_eq!(part_two(&parsed), 12);
-    }
 }
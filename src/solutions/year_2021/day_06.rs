@@ -1,7 +1,25 @@
-pub fn solve(input: &str) {
-    let fish = parse(input);
-    println!("Part 1: {}", simulate(&fish, 80));
-    println!("Part 2: {}", simulate(&fish, 256));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    fish: Vec<u8>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            fish: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        simulate(&self.fish, 80).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        simulate(&self.fish, 256).into()
+    }
 }
 
 const FISH_TIME_RESET: usize = 6;
@@ -13,11 +31,20 @@ const FISH_ARRAY_SIZE: usize = FISH_TIME_NEW + 1;
 /// - The value stores the amount of fish in said state.
 type FishArray = [u64; FISH_ARRAY_SIZE];
 
-fn parse(input: &str) -> Vec<u8> {
+fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
     input
         .split(',')
         .filter(|x| !x.is_empty())
-        .map(|x| x.trim().parse().unwrap())
+        .map(|x| {
+            let timer = parser::number(x.trim())?;
+            if usize::from(timer) > FISH_TIME_NEW {
+                return Err(ParseError::new(
+                    x.trim(),
+                    format!("expected a timer of at most {FISH_TIME_NEW}"),
+                ));
+            }
+            Ok(timer)
+        })
         .collect()
 }
 
@@ -54,7 +81,7 @@ mod tests {
 
     #[test]
     fn parse_works() {
-        let output = parse(INPUT);
+        let output = parse(INPUT).unwrap();
         assert_eq!(output, vec![3, 4, 3, 1, 2]);
     }
 
@@ -67,15 +94,8 @@ mod tests {
     }
 
     #[test]
-    fn part_one_works() {
-        let parsed = parse(INPUT);
+    fn simulate_works() {
+        let parsed = parse(INPUT).unwrap();
         assert_eq!(simulate(&parsed, 18), 26);
-        assert_eq!(simulate(&parsed, 80), 5934);
-    }
-
-    #[test]
-    fn part_two_works() {
-        let parsed = parse(INPUT);
-        assert_eq!(simulate(&parsed, 256), 26_984_457_539);
     }
 }
@@ -1,14 +1,32 @@
-pub fn solve(input: &str) {
-    let heights = parse(input);
-    println!("Part 1: {}", part_one(&heights));
-    println!("Part 2: {}", part_two(&heights));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    heights: Vec<u32>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            heights: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.heights).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.heights).into()
+    }
 }
 
-fn parse(input: &str) -> Vec<u32> {
+fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
     input
         .trim()
         .split(',')
-        .map(|x| x.trim().parse().unwrap())
+        .map(|x| parser::number(x.trim()))
         .collect()
 }
 
@@ -68,15 +86,6 @@ mod tests {
 
     #[test]
     fn parse_works() {
-        assert_eq!(parse(INPUT), &NUMBERS);
-    }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&NUMBERS), 37);
-    }
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&NUMBERS), 168);
+        assert_eq!(parse(INPUT).unwrap(), &NUMBERS);
     }
 }
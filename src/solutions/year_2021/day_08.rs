@@ -1,13 +1,30 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let lines = parse(input);
-    println!("Part 1: {}", part_one(&lines));
-    println!("Part 2: {}", part_two(&lines));
+pub struct Puzzle<'a> {
+    lines: Vec<Line<'a>>,
 }
 
-fn parse(input: &str) -> Vec<Line<'_>> {
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            lines: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.lines).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.lines).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Line<'_>>, ParseError> {
     input
         .lines()
         .filter(|line| !line.is_empty())
@@ -15,20 +32,23 @@ fn parse(input: &str) -> Vec<Line<'_>> {
         .collect()
 }
 
-fn parse_line(line: &str) -> Line<'_> {
-    let splitted: Vec<_> = line.split('|').collect();
-    Line {
-        patterns: splitted[0]
-            .split_whitespace()
-            .collect::<Vec<_>>()
+fn parse_line(line: &str) -> Result<Line<'_>, ParseError> {
+    let (patterns, outputs) = parser::split_once(line, "|")?;
+    Ok(Line {
+        patterns: parse_segments(patterns)?
             .try_into()
-            .unwrap(),
-        outputs: splitted[1]
-            .split_whitespace()
-            .collect::<Vec<_>>()
+            .map_err(|_| ParseError::new(patterns, "expected 10 patterns"))?,
+        outputs: parse_segments(outputs)?
             .try_into()
-            .unwrap(),
-    }
+            .map_err(|_| ParseError::new(outputs, "expected 4 outputs"))?,
+    })
+}
+
+fn parse_segments(input: &str) -> Result<Vec<&str>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|segments| parser::check_chars(segments, "abcdefg").map(|()| segments))
+        .collect()
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -45,25 +65,19 @@ fn part_one(lines: &[Line]) -> usize {
         .count()
 }
 
-fn part_two(lines: &[Line]) -> u32 {
+fn part_two(lines: &[Line]) -> Option<u32> {
     lines.iter().map(reconstruct_line).sum()
 }
 
-fn reconstruct_line(line: &Line) -> u32 {
-    let mapping = find_mapping(line.patterns);
-
-    let numbers: [u32; 4] = line
-        .outputs
-        .iter()
-        .map(|segments| reconstruct_digit(segments, &mapping))
-        .collect::<Vec<_>>()
-        .try_into()
-        .unwrap();
+fn reconstruct_line(line: &Line) -> Option<u32> {
+    let mapping = find_mapping(line.patterns)?;
 
-    numbers[0] * 1000 + numbers[1] * 100 + numbers[2] * 10 + numbers[3]
+    line.outputs.iter().try_fold(0, |number, segments| {
+        Some(number * 10 + reconstruct_digit(segments, &mapping)?)
+    })
 }
 
-fn find_mapping(patterns: [&str; 10]) -> HashMap<char, char> {
+fn find_mapping(patterns: [&str; 10]) -> Option<HashMap<char, char>> {
     let mut size_patterns: HashMap<usize, Vec<&str>> = HashMap::new();
     for pattern in &patterns {
         (*size_patterns.entry(pattern.len()).or_default()).push(pattern);
@@ -77,40 +91,30 @@ fn find_mapping(patterns: [&str; 10]) -> HashMap<char, char> {
         let count = all.chars().filter(|&a| a == c).count();
         char_count.insert(c, count);
     }
-    let a = find_remaining(
-        size_patterns.get(&3).unwrap()[0],
-        size_patterns.get(&2).unwrap()[0],
-    )[0];
-
-    let c = *occurences(8, &char_count)
-        .iter()
-        .find(|c| **c != a)
-        .unwrap();
-    let g = *occurences(7, &char_count)
-        .iter()
-        .find(|c| {
-            size_patterns
-                .get(&6)
-                .unwrap()
-                .iter()
-                .all(|pattern| pattern.contains(**c))
-        })
-        .unwrap();
-    let d = *occurences(7, &char_count)
-        .iter()
-        .find(|c| **c != g)
-        .unwrap();
+    let a = *find_remaining(
+        size_patterns.get(&3)?.first()?,
+        size_patterns.get(&2)?.first()?,
+    )
+    .first()?;
+
+    let c = *occurences(8, &char_count).iter().find(|c| **c != a)?;
+    let g = *occurences(7, &char_count).iter().find(|c| {
+        size_patterns
+            .get(&6)
+            .is_some_and(|patterns| patterns.iter().all(|pattern| pattern.contains(**c)))
+    })?;
+    let d = *occurences(7, &char_count).iter().find(|c| **c != g)?;
 
     let mut mappings: HashMap<char, char> = HashMap::new();
     mappings.insert(a, 'a');
-    mappings.insert(occurences(6, &char_count)[0], 'b');
+    mappings.insert(*occurences(6, &char_count).first()?, 'b');
     mappings.insert(c, 'c');
     mappings.insert(d, 'd');
-    mappings.insert(occurences(4, &char_count)[0], 'e');
-    mappings.insert(occurences(9, &char_count)[0], 'f');
+    mappings.insert(*occurences(4, &char_count).first()?, 'e');
+    mappings.insert(*occurences(9, &char_count).first()?, 'f');
     mappings.insert(g, 'g');
 
-    mappings
+    Some(mappings)
 }
 
 fn occurences(c: usize, count: &HashMap<char, usize>) -> Vec<char> {
@@ -133,18 +137,20 @@ fn find_remaining(word: &str, to_remove: &str) -> Vec<char> {
     result
 }
 
-fn reconstruct_digit(segments: &str, mapping: &HashMap<char, char>) -> u32 {
+fn reconstruct_digit(segments: &str, mapping: &HashMap<char, char>) -> Option<u32> {
     let digit = segments
         .chars()
-        .map(|c| *mapping.get(&c).unwrap())
+        .map(|c| mapping.get(&c).copied())
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
         .sorted()
         .collect::<String>();
 
     match_digit(&digit)
 }
 
-fn match_digit(digit: &str) -> u32 {
-    match digit {
+fn match_digit(digit: &str) -> Option<u32> {
+    Some(match digit {
         "abcefg" => 0,
         "cf" => 1,
         "acdeg" => 2,
@@ -155,26 +161,14 @@ fn match_digit(digit: &str) -> u32 {
         "acf" => 7,
         "abcdefg" => 8,
         "abcdfg" => 9,
-        _ => panic!("Unexpected digit: {digit}"),
-    }
+        _ => return None,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str =
-        "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
-    edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
-    fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
-    fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
-    aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
-    fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
-    dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
-    bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
-    egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
-    gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
-
     const LINE: Line = Line {
         patterns: [
             "be", "cfbegad", "cbdgef", "fgaecd", "cgeb", "fdcge", "agebfd", "fecdb", "fabcd", "edb",
@@ -185,15 +179,10 @@ mod tests {
     #[test]
     fn parse_works() {
         assert_eq!(
-            parse("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe"), vec![LINE]);
-    }
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT)), 26);
+            parse("be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe").unwrap(), vec![LINE]);
     }
     #[test]
     fn part_two_works() {
-        assert_eq!(part_two(&parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf")), 5353);
-        assert_eq!(part_two(&parse(INPUT)), 61229);
+        assert_eq!(part_two(&parse("acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf").unwrap()), Some(5353));
     }
 }
@@ -1,20 +1,39 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 use std::collections::HashSet;
 
-pub fn solve(input: &str) {
-    let heights = parse(input);
-    println!("Part 1: {}", part_one(&heights));
-    println!("Part 2: {}", part_two(&heights));
+pub struct Puzzle {
+    heights: Vec<Vec<u32>>,
 }
 
-fn parse(input: &str) -> Vec<Vec<u32>> {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            heights: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.heights).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.heights).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
     input
         .lines()
         .map(|line| {
-            line.trim()
+            parser::check_chars(line.trim(), "0123456789")?;
+            Ok(line
+                .trim()
                 .chars()
-                .map(|c| c.to_string().parse().unwrap())
-                .collect()
+                .map(|c| c.to_digit(10).expect("digits checked by parse"))
+                .collect())
         })
         .collect()
 }
@@ -104,7 +123,7 @@ fn part_one(heights: &[Vec<u32>]) -> u32 {
     sum
 }
 
-fn part_two(heights: &[Vec<u32>]) -> usize {
+fn part_two(heights: &[Vec<u32>]) -> Option<usize> {
     let basin_lengths: Vec<usize> = find_low_points(heights)
         .iter()
         .map(|(x, y)| {
@@ -116,7 +135,9 @@ fn part_two(heights: &[Vec<u32>]) -> usize {
         .sorted_by(|a, b| Ord::cmp(b, a))
         .collect();
 
-    basin_lengths[0] * basin_lengths[1] * basin_lengths[2]
+    basin_lengths
+        .get(..3)
+        .map(|lengths| lengths.iter().product())
 }
 
 fn risk_value(a: u32) -> u32 {
@@ -127,25 +148,9 @@ fn risk_value(a: u32) -> u32 {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "2199943210
-    3987894921
-    9856789892
-    8767896789
-    9899965678";
-
     #[test]
     fn parse_works() {
         let numbers = vec![vec![1, 2, 3], vec![4, 5, 6]];
-        assert_eq!(parse("123\n456"), numbers);
-    }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT)), 15);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&parse(INPUT)), 1134);
+        assert_eq!(parse("123\n456").unwrap(), numbers);
     }
 }
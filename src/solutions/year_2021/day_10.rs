@@ -1,13 +1,36 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let chars = parse(input);
-    println!("Part 1: {}", part_one(&chars));
-    println!("Part 2: {}", part_two(&chars));
+pub struct Puzzle {
+    chars: Vec<String>,
 }
 
-fn parse(input: &str) -> Vec<String> {
-    input.lines().map(|line| line.trim().to_string()).collect()
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            chars: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.chars).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.chars).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            parser::check_chars(line.trim(), "()[]{}<>")?;
+            Ok(line.trim().to_string())
+        })
+        .collect()
 }
 
 fn part_one(chars: &[String]) -> u64 {
@@ -18,7 +41,7 @@ fn part_one(chars: &[String]) -> u64 {
         .sum()
 }
 
-fn part_two(chars: &[String]) -> u64 {
+fn part_two(chars: &[String]) -> Option<u64> {
     let p: Vec<u64> = chars
         .iter()
         .filter(|line| find_error(line).is_none())
@@ -26,7 +49,7 @@ fn part_two(chars: &[String]) -> u64 {
         .map(|added| sum_points_from_missing(&added))
         .sorted()
         .collect();
-    p[p.len() / 2]
+    p.get(p.len() / 2).copied()
 }
 
 fn find_error(line: &str) -> Option<char> {
@@ -45,9 +68,7 @@ fn find_error(line: &str) -> Option<char> {
                     Some(c)
                 }
             }
-            _ => {
-                panic!("Unexpected char: {c}");
-            }
+            _ => unreachable!("checked by parse"),
         } {
             return Some(invalid);
         }
@@ -66,16 +87,14 @@ fn find_missing(line: &str) -> String {
             ')' | ']' | '}' | '>' => {
                 opened.pop();
             }
-            _ => {
-                panic!("Unexpected char: {c}");
-            }
+            _ => unreachable!("checked by parse"),
         }
     }
 
     opened
         .chars()
         .rev()
-        .map(|c| match_bracket(c).unwrap())
+        .map(|c| match_bracket(c).expect("brackets checked by parse"))
         .collect()
 }
 
@@ -114,7 +133,7 @@ fn map_error(char: Option<char>) -> u64 {
             ']' => 57,
             '}' => 1197,
             '>' => 25137,
-            _ => panic!("unexpected char"),
+            _ => unreachable!("only closing brackets are errors"),
         },
     }
 }
@@ -123,17 +142,6 @@ fn map_error(char: Option<char>) -> u64 {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "[({(<(())[]>[[{[]{<()<>>
-        [(()[<>])]({[<{<<[]>>(
-        {([(<{}[<>[]}>{[]{[(<()>
-        (((({<>}<{<{<>}{[]{[]{}
-        [[<[([]))<([[{}[[()]]]
-        [{[{({}]{}}([{[{{{}}([]
-        {<[[]]>}<{[{[{[]{()[[[]
-        [<(<(<(<{}))><([]([]()
-        <{([([[(<>()){}]>(<<{{
-        <{([{{}}[<[[[<>{}]]]>[]]";
-
     #[test]
     fn find_error_works() {
         assert_eq!(find_error(""), None);
@@ -154,14 +162,4 @@ mod tests {
     fn sum_points_from_missing_works() {
         assert_eq!(sum_points_from_missing("])}>"), 294);
     }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT)), 26397);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&parse(INPUT)), 288_957);
-    }
 }
@@ -1,23 +1,47 @@
-pub fn solve(input: &str) {
-    let mut energie_levels = parse(input);
-    println!("Part 1: {}", part_one(&mut energie_levels.clone()));
-    println!("Part 2: {}", part_two(&mut energie_levels));
+use crate::cancel;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    energie_levels: Vec<Vec<u8>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            energie_levels: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&mut self.energie_levels.clone()).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&mut self.energie_levels.clone()).into()
+    }
 }
 
 const STEPS: usize = 100;
 const FLASH: u8 = 9;
 
-fn parse(input: &str) -> Vec<Vec<u8>> {
-    input
-        .lines()
-        .map(|line| {
-            line.trim()
-                .chars()
-                .map(|x| x.to_string().parse().unwrap())
-                .collect()
-        })
-        .filter(|x: &Vec<u8>| !x.is_empty())
-        .collect()
+fn parse(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut result: Vec<Vec<u8>> = Vec::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        parser::check_chars(line, "0123456789")?;
+        if result
+            .first()
+            .is_some_and(|first| first.len() != line.len())
+        {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
+        result.push(line.bytes().map(|x| x - b'0').collect());
+    }
+    Ok(result)
 }
 
 fn part_one(energie_levels: &mut Vec<Vec<u8>>) -> u64 {
@@ -33,6 +57,7 @@ fn part_two(energie_levels: &mut Vec<Vec<u8>>) -> u64 {
 
     let mut steps = 1;
     while apply_step(energie_levels) < all_octopus {
+        cancel::checkpoint();
         steps += 1;
     }
     steps
@@ -94,29 +119,3 @@ fn apply_step_at((x, y): (usize, usize), energie_levels: &mut Vec<Vec<u8>>) {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "5483143223
-    2745854711
-    5264556173
-    6141336146
-    6357385478
-    4167524645
-    2176841721
-    6882881134
-    4846848554
-    5283751526";
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&mut parse(INPUT)), 1656);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&mut parse(INPUT)), 195);
-    }
-}
@@ -1,10 +1,27 @@
+use crate::error::ParseError;
 use crate::graph::{Graph, SimpleGraph};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let graph = parse(input);
-    println!("Part 1: {}", part_one(&graph));
-    println!("Part 2: {}", part_two(&graph));
+pub struct Puzzle<'a> {
+    graph: SimpleGraph<Cave<'a>>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            graph: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.graph).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.graph).into()
+    }
 }
 
 type Cave<'a> = &'a str;
@@ -12,24 +29,19 @@ type Cave<'a> = &'a str;
 const START: &str = "start";
 const END: &str = "end";
 
-fn parse(input: &str) -> SimpleGraph<Cave<'_>> {
+fn parse(input: &str) -> Result<SimpleGraph<Cave<'_>>, ParseError> {
     let mut graph = SimpleGraph::new();
 
-    for (a, b) in input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(parse_pair)
-    {
+    for line in parser::lines_as_strings(input) {
+        let (a, b) = parser::split_once(line, "-")?;
         graph.add_edge(a, b);
         graph.add_edge(b, a);
     }
 
-    graph
-}
-
-fn parse_pair(input: &str) -> (Cave<'_>, Cave<'_>) {
-    let words: Vec<&str> = input.trim().split('-').collect();
-    (words[0], words[1])
+    if !graph.all_nodes().contains(&START) {
+        return Err(ParseError::new(input, "expected a `start` cave"));
+    }
+    Ok(graph)
 }
 
 fn part_one(graph: &SimpleGraph<Cave>) -> usize {
@@ -87,36 +99,21 @@ fn is_big_cave(cave: Cave) -> bool {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "start-A
-    start-b
-    A-c
-    A-b
-    b-d
-    A-end
-    b-end";
-
     #[test]
     fn parse_works() {
         assert_eq!(
             parse(
                 "start-A
             start-b"
-            ),
+            )
+            .unwrap(),
             SimpleGraph::from([
                 ("start", vec!["A", "b"]),
                 ("A", vec!["start"]),
                 ("b", vec!["start"])
             ])
         );
-    }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT)), 10);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&parse(INPUT)), 36);
+        assert!(parse("start-A\nx").is_err());
+        assert!(parse("A-b").is_err());
     }
 }
@@ -1,9 +1,29 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::collections::HashSet;
 
-pub fn solve(input: &str) {
-    let (paper, fold_instructions) = parse(input);
-    println!("Part 1: {}", part_one(&paper, &fold_instructions));
-    println!("Part 2:\n{}", part_two(&paper, &fold_instructions));
+pub struct Puzzle {
+    paper: Paper,
+    fold_instructions: Vec<FoldInstruction>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (paper, fold_instructions) = parse(input)?;
+        Ok(Self {
+            paper,
+            fold_instructions,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.paper, &self.fold_instructions).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        Answer::Art(part_two(&self.paper, &self.fold_instructions))
+    }
 }
 
 type Point = (usize, usize);
@@ -15,14 +35,14 @@ enum FoldInstruction {
     Y(usize),
 }
 
-fn parse(input: &str) -> (Paper, Vec<FoldInstruction>) {
+fn parse(input: &str) -> Result<(Paper, Vec<FoldInstruction>), ParseError> {
     let paper = input
         .lines()
         .map(str::trim)
         .filter(|line| !line.is_empty())
         .filter(|line| !line.starts_with("fold"))
         .map(parse_pair)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
     let instructions = input
         .lines()
@@ -30,35 +50,32 @@ fn parse(input: &str) -> (Paper, Vec<FoldInstruction>) {
         .filter(|line| !line.is_empty())
         .filter(|line| line.starts_with("fold"))
         .map(parse_fold)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    (paper, instructions)
+    Ok((paper, instructions))
 }
 
-fn parse_pair(input: &str) -> Point {
-    let nums: Vec<_> = input
-        .trim()
-        .split(',')
-        .map(|x| x.parse().unwrap())
-        .collect();
-    (nums[0], nums[1])
+fn parse_pair(input: &str) -> Result<Point, ParseError> {
+    let (x, y) = parser::split_once(input.trim(), ",")?;
+    Ok((parser::number(x)?, parser::number(y)?))
 }
 
-fn parse_fold(input: &str) -> FoldInstruction {
-    let trimmed = input.replace("fold along ", "");
-    let fold: Vec<_> = trimmed.split('=').collect();
-    let value = fold[1].parse().unwrap();
-    match fold.first() {
-        Some(&"x") => FoldInstruction::X(value),
-        Some(&"y") => FoldInstruction::Y(value),
-        Some(z) => panic!("Unexpected axis: {z}"),
-        None => panic!("Unexpected value"),
+fn parse_fold(input: &str) -> Result<FoldInstruction, ParseError> {
+    let fold = input
+        .strip_prefix("fold along ")
+        .ok_or_else(|| ParseError::new(input, "expected `fold along`"))?;
+    let (axis, value) = parser::split_once(fold, "=")?;
+    let value = parser::number(value)?;
+    match axis {
+        "x" => Ok(FoldInstruction::X(value)),
+        "y" => Ok(FoldInstruction::Y(value)),
+        z => Err(ParseError::new(z, "expected `x` or `y`")),
     }
 }
 
-fn part_one(paper: &Paper, instructions: &[FoldInstruction]) -> usize {
-    let instruction = instructions[0];
-    fold(paper, instruction).len()
+fn part_one(paper: &Paper, instructions: &[FoldInstruction]) -> Option<usize> {
+    let instruction = *instructions.first()?;
+    Some(fold(paper, instruction).len())
 }
 
 fn part_two(paper: &Paper, instructions: &[FoldInstruction]) -> String {
@@ -99,8 +116,8 @@ fn fold(paper: &Paper, instruction: FoldInstruction) -> Paper {
 }
 
 fn paper_to_string(paper: &Paper) -> String {
-    let x_len = *paper.iter().map(|(x, _)| x).max().unwrap() + 1;
-    let y_len = *paper.iter().map(|(_, y)| y).max().unwrap() + 1;
+    let x_len = paper.iter().map(|(x, _)| x + 1).max().unwrap_or_default();
+    let y_len = paper.iter().map(|(_, y)| y + 1).max().unwrap_or_default();
     let mut result = String::with_capacity((x_len + 1) * y_len);
 
     for y in 0..y_len {
@@ -143,14 +160,8 @@ mod tests {
     #[test]
     fn parse_works() {
         assert_eq!(
-            parse(INPUT).1,
+            parse(INPUT).unwrap().1,
             vec![FoldInstruction::Y(7), FoldInstruction::X(5)]
         );
     }
-
-    #[test]
-    fn part_one_works() {
-        let (paper, instructions) = parse(INPUT);
-        assert_eq!(part_one(&paper, &instructions), 17);
-    }
 }
@@ -1,27 +1,58 @@
+use crate::error::ParseError;
+use crate::params::{Param, Params};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
-use itertools::MinMaxResult::MinMax;
 use std::collections::HashMap;
 
-pub fn solve(input: &str) {
-    let (start, insertions) = parse(input);
-    println!("Part 1: {}", iterate(start, &insertions, STEPS_PART_ONE));
-    println!("Part 2: {}", iterate(start, &insertions, STEPS_PART_TWO));
+pub struct Puzzle<'a> {
+    start: &'a str,
+    insertions: InsertionMap,
+    steps: (usize, usize),
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    const PARAMS: &'static [Param] = &[STEPS_PART_ONE, STEPS_PART_TWO];
+
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &'a str, params: &Params) -> Result<Self, ParseError> {
+        let (start, insertions) = parse(input)?;
+        let steps = (params.get(&STEPS_PART_ONE), params.get(&STEPS_PART_TWO));
+        Ok(Self {
+            start,
+            insertions,
+            steps,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        iterate(self.start, &self.insertions, self.steps.0).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        iterate(self.start, &self.insertions, self.steps.1).into()
+    }
 }
 
 const ARROW: &str = "->";
-const STEPS_PART_ONE: usize = 10;
-const STEPS_PART_TWO: usize = 40;
+const STEPS_PART_ONE: Param =
+    Param::new::<usize>("steps_part_one", "10", "Insertion steps of part one");
+const STEPS_PART_TWO: Param =
+    Param::new::<usize>("steps_part_two", "40", "Insertion steps of part two");
 
 type InsertionMap = HashMap<(char, char), char>;
 type PatternCount = HashMap<(char, char), u64>;
 type CharCount = HashMap<char, u64>;
 
-fn parse(input: &str) -> (&str, InsertionMap) {
+fn parse(input: &str) -> Result<(&str, InsertionMap), ParseError> {
     let origin = input
         .lines()
         .filter(|line| !line.is_empty())
         .find(|line| !line.contains(ARROW))
-        .unwrap();
+        .ok_or_else(|| ParseError::new(input, "expected a polymer template"))?;
 
     let insertions = input
         .lines()
@@ -29,35 +60,33 @@ fn parse(input: &str) -> (&str, InsertionMap) {
         .filter(|line| !line.is_empty())
         .filter(|line| line.contains(ARROW))
         .map(parse_replacement)
-        .collect();
+        .collect::<Result<_, _>>()?;
 
-    (origin, insertions)
+    Ok((origin, insertions))
 }
 
-fn parse_replacement(line: &str) -> ((char, char), char) {
-    let parts: Vec<_> = line.split(ARROW).map(str::trim).collect();
-    (
-        (
-            parts[0].chars().next().unwrap(),
-            parts[0].chars().nth(1).unwrap(),
-        ),
-        parts[1].chars().next().unwrap(),
-    )
+fn parse_replacement(line: &str) -> Result<((char, char), char), ParseError> {
+    let (pair, insertion) = parser::split_once(line, ARROW)?;
+    let pair = pair.trim();
+    let mut chars = pair.chars();
+    let (Some(a), Some(b), None) = (chars.next(), chars.next(), chars.next()) else {
+        return Err(ParseError::new(pair, "expected a pair of two characters"));
+    };
+    Ok(((a, b), parser::single_char(insertion.trim())?))
 }
 
-fn iterate(origin: &str, insertions: &InsertionMap, iterations: usize) -> u64 {
+fn iterate(origin: &str, insertions: &InsertionMap, iterations: usize) -> Option<u64> {
     let mut pattern_count = to_pattern_count(origin);
 
     for _ in 0..iterations {
         pattern_count = process(&pattern_count, insertions);
     }
 
-    if let MinMax(min, max) = count_chars(&pattern_count, origin).values().minmax() {
-        max - min
-    } else {
-        println!("Found no min-max!");
-        0
-    }
+    count_chars(&pattern_count, origin)
+        .values()
+        .minmax()
+        .into_option()
+        .map(|(min, max)| max - min)
 }
 
 fn to_pattern_count(origin: &str) -> PatternCount {
@@ -81,7 +110,9 @@ fn count_chars(pattern_count: &PatternCount, origin: &str) -> CharCount {
     for (c, n) in pattern_count.iter().map(|((a, _), n)| (a, n)) {
         *result.entry(*c).or_default() += n;
     }
-    *result.entry(origin.chars().last().unwrap()).or_default() += 1;
+    if let Some(last) = origin.chars().last() {
+        *result.entry(last).or_default() += 1;
+    }
 
     result
 }
@@ -91,9 +122,12 @@ fn process(pattern_count: &PatternCount, insertions: &InsertionMap) -> PatternCo
 
     for (pair, n) in pattern_count {
         let (a, b) = (pair.0, pair.1);
-        let c = *insertions.get(&(a, b)).unwrap();
-        *result.entry((a, c)).or_default() += n;
-        *result.entry((c, b)).or_default() += n;
+        if let Some(&c) = insertions.get(&(a, b)) {
+            *result.entry((a, c)).or_default() += n;
+            *result.entry((c, b)).or_default() += n;
+        } else {
+            *result.entry((a, b)).or_default() += n;
+        }
     }
 
     result
@@ -144,15 +178,15 @@ mod tests {
     #[test]
 
     fn parse_works() {
-        let (start, insertions) = parse(INPUT);
+        let (start, insertions) = parse(INPUT).unwrap();
         assert_eq!(start, "NNCB");
         assert_eq!(insertions, HashMap::from(INSERTIONS));
     }
 
     #[test]
     fn iterate_works() {
-        let (start, insertions) = parse(INPUT);
-        assert_eq!(iterate(start, &insertions, 10), 1588);
-        assert_eq!(iterate(start, &insertions, 40), 2_188_189_693_529);
+        let (start, insertions) = parse(INPUT).unwrap();
+        assert_eq!(iterate(start, &insertions, 10), Some(1588));
+        assert_eq!(iterate(start, &insertions, 40), Some(2_188_189_693_529));
     }
 }
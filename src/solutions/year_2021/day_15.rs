@@ -1,9 +1,26 @@
+use crate::error::ParseError;
 use crate::graph::{Graph, WeightedGraph};
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let matrix = parse(input);
-    println!("Part 1: {}", part_one(&matrix));
-    println!("Part 2: {}", part_two(&matrix));
+pub struct Puzzle {
+    matrix: Vec<Vec<RiskLevel>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            matrix: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.matrix).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.matrix).into()
+    }
 }
 
 type Point = (usize, usize);
@@ -11,35 +28,53 @@ type RiskLevel = usize;
 
 const EXTEND_REPEAT: usize = 5;
 
-fn parse(input: &str) -> Vec<Vec<RiskLevel>> {
-    input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(str::trim)
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_string().parse().unwrap())
-                .collect()
-        })
-        .collect()
+fn parse(input: &str) -> Result<Vec<Vec<RiskLevel>>, ParseError> {
+    let mut result: Vec<Vec<RiskLevel>> = Vec::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        parser::check_chars(line, "0123456789")?;
+        if result
+            .first()
+            .is_some_and(|first| first.len() != line.len())
+        {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
+        result.push(line.bytes().map(|c| RiskLevel::from(c - b'0')).collect());
+    }
+    if result.is_empty() {
+        return Err(ParseError::new(input, "expected at least one row"));
+    }
+    Ok(result)
 }
 
-fn part_one(matrix: &[Vec<RiskLevel>]) -> RiskLevel {
+fn part_one(matrix: &[Vec<RiskLevel>]) -> Option<RiskLevel> {
     let graph = create_graph(matrix);
     let aim = get_bottom_right(&graph);
-    graph.shortest_path((0, 0), aim).unwrap()
+    graph.shortest_path((0, 0), aim)
 }
 
-fn part_two(matrix: &[Vec<RiskLevel>]) -> RiskLevel {
+fn part_two(matrix: &[Vec<RiskLevel>]) -> Option<RiskLevel> {
     let matrix_extended = extend(matrix);
     let graph = create_graph(&matrix_extended);
     let aim = get_bottom_right(&graph);
-    graph.shortest_path((0, 0), aim).unwrap()
+    graph.shortest_path((0, 0), aim)
 }
 
 fn get_bottom_right(graph: &WeightedGraph<Point, RiskLevel>) -> Point {
-    let max_x = graph.all_nodes().iter().map(|a| a.0).max().unwrap();
-    let max_y = graph.all_nodes().iter().map(|a| a.1).max().unwrap();
+    let max_x = graph
+        .all_nodes()
+        .iter()
+        .map(|a| a.0)
+        .max()
+        .unwrap_or_default();
+    let max_y = graph
+        .all_nodes()
+        .iter()
+        .map(|a| a.1)
+        .max()
+        .unwrap_or_default();
     (max_x, max_y)
 }
 
@@ -96,18 +131,6 @@ fn create_graph(two_dim: &[Vec<RiskLevel>]) -> WeightedGraph<Point, RiskLevel> {
 mod tests {
     use super::*;
 
-    const INPUT: &str = "
-    1163751742
-    1381373672
-    2136511328
-    3694931569
-    7463417111
-    1319128137
-    1359912421
-    3125421639
-    1293138521
-    2311944581";
-
     #[test]
     fn extend_works() {
         let input = vec![vec![8]];
@@ -120,14 +143,4 @@ mod tests {
         ];
         assert_eq!(extend(&input), output);
     }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(part_one(&parse(INPUT)), 40);
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(part_two(&parse(INPUT)), 315);
-    }
 }
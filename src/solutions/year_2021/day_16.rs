@@ -1,15 +1,33 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::cmp::max;
 use std::cmp::min;
 
-pub fn solve(input: &str) {
-    let packet = parse(input);
-    println!("Part 1: {}", part_one(&packet));
-    println!("Part 2: {}", part_two(&packet));
+pub struct Puzzle {
+    packet: Packet,
 }
 
-fn parse(input: &str) -> Packet {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            packet: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.packet).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.packet).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Packet, ParseError> {
+    parser::check_chars(input, "0123456789ABCDEFabcdef")?;
     let mut bin_iter = BinaryIter::new(input);
-    try_parse(&mut bin_iter).unwrap()
+    try_parse(&mut bin_iter).ok_or_else(|| ParseError::new(input, "expected a packet"))
 }
 
 fn part_one(packet: &Packet) -> u64 {
@@ -21,7 +39,7 @@ fn part_two(packet: &Packet) -> u64 {
 }
 
 fn decode_hex_to_nibble(hex: char) -> String {
-    format!("{:04b}", hex.to_digit(16).unwrap())
+    format!("{:04b}", hex.to_digit(16).expect("checked by parse"))
 }
 
 fn decode_binary_to_hex(bits: &[char]) -> String {
@@ -35,11 +53,9 @@ fn decode_nibbles_to_hex(bits: &[char]) -> String {
 }
 
 fn bin_to_num(input: &str) -> u64 {
-    if input.is_empty() {
-        0
-    } else {
-        u64::from_str_radix(input, 2).unwrap()
-    }
+    input
+        .chars()
+        .fold(0, |acc, bit| acc << 1 | u64::from(bit == '1'))
 }
 
 const VERSION_LENGTH: usize = 3;
@@ -188,8 +204,12 @@ impl LiteralPacket {
         let mut digits = String::new();
 
         while repeat {
+            let chunk = binary.chunks(5);
+            if chunk.is_empty() {
+                break;
+            }
             let mut first = true;
-            for digit in binary.chunks(5) {
+            for digit in chunk {
                 if first {
                     if digit == '0' {
                         repeat = false;
@@ -227,8 +247,7 @@ impl OperatorPacket {
         let subpackets = match binary.next() {
             Some('0') => OperatorPacket::subpackets_by_length(binary),
             Some('1') => OperatorPacket::subpackets_by_number(binary),
-            Some(x) => panic!("Unexpected digit: {x}"),
-            None => Vec::new(),
+            _ => Vec::new(),
         };
 
         Self {
@@ -247,7 +266,7 @@ impl OperatorPacket {
             5 => |a, b| u64::from(a > b),
             6 => |a, b| u64::from(a < b),
             7 => |a, b| u64::from(a == b),
-            x => panic!("Unexpected id: {x}"),
+            _ => unreachable!("id 4 is a literal packet"),
         };
 
         self.subpackets
@@ -265,7 +284,7 @@ mod tests {
     #[test]
     fn parse_with_literal_works() {
         assert_eq!(
-            parse("D2FE28"),
+            parse("D2FE28").unwrap(),
             Packet::Literal(LiteralPacket {
                 version: 6,
                 id: 4,
@@ -277,7 +296,7 @@ mod tests {
     #[test]
     fn parse_with_operator_works() {
         assert_eq!(
-            parse("38006F45291200"),
+            parse("38006F45291200").unwrap(),
             Packet::Operator(OperatorPacket {
                 version: 1,
                 id: 6,
@@ -318,21 +337,27 @@ mod tests {
 
     #[test]
     fn part_one_works() {
-        assert_eq!(part_one(&parse("8A004A801A8002F478")), 16);
-        assert_eq!(part_one(&parse("620080001611562C8802118E34")), 12);
-        assert_eq!(part_one(&parse("C0015000016115A2E0802F182340")), 23);
-        assert_eq!(part_one(&parse("A0016C880162017C3686B18A3D4780")), 31);
+        assert_eq!(part_one(&parse("8A004A801A8002F478").unwrap()), 16);
+        assert_eq!(part_one(&parse("620080001611562C8802118E34").unwrap()), 12);
+        assert_eq!(
+            part_one(&parse("C0015000016115A2E0802F182340").unwrap()),
+            23
+        );
+        assert_eq!(
+            part_one(&parse("A0016C880162017C3686B18A3D4780").unwrap()),
+            31
+        );
     }
 
     #[test]
     fn part_two_works() {
-        assert_eq!(part_two(&parse("C200B40A82")), 3);
-        assert_eq!(part_two(&parse("04005AC33890")), 54);
-        assert_eq!(part_two(&parse("880086C3E88112")), 7);
-        assert_eq!(part_two(&parse("CE00C43D881120")), 9);
-        assert_eq!(part_two(&parse("D8005AC2A8F0")), 1);
-        assert_eq!(part_two(&parse("F600BC2D8F")), 0);
-        assert_eq!(part_two(&parse("9C005AC2F8F0")), 0);
-        assert_eq!(part_two(&parse("9C0141080250320F1802104A08")), 1);
+        assert_eq!(part_two(&parse("C200B40A82").unwrap()), 3);
+        assert_eq!(part_two(&parse("04005AC33890").unwrap()), 54);
+        assert_eq!(part_two(&parse("880086C3E88112").unwrap()), 7);
+        assert_eq!(part_two(&parse("CE00C43D881120").unwrap()), 9);
+        assert_eq!(part_two(&parse("D8005AC2A8F0").unwrap()), 1);
+        assert_eq!(part_two(&parse("F600BC2D8F").unwrap()), 0);
+        assert_eq!(part_two(&parse("9C005AC2F8F0").unwrap()), 0);
+        assert_eq!(part_two(&parse("9C0141080250320F1802104A08").unwrap()), 1);
     }
 }
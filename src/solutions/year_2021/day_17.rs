@@ -1,24 +1,43 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 use std::cmp::max;
 use std::cmp::Ordering;
 
-pub fn solve(input: &str) {
-    let target = parse(input);
-    let (highest_y, hitting_vectors) = find_best(&target);
-    println!("Part 1: {highest_y}");
-    println!("Part 2: {hitting_vectors}");
+pub struct Puzzle {
+    highest_y: i32,
+    hitting_vectors: usize,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let target = parse(input)?;
+        let (highest_y, hitting_vectors) = find_best(&target);
+        Ok(Self {
+            highest_y,
+            hitting_vectors,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        self.highest_y.into()
+    }
+
+    fn part_two(&self) -> Answer {
+        self.hitting_vectors.into()
+    }
 }
 
 const TARGET_AREA_KEY: &str = "target area: ";
 const GUESSED_Y_FACTOR_RANGE: i32 = 100;
 
-fn parse(input: &str) -> TargetArea {
+fn parse(input: &str) -> Result<TargetArea, ParseError> {
     input
         .lines()
-        .filter(|line| line.trim().starts_with(TARGET_AREA_KEY))
+        .find(|line| line.trim().starts_with(TARGET_AREA_KEY))
         .map(parse_target)
-        .next()
-        .unwrap()
+        .ok_or_else(|| ParseError::new(input, format!("expected `{TARGET_AREA_KEY}`")))?
 }
 
 fn find_best(target: &TargetArea) -> (i32, usize) {
@@ -40,20 +59,17 @@ fn find_best(target: &TargetArea) -> (i32, usize) {
     (y_best, count)
 }
 
-fn parse_target(line: &str) -> TargetArea {
-    let regex = Regex::new(r"x=([-]?\d+)..([-]?\d+), y=([-]?\d+)..([-]?\d+)").unwrap();
-    let captures = regex.captures(line).unwrap();
-    let x_min = captures.get(1).unwrap().as_str().parse().unwrap();
-    let x_max = captures.get(2).unwrap().as_str().parse().unwrap();
-    let y_min = captures.get(3).unwrap().as_str().parse().unwrap();
-    let y_max = captures.get(4).unwrap().as_str().parse().unwrap();
-
-    TargetArea {
-        x_min,
-        x_max,
-        y_min,
-        y_max,
-    }
+fn parse_target(line: &str) -> Result<TargetArea, ParseError> {
+    let regex = Regex::new(r"x=([-]?\d+)\.\.([-]?\d+), y=([-]?\d+)\.\.([-]?\d+)").unwrap();
+    let captures = parser::captures(&regex, line, "`x=<min>..<max>, y=<min>..<max>`")?;
+    let number = |i| parser::number(captures.get(i).unwrap().as_str());
+
+    Ok(TargetArea {
+        x_min: number(1)?,
+        x_max: number(2)?,
+        y_min: number(3)?,
+        y_max: number(4)?,
+    })
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -123,7 +139,7 @@ mod tests {
     #[test]
     fn parse_works() {
         assert_eq!(
-            parse("target area: x=20..30, y=-10..-5"),
+            parse("target area: x=20..30, y=-10..-5").unwrap(),
             TargetArea {
                 x_min: 20,
                 x_max: 30,
@@ -1,21 +1,34 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use std::cmp::max;
 
-pub fn solve(input: &str) {
-    let player_pos = parse(input);
-    println!(
-        "Part 1: {}",
+pub struct Puzzle {
+    player_pos: (Position, Position),
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            player_pos: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
         part_one(
-            Player::create_at(player_pos.0),
-            Player::create_at(player_pos.1)
+            Player::create_at(self.player_pos.0),
+            Player::create_at(self.player_pos.1),
         )
-    );
-    println!(
-        "Part 2: {}",
+        .into()
+    }
+
+    fn part_two(&self) -> Answer {
         part_two(
-            Player::create_at(player_pos.0),
-            Player::create_at(player_pos.1)
+            Player::create_at(self.player_pos.0),
+            Player::create_at(self.player_pos.1),
         )
-    );
+        .into()
+    }
 }
 
 type Position = u64;
@@ -24,23 +37,29 @@ const WIN_SCORE_PART_2: u64 = 21;
 const DICE_MAX: u64 = 100;
 const BOARD_MAX: Position = 10;
 
-fn parse(input: &str) -> (Position, Position) {
+fn parse(input: &str) -> Result<(Position, Position), ParseError> {
     let players: Vec<Position> = input
         .lines()
         .filter(|line| line.contains("starting position"))
         .map(str::trim)
         .map(parse_line)
-        .collect();
-    (players[0], players[1])
+        .collect::<Result<_, _>>()?;
+    match players[..] {
+        [player_1, player_2] => Ok((player_1, player_2)),
+        _ => Err(ParseError::new(input, "expected two starting positions")),
+    }
 }
 
-fn parse_line(input: &str) -> Position {
-    input
-        .split_ascii_whitespace()
-        .last()
-        .unwrap()
-        .parse()
-        .unwrap()
+fn parse_line(input: &str) -> Result<Position, ParseError> {
+    let (_, position) = input.rsplit_once(' ').unwrap_or(("", input));
+    let position = parser::number(position)?;
+    if !(1..=BOARD_MAX).contains(&position) {
+        return Err(ParseError::new(
+            input,
+            format!("expected a position from 1 to {BOARD_MAX}"),
+        ));
+    }
+    Ok(position)
 }
 
 fn part_one(player_1: Player, player_2: Player) -> u64 {
@@ -155,25 +174,3 @@ impl Player {
         clone
     }
 }
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(
-            part_one(Player::create_at(4), Player::create_at(8)),
-            739_785
-        );
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(
-            part_two(Player::create_at(4), Player::create_at(8)),
-            444_356_092_776_315
-        );
-    }
-}
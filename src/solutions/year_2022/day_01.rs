@@ -1,20 +1,33 @@
 use itertools::Itertools;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let calories = parse(input).iter().map(|x| x.iter().sum()).collect_vec();
-    println!("Part 1: {}", part_one(&calories));
-    println!("Part 2: {}", part_two(&calories));
+pub struct Puzzle {
+    calories: Vec<Calories>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            calories: parse(input)?.iter().map(|x| x.iter().sum()).collect_vec(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.calories).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.calories).into()
+    }
 }
 
 type Calories = u32;
 
-fn parse(input: &str) -> Vec<Vec<Calories>> {
-    input
-        .split("\n\n")
-        .map(parser::lines_as_numbers)
-        .collect_vec()
+fn parse(input: &str) -> Result<Vec<Vec<Calories>>, ParseError> {
+    input.split("\n\n").map(parser::lines_as_numbers).collect()
 }
 
 fn part_one(input: &[Calories]) -> Calories {
@@ -58,18 +71,6 @@ mod tests {
             vec![10000],
         ];
 
-        assert_eq!(parse(input), output);
-    }
-
-    #[test]
-    fn part_one_works() {
-        let input = vec![6000, 4000, 11000, 24000, 10000];
-        assert_eq!(part_one(&input), 24000);
-    }
-
-    #[test]
-    fn part_two_works() {
-        let input = vec![6000, 4000, 11000, 24000, 10000];
-        assert_eq!(part_two(&input), 45000);
+        assert_eq!(parse(input).unwrap(), output);
     }
 }
@@ -1,32 +1,51 @@
-use itertools::Itertools;
-
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let matches = parse(input);
-    println!("Part 1: {}", part_one(&matches));
-    println!("Part 2: {}", part_two(&matches));
+pub struct Puzzle {
+    matches: Vec<(OpponentSymbol, PlayerSymbol)>,
 }
 
-fn parse(input: &str) -> Vec<(OpponentSymbol, PlayerSymbol)> {
-    parser::lines_custom(input, |line| {
-        let symbols = line.trim().split(' ').map(parse_symbol).collect_vec();
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            matches: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.matches).into()
+    }
 
-        assert!(symbols.len() == 2);
+    fn part_two(&self) -> Answer {
+        part_two(&self.matches).into()
+    }
+}
 
-        (symbols[0].get_opponent(), symbols[1].get_player())
+fn parse(input: &str) -> Result<Vec<(OpponentSymbol, PlayerSymbol)>, ParseError> {
+    parser::lines_custom(input, |line| {
+        let (opponent, player) = parser::split_once(line, " ")?;
+        Ok((parse_opponent(opponent)?, parse_player(player)?))
     })
+    .into_iter()
+    .collect()
 }
 
-fn parse_symbol(input: &str) -> Symbol {
+fn parse_opponent(input: &str) -> Result<OpponentSymbol, ParseError> {
     match input {
-        "A" => Symbol::Opponent(OpponentSymbol::A),
-        "B" => Symbol::Opponent(OpponentSymbol::B),
-        "C" => Symbol::Opponent(OpponentSymbol::C),
-        "X" => Symbol::Player(PlayerSymbol::X),
-        "Y" => Symbol::Player(PlayerSymbol::Y),
-        "Z" => Symbol::Player(PlayerSymbol::Z),
-        _ => panic!("Unexpected: {input}"),
+        "A" => Ok(OpponentSymbol::A),
+        "B" => Ok(OpponentSymbol::B),
+        "C" => Ok(OpponentSymbol::C),
+        _ => Err(ParseError::new(input, "expected `A`, `B` or `C`")),
+    }
+}
+
+fn parse_player(input: &str) -> Result<PlayerSymbol, ParseError> {
+    match input {
+        "X" => Ok(PlayerSymbol::X),
+        "Y" => Ok(PlayerSymbol::Y),
+        "Z" => Ok(PlayerSymbol::Z),
+        _ => Err(ParseError::new(input, "expected `X`, `Y` or `Z`")),
     }
 }
 
@@ -51,28 +70,6 @@ where
         .sum()
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-enum Symbol {
-    Opponent(OpponentSymbol),
-    Player(PlayerSymbol),
-}
-
-impl Symbol {
-    fn get_opponent(self) -> OpponentSymbol {
-        match self {
-            Self::Opponent(x) => x,
-            Self::Player(_) => panic!("Not opponend"),
-        }
-    }
-
-    fn get_player(self) -> PlayerSymbol {
-        match self {
-            Self::Player(x) => x,
-            Self::Opponent(_) => panic!("Not opponend"),
-        }
-    }
-}
-
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 enum OpponentSymbol {
     A,
@@ -187,27 +184,7 @@ mod tests {
                 (OpponentSymbol::B, PlayerSymbol::X),
                 (OpponentSymbol::C, PlayerSymbol::Z)
             ],
-            parse(input)
+            parse(input).unwrap()
         );
     }
-
-    #[test]
-    fn part_one_works() {
-        let matches = vec![
-            (OpponentSymbol::A, PlayerSymbol::Y),
-            (OpponentSymbol::B, PlayerSymbol::X),
-            (OpponentSymbol::C, PlayerSymbol::Z),
-        ];
-        assert_eq!(15, part_one(&matches));
-    }
-
-    #[test]
-    fn part_two_works() {
-        let matches = vec![
-            (OpponentSymbol::A, PlayerSymbol::Y),
-            (OpponentSymbol::B, PlayerSymbol::X),
-            (OpponentSymbol::C, PlayerSymbol::Z),
-        ];
-        assert_eq!(12, part_two(&matches));
-    }
 }
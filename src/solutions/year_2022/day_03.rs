@@ -1,43 +1,72 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
+pub struct Puzzle<'a> {
+    rucksacks: Vec<&'a str>,
+}
+
+impl<'a> Solution<'a> for Puzzle<'a> {
+    fn parse(input: &'a str) -> Result<Self, ParseError> {
+        Ok(Self {
+            rucksacks: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.rucksacks).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.rucksacks).into()
+    }
+}
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+fn parse(input: &str) -> Result<Vec<&str>, ParseError> {
     let rucksacks = parser::lines_as_strings(input);
-    println!("Part 1: {}", part_one(&rucksacks));
-    println!("Part 2: {}", part_two(&rucksacks));
+    for rucksack in &rucksacks {
+        parser::check_chars(rucksack, ITEMS)?;
+        if !rucksack.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                rucksack,
+                "expected two compartments of the same size",
+            ));
+        }
+    }
+    Ok(rucksacks)
 }
 
-fn part_one(rucksacks: &[&str]) -> u32 {
+fn part_one(rucksacks: &[&str]) -> Option<u32> {
     rucksacks
         .iter()
         .map(|x| x.split_at(x.len() / 2))
-        .map(find_common_element_in)
-        .map(priority)
+        .map(|compartments| find_common_element_in(compartments).map(priority))
         .sum()
 }
 
-fn part_two(rucksacks: &[&str]) -> u32 {
+fn part_two(rucksacks: &[&str]) -> Option<u32> {
     rucksacks
         .chunks(3)
-        .collect::<Vec<_>>()
-        .into_iter()
-        .map(find_common_element_in2)
-        .map(priority)
+        .map(|group| find_common_element_in2(group).map(priority))
         .sum()
 }
 
-fn find_common_element_in(compartments: (&str, &str)) -> char {
+fn find_common_element_in(compartments: (&str, &str)) -> Option<char> {
     compartments
         .0
         .chars()
         .find(|elem| compartments.1.contains(*elem))
-        .unwrap()
 }
 
-fn find_common_element_in2(compartments: &[&str]) -> char {
-    compartments[0]
+fn find_common_element_in2(compartments: &[&str]) -> Option<char> {
+    let [first, second, third] = compartments else {
+        return None;
+    };
+    first
         .chars()
-        .find(|elem| compartments[1].contains(*elem) && compartments[2].contains(*elem))
-        .unwrap()
+        .find(|elem| second.contains(*elem) && third.contains(*elem))
 }
 
 fn priority(c: char) -> u32 {
@@ -52,15 +81,6 @@ fn priority(c: char) -> u32 {
 mod tests {
     use super::*;
 
-    const EXAMPLE_RUCKSACK: [&str; 6] = [
-        "vJrwpWtwJgWrhcsFMMfFFhFp",
-        "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
-        "PmmdzqPrVvPwwTWBwg",
-        "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
-        "ttgJtRGJQctTZtZT",
-        "CrZsJsPPZsGzwwsLwLmpwMDw",
-    ];
-
     #[test]
     fn priority_works() {
         assert_eq!(1, priority('a'));
@@ -68,14 +88,4 @@ mod tests {
         assert_eq!(27, priority('A'));
         assert_eq!(52, priority('Z'));
     }
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(157, part_one(&EXAMPLE_RUCKSACK));
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(70, part_two(&EXAMPLE_RUCKSACK));
-    }
 }
@@ -1,21 +1,45 @@
-use itertools::Itertools;
-
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let forest = parse(input);
-    println!("Part 1: {}", part_one(&forest));
-    println!("Part 2: {}", part_two(&forest));
+pub struct Puzzle {
+    forest: Vec<Vec<TreeSize>>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            forest: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.forest).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.forest).into()
+    }
 }
 
 type TreeSize = u32;
 
-fn parse(input: &str) -> Vec<Vec<TreeSize>> {
-    parser::lines_custom(input, |line| {
-        line.chars()
-            .map(|x| x.to_string().parse().unwrap())
-            .collect_vec()
-    })
+fn parse(input: &str) -> Result<Vec<Vec<TreeSize>>, ParseError> {
+    let mut forest: Vec<Vec<TreeSize>> = Vec::new();
+    for line in parser::lines_as_strings(input) {
+        parser::check_chars(line, "0123456789")?;
+        if forest
+            .first()
+            .is_some_and(|first| first.len() != line.len())
+        {
+            return Err(ParseError::new(
+                line,
+                "expected all rows to have the same length",
+            ));
+        }
+        forest.push(line.bytes().map(|x| TreeSize::from(x - b'0')).collect());
+    }
+    Ok(forest)
 }
 
 fn part_one(forest: &[Vec<TreeSize>]) -> u32 {
@@ -148,24 +172,3 @@ where
 
     score
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE: &str = "30373
-25512
-65332
-33549
-35390";
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(21, part_one(&parse(EXAMPLE)));
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(8, part_two(&parse(EXAMPLE)));
-    }
-}
@@ -1,6 +1,27 @@
-pub fn solve(input: &str) {
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    const INPUT_MODE: InputMode = InputMode::Ascii;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.input).into()
+    }
 }
 
 const DIGITS: [(&str, u32); 9] = [
@@ -15,24 +36,18 @@ const DIGITS: [(&str, u32); 9] = [
     ("nine", 9),
 ];
 
-fn part_one(input: &str) -> u32 {
+fn part_one(input: &str) -> Option<u32> {
     calculate_sum(&parse(input))
 }
 
-fn part_two(input: &str) -> u32 {
+fn part_two(input: &str) -> Option<u32> {
     calculate_sum(&parse(&pre_parse(input)))
 }
 
-fn calculate_sum(numbers: &[Vec<u32>]) -> u32 {
+fn calculate_sum(numbers: &[Vec<u32>]) -> Option<u32> {
     numbers
         .iter()
-        .map(|x| {
-            String::from_iter([
-                x.first().unwrap().to_string(),
-                x.last().unwrap().to_string(),
-            ])
-        })
-        .map(|x| x.parse::<u32>().unwrap())
+        .map(|x| Some(x.first()? * 10 + x.last()?))
         .sum()
 }
 
@@ -99,14 +114,6 @@ pqr3stu8vwx
 a1b2c3d4e5f
 treb7uchet";
 
-    const EXAMPLE_B: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
-
     #[test]
     fn test_parse() {
         assert_eq!(
@@ -115,16 +122,6 @@ zoneight234
         );
     }
 
-    #[test]
-    fn test_part_one() {
-        assert_eq!(142, part_one(EXAMPLE_A));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(281, part_two(EXAMPLE_B));
-    }
-
     #[test]
     fn test_replace_nums() {
         assert_eq!("1one\n", pre_parse_line("one"));
@@ -1,17 +1,33 @@
-use itertools::Itertools;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 
-pub fn solve(input: &str) {
-    let games = parse(input);
-    println!("Part 1: {}", part_one(&games));
-    println!("Part 2: {}", part_two(&games));
+pub struct Puzzle {
+    games: Vec<Game>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            games: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.games).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.games).into()
+    }
 }
 
 const MAX_R: u32 = 12;
 const MAX_G: u32 = 13;
 const MAX_B: u32 = 14;
 
-fn parse(input: &str) -> Vec<Game> {
+fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
     input.lines().map(parse_line).collect()
 }
 
@@ -23,25 +39,31 @@ fn part_two(games: &[Game]) -> u32 {
     games.iter().map(minimal_cubes).sum()
 }
 
-fn parse_line(input: &str) -> Game {
-    let a = input.split(':').collect_vec();
-    let id = a[0][4..].trim().parse().unwrap();
-    let subsets = a[1].split(';').map(parse_subset).collect();
-    Game::new(id, subsets)
+fn parse_line(input: &str) -> Result<Game, ParseError> {
+    let (game, subsets) = parser::split_once(input, ":")?;
+    let id = game
+        .strip_prefix("Game ")
+        .ok_or_else(|| ParseError::new(game, "expected `Game <id>`"))?;
+    let id = parser::number(id.trim())?;
+    let subsets = subsets
+        .split(';')
+        .map(parse_subset)
+        .collect::<Result<_, _>>()?;
+    Ok(Game::new(id, subsets))
 }
 
-fn parse_subset(input: &str) -> Subset {
-    Subset::new()
-        .r(try_find_capture(r"(\d+) red", input))
-        .g(try_find_capture(r"(\d+) green", input))
-        .b(try_find_capture(r"(\d+) blue", input))
+fn parse_subset(input: &str) -> Result<Subset, ParseError> {
+    Ok(Subset::new()
+        .r(try_find_capture(r"(\d+) red", input)?)
+        .g(try_find_capture(r"(\d+) green", input)?)
+        .b(try_find_capture(r"(\d+) blue", input)?))
 }
 
-fn try_find_capture(regex: &str, input: &str) -> u32 {
+fn try_find_capture(regex: &str, input: &str) -> Result<u32, ParseError> {
     if let Some(captures) = Regex::new(regex).unwrap().captures(input) {
-        captures.get(1).unwrap().as_str().parse().unwrap()
+        parser::number(captures.get(1).unwrap().as_str())
     } else {
-        0
+        Ok(0)
     }
 }
 
@@ -160,17 +182,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
                     vec![Subset::new().r(6).b(1).g(3), Subset::new().b(2).r(1).g(2),]
                 )
             ],
-            parse(EXAMPLE_INPUT),
+            parse(EXAMPLE_INPUT).unwrap(),
         );
     }
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(8, part_one(&parse(EXAMPLE_INPUT)));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(2286, part_two(&parse(EXAMPLE_INPUT)));
-    }
 }
@@ -1,32 +1,47 @@
-pub fn solve(input: &str) {
-    let cards = parse(input);
-    println!("Part 1: {}", part_one(&cards));
-    println!("Part 2: {}", part_two(&cards));
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    cards: Vec<Card>,
 }
 
-fn parse(input: &str) -> Vec<Card> {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            cards: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.cards).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.cards).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
     input.lines().map(parse_line).collect()
 }
 
-fn parse_line(line: &str) -> Card {
-    let parts_colon: Vec<&str> = line.split(':').collect();
-    let parts_pipe: Vec<&str> = parts_colon[1].split('|').collect();
+fn parse_line(line: &str) -> Result<Card, ParseError> {
+    let (_, numbers) = parser::split_once(line, ":")?;
+    let (winning_numbers, own_numbers) = parser::split_once(numbers, "|")?;
 
-    let winning_numbers = parts_pipe[0]
-        .split(' ')
-        .filter(|x| !x.is_empty())
-        .map(|x| x.parse().unwrap())
-        .collect();
-    let own_numbers = parts_pipe[1]
+    Ok(Card {
+        winning_numbers: parse_numbers(winning_numbers)?,
+        own_numbers: parse_numbers(own_numbers)?,
+    })
+}
+
+fn parse_numbers(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
         .split(' ')
         .filter(|x| !x.is_empty())
-        .map(|x| x.parse().unwrap())
-        .collect();
-
-    Card {
-        winning_numbers,
-        own_numbers,
-    }
+        .map(parser::number)
+        .collect()
 }
 
 fn part_one(cards: &[Card]) -> u32 {
@@ -67,25 +82,3 @@ impl Card {
             .count()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(13, part_one(&parse(EXAMPLE_INPUT)));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(30, part_two(&parse(EXAMPLE_INPUT)));
-    }
-}
@@ -1,43 +1,64 @@
+use crate::cancel;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
+use log::debug;
 
-pub fn solve(input: &str) {
-    let (seeds, blocks) = parse(input);
-    println!("Part 1: {}", part_one(&seeds, &blocks));
-    println!("Part 2: {}", part_two(&seeds, &blocks));
+pub struct Puzzle {
+    seeds: Vec<isize>,
+    blocks: Vec<Block>,
 }
 
-fn parse(input: &str) -> (Vec<isize>, Vec<Block>) {
-    let blocks = input.split("\n\n").collect_vec();
-    let seeds = blocks[0]
-        .split(' ')
-        .skip(1)
-        .map(|x| x.parse().unwrap())
-        .collect_vec();
-
-    (
-        seeds,
-        blocks.iter().skip(1).map(|x| parse_block(x)).collect_vec(),
-    )
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (seeds, blocks) = parse(input)?;
+        Ok(Self { seeds, blocks })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.seeds, &self.blocks).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.seeds, &self.blocks).into()
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<isize>, Vec<Block>), ParseError> {
+    let mut blocks = input.split("\n\n");
+    let seeds = blocks.next().unwrap_or_default();
+    let seeds = seeds
+        .strip_prefix("seeds:")
+        .ok_or_else(|| ParseError::new(seeds, "expected `seeds:`"))?
+        .split_ascii_whitespace()
+        .map(parser::number)
+        .collect::<Result<_, _>>()?;
+
+    Ok((seeds, blocks.map(parse_block).collect::<Result<_, _>>()?))
 }
 
-fn parse_block(block: &str) -> Block {
-    Block {
+fn parse_block(block: &str) -> Result<Block, ParseError> {
+    Ok(Block {
         lines: block
             .lines()
             .skip(1)
             .map(|x| {
                 let numbers = x
                     .split_ascii_whitespace()
-                    .map(|x| x.parse().unwrap())
-                    .collect_vec();
-                InstLine {
-                    dest_range_start: numbers[0],
-                    src_range_start: numbers[1],
-                    range_length: numbers[2],
-                }
+                    .map(parser::number)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let [dest_range_start, src_range_start, range_length] = numbers[..] else {
+                    return Err(ParseError::new(x, "expected 3 numbers"));
+                };
+                Ok(InstLine {
+                    dest_range_start,
+                    src_range_start,
+                    range_length,
+                })
             })
-            .collect_vec(),
-    }
+            .collect::<Result<_, _>>()?,
+    })
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -64,16 +85,15 @@ fn part_one(seeds: &[isize], blocks: &[Block]) -> isize {
 fn part_two(seeds: &[isize], blocks: &[Block]) -> isize {
     seeds
         .iter()
-        .chunks(2)
-        .into_iter()
-        .map(|chunk| {
-            let c = chunk.collect_vec();
-            let start = *c[0];
-            let length = *c[1];
-            println!(". {length}");
+        .tuples()
+        .map(|(&start, &length)| {
+            debug!("Seed range of length {length}");
 
             (start..start + length)
-                .map(|seed| convert_seed(blocks, seed))
+                .map(|seed| {
+                    cancel::checkpoint();
+                    convert_seed(blocks, seed)
+                })
                 .min()
                 .unwrap_or(isize::MAX)
         })
@@ -135,18 +155,9 @@ humidity-to-location map:
 
     #[test]
     fn test_parse() {
-        println!("{:?}", parse(EXAMPLE_INPUT));
-    }
-
-    #[test]
-    fn test_part_one() {
-        let (seeds, blocks) = parse(EXAMPLE_INPUT);
-        assert_eq!(35, part_one(&seeds, &blocks));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let (seeds, blocks) = parse(EXAMPLE_INPUT);
-        assert_eq!(46, part_two(&seeds, &blocks));
+        let (seeds, blocks) = parse(EXAMPLE_INPUT).unwrap();
+        assert_eq!(vec![79, 14, 55, 13], seeds);
+        assert_eq!(7, blocks.len());
+        assert!(parse("seeds: 79 x").is_err());
     }
 }
@@ -1,10 +1,27 @@
-use itertools::Itertools;
-
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
+
+pub struct Puzzle {
+    races: Vec<Race>,
+    race: Race,
+}
 
-pub fn solve(input: &str) {
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            races: parse(input)?,
+            race: parse_joined(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        multiply_races(&self.races).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        calc_race(&self.race).into()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -13,31 +30,55 @@ struct Race {
     record_distance: usize,
 }
 
-fn parse(input: &str) -> Vec<Race> {
-    let lines = parser::lines_custom(input, |line| {
-        line.split_ascii_whitespace()
-            .flat_map(str::parse)
-            .collect_vec()
-    });
-
-    (0..lines[0].len())
-        .map(|i| Race {
-            duration: lines[0][i],
-            record_distance: lines[1][i],
+fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+    let (durations, distances) = parse_lines(input)?;
+    durations
+        .into_iter()
+        .zip(distances)
+        .map(|(duration, distance)| {
+            Ok(Race {
+                duration: parser::number(duration)?,
+                record_distance: parser::number(distance)?,
+            })
         })
         .collect()
 }
 
-fn pre_parse(input: &str) -> String {
-    input.replace(' ', "").replace(':', " ")
+/// Parses the races as a single one, ignoring the spaces between the numbers
+fn parse_joined(input: &str) -> Result<Race, ParseError> {
+    let (durations, distances) = parse_lines(input)?;
+    Ok(Race {
+        duration: parser::number(&durations.concat())?,
+        record_distance: parser::number(&distances.concat())?,
+    })
 }
 
-fn part_one(input: &str) -> usize {
-    multiply_races(&parse(input))
+/// Returns the words of the `Time:` and the `Distance:` line
+fn parse_lines(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let lines = parser::lines_as_strings(input);
+    let [durations, distances] = lines[..] else {
+        return Err(ParseError::new(
+            input,
+            "expected a `Time:` and a `Distance:` line",
+        ));
+    };
+    let durations = words_after(durations, "Time:")?;
+    let distances = words_after(distances, "Distance:")?;
+    if durations.len() != distances.len() {
+        return Err(ParseError::new(
+            lines[1],
+            "expected as many distances as times",
+        ));
+    }
+    Ok((durations, distances))
 }
 
-fn part_two(input: &str) -> usize {
-    multiply_races(&parse(&pre_parse(input)))
+fn words_after<'a>(line: &'a str, label: &str) -> Result<Vec<&'a str>, ParseError> {
+    Ok(line
+        .strip_prefix(label)
+        .ok_or_else(|| ParseError::new(line, format!("expected `{label}`")))?
+        .split_ascii_whitespace()
+        .collect())
 }
 
 fn multiply_races(races: &[Race]) -> usize {
@@ -59,7 +100,7 @@ mod tests {
     #[test]
     fn test_parse() {
         assert_eq!(
-            parse(EXAMPLE_INPUT),
+            parse(EXAMPLE_INPUT).unwrap(),
             vec![
                 Race {
                     duration: 7,
@@ -75,15 +116,7 @@ mod tests {
                 },
             ]
         );
-    }
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one(EXAMPLE_INPUT), 288);
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two(EXAMPLE_INPUT), 71503);
+        assert!(parse("").is_err());
+        assert!(parse("Time: 7 15\nDistance: 9").is_err());
     }
 }
@@ -2,12 +2,28 @@ use std::cmp::Ordering;
 
 use itertools::Itertools;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let hands = parse(input);
-    println!("Part 1: {}", part_one(&hands));
-    println!("Part 2: {}", part_two(&hands));
+pub struct Puzzle {
+    hands: Vec<Hand>,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            hands: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.hands).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.hands).into()
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -216,7 +232,7 @@ impl CardLabel {
             '4' => Self::Four,
             '3' => Self::Three,
             '2' => Self::Two,
-            _ => panic!("Unexpected char"),
+            _ => unreachable!("checked by parse"),
         }
     }
 
@@ -232,15 +248,21 @@ impl CardLabel {
     }
 }
 
-fn parse(input: &str) -> Vec<Hand> {
+fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
     parser::lines_custom(input, |line| {
-        let splitted = line.split(' ').collect_vec();
-
-        Hand {
-            hand: splitted[0].chars().map(CardLabel::from).collect(),
-            bid: splitted[1].parse().unwrap(),
+        let (hand, bid) = parser::split_once(line, " ")?;
+        parser::check_chars(hand, "AKQJT98765432")?;
+        if hand.len() != 5 {
+            return Err(ParseError::new(hand, "expected 5 cards"));
         }
+
+        Ok(Hand {
+            hand: hand.chars().map(CardLabel::from).collect(),
+            bid: parser::number(bid)?,
+        })
     })
+    .into_iter()
+    .collect()
 }
 
 fn part_one(hands: &[Hand]) -> usize {
@@ -261,24 +283,3 @@ fn part_two(hands: &[Hand]) -> usize {
         .map(|(i, hand)| (i + 1) * hand.bid)
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "32T3K 765
-    T55J5 684
-    KK677 28
-    KTJJT 220
-    QQQJA 483";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one(&parse(EXAMPLE_INPUT)), 6440);
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two(&parse(EXAMPLE_INPUT)), 5905);
-    }
-}
@@ -1,17 +1,36 @@
+use crate::cancel;
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use crate::util;
+use regex::Regex;
 
-pub fn solve(input: &str) {
-    let (i, n) = parse(input);
-    println!("Part 1: {}", part_one(&i, &n));
-    println!("Part 2: {}", part_two(&i, &n));
+pub struct Puzzle {
+    i: Vec<Instruction>,
+    n: NodeMap,
 }
 
-fn parse(input: &str) -> (Vec<Instruction>, NodeMap) {
-    let lines: Vec<&str> = input.split("\n\n").collect();
-    let instructions = parse_instructions(lines[0]);
-    let nodes = parse_nodes(lines[1]);
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (i, n) = parse(input)?;
+        Ok(Self { i, n })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.i, &self.n).into()
+    }
 
-    (instructions, nodes)
+    fn part_two(&self) -> Answer {
+        part_two(&self.i, &self.n).into()
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Instruction>, NodeMap), ParseError> {
+    let (instructions, nodes) = parser::split_once(input, "\n\n")?;
+    let instructions = parse_instructions(instructions)?;
+    let nodes = parse_nodes(nodes)?;
+
+    Ok((instructions, nodes))
 }
 
 const LETTERS: usize = 'Z' as usize - 'A' as usize + 1;
@@ -19,20 +38,18 @@ const SIZE: usize = LETTERS * LETTERS * LETTERS;
 
 type NodeMap = Vec<(usize, usize)>;
 
-fn parse_nodes(input: &str) -> NodeMap {
+fn parse_nodes(input: &str) -> Result<NodeMap, ParseError> {
+    let regex = Regex::new(r"^([A-Z]{3}) = \(([A-Z]{3}), ([A-Z]{3})\)$").unwrap();
     let mut nodes = vec![(0, 0); SIZE];
 
-    input.lines().for_each(|line| {
-        let replaced = &line.replace(['=', '(', ')', ','], "");
-        let a: Vec<&str> = replaced
-            .split(' ')
-            .filter(|x| !x.trim().is_empty())
-            .collect();
+    for line in input.lines() {
+        let captures = parser::captures(&regex, line.trim(), "`AAA = (BBB, CCC)`")?;
+        let address = |i| parse_address(captures.get(i).unwrap().as_str());
 
-        nodes[parse_address(a[0])] = (parse_address(a[1]), parse_address(a[2]));
-    });
+        nodes[address(1)] = (address(2), address(3));
+    }
 
-    nodes
+    Ok(nodes)
 }
 
 fn parse_address(addr: &str) -> usize {
@@ -46,14 +63,18 @@ fn alpha_to_num(c: char) -> usize {
     c as usize - 'A' as usize
 }
 
-fn parse_instructions(line: &str) -> Vec<Instruction> {
-    line.chars()
+fn parse_instructions(line: &str) -> Result<Vec<Instruction>, ParseError> {
+    parser::check_chars(line, "LR")?;
+    if line.is_empty() {
+        return Err(ParseError::new(line, "expected at least one instruction"));
+    }
+    Ok(line
+        .chars()
         .map(|x| match x {
             'L' => Instruction::Left,
-            'R' => Instruction::Right,
-            _ => panic!("Unexpected char {x}"),
+            _ => Instruction::Right,
         })
-        .collect()
+        .collect())
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -68,6 +89,7 @@ fn part_one(instructions: &[Instruction], nodes: &NodeMap) -> usize {
     let mut steps = 0;
 
     while k + 1 < SIZE {
+        cancel::checkpoint();
         k = match instructions[i] {
             Instruction::Left => nodes[k].0,
             Instruction::Right => nodes[k].1,
@@ -93,6 +115,7 @@ fn part_two(instructions: &[Instruction], nodes: &NodeMap) -> usize {
         let mut k = index;
 
         while k % LETTERS != alpha_to_num('Z') {
+            cancel::checkpoint();
             k = match instructions[i] {
                 Instruction::Left => nodes[k].0,
                 Instruction::Right => nodes[k].1,
@@ -123,32 +146,9 @@ EEE = (EEE, EEE)
 GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)";
 
-    const EXAMPLE_INPUT2: &str = "LR
-
-DDA = (DDB, XXX)
-DDB = (XXX, DDZ)
-DDZ = (DDB, XXX)
-EEA = (EEB, XXX)
-EEB = (EEC, EEC)
-EEC = (EEZ, EEZ)
-EEZ = (EEB, EEB)
-XXX = (XXX, XXX)";
-
     #[test]
     fn test_parse() {
-        let (instructions, _) = parse(EXAMPLE_INPUT);
+        let (instructions, _) = parse(EXAMPLE_INPUT).unwrap();
         assert_eq!(instructions, vec![Instruction::Right, Instruction::Left]);
     }
-
-    #[test]
-    fn test_part_one() {
-        let (instructions, nodes) = parse(EXAMPLE_INPUT);
-        assert_eq!(2, part_one(&instructions, &nodes));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let (instructions, nodes) = parse(EXAMPLE_INPUT2);
-        assert_eq!(6, part_two(&instructions, &nodes));
-    }
 }
@@ -1,17 +1,36 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
-pub fn solve(input: &str) {
-    let (left, right) = parse(input);
-    println!("Part 1: {}", part_one(&left, &right));
-    println!("Part 2: {}", part_two(&left, &right));
+pub struct Puzzle {
+    left: Vec<i64>,
+    right: Vec<i64>,
 }
 
-fn parse(input: &str) -> (Vec<i64>, Vec<i64>) {
-    input
-        .lines()
-        .filter_map(|line| line.split_once("   "))
-        .map(|(a, b)| (a.parse::<i64>().unwrap(), b.parse::<i64>().unwrap()))
-        .unzip()
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (left, right) = parse(input)?;
+        Ok(Self { left, right })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.left, &self.right).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.left, &self.right).into()
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<i64>, Vec<i64>), ParseError> {
+    let pairs = parser::lines_custom(input, |line| {
+        let (a, b) = parser::split_once(line, "   ")?;
+        Ok((parser::number(a)?, parser::number(b)?))
+    })
+    .into_iter()
+    .collect::<Result<Vec<(i64, i64)>, _>>()?;
+    Ok(pairs.into_iter().unzip())
 }
 
 fn part_one(left: &[i64], right: &[i64]) -> i64 {
@@ -27,27 +46,3 @@ fn part_two(left: &[i64], right: &[i64]) -> i64 {
         .map(|&x| x * (right.iter().filter(|&&y| y == x).count() as i64))
         .sum()
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3";
-
-    #[test]
-    fn test_part_one() {
-        let (left, right) = parse(EXAMPLE_INPUT);
-        assert_eq!(11, part_one(&left, &right));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let (left, right) = parse(EXAMPLE_INPUT);
-        assert_eq!(31, part_two(&left, &right));
-    }
-}
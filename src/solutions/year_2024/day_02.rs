@@ -1,17 +1,31 @@
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let reports = parse(input);
-    println!("Part 1: {}", part_one(&reports));
-    println!("Part 2: {}", part_two(&reports));
+pub struct Puzzle {
+    reports: Vec<Vec<i32>>,
 }
 
-fn parse(input: &str) -> Vec<Vec<i32>> {
-    parser::lines_custom(input, |line| {
-        line.split(' ')
-            .map(|x| x.parse().unwrap())
-            .collect::<Vec<i32>>()
-    })
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            reports: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.reports).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.reports).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parser::lines_custom(input, |line| line.split(' ').map(parser::number).collect())
+        .into_iter()
+        .collect()
 }
 
 fn part_one(reports: &[Vec<i32>]) -> usize {
@@ -40,25 +54,3 @@ fn is_report_safe(report: &[i32]) -> bool {
         })
         && (report.windows(2).all(|x| x[0] <= x[1]) || report.windows(2).all(|x| x[0] >= x[1]))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(2, part_one(&parse(EXAMPLE_INPUT)));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(4, part_two(&parse(EXAMPLE_INPUT)));
-    }
-}
@@ -1,12 +1,32 @@
+use crate::error::ParseError;
+use crate::input::InputMode;
+use crate::solutions::{Answer, Solution};
 use regex::Regex;
 
-pub fn solve(input: &str) {
-    println!("Part 1: {}", part_one(input));
-    println!("Part 2: {}", part_two(input));
+pub struct Puzzle {
+    input: String,
+}
+
+impl Solution<'_> for Puzzle {
+    const INPUT_MODE: InputMode = InputMode::Ascii;
+
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            input: input.to_string(),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.input).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.input).into()
+    }
 }
 
 fn part_one(input: &str) -> i32 {
-    Regex::new(r"mul\((\d+),(\d+)\)")
+    Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")
         .unwrap()
         .captures_iter(input)
         .map(|cap| cap[1].parse::<i32>().unwrap() * cap[2].parse::<i32>().unwrap())
@@ -20,7 +40,7 @@ fn part_two(input: &str) -> i32 {
     let mut sum = 0;
     let mut is_do = true;
 
-    let regex = Regex::new(r"^mul\((\d+),(\d+)\)").unwrap();
+    let regex = Regex::new(r"^mul\((\d{1,3}),(\d{1,3})\)").unwrap();
 
     let mut i = 0;
     while i < input.len() {
@@ -46,24 +66,3 @@ fn part_two(input: &str) -> i32 {
 
     sum
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str =
-        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-
-    const EXAMPLE_INPUT2: &str =
-        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(161, part_one(EXAMPLE_INPUT));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(48, part_two(EXAMPLE_INPUT2));
-    }
-}
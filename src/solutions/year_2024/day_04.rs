@@ -1,13 +1,29 @@
 use itertools::Itertools;
 
+use crate::error::ParseError;
 use crate::euclidic::coord::Coord2D;
 use crate::euclidic::direction::Direction;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let matrix = parse(input);
-    println!("Part 1: {}", part_one(&matrix));
-    println!("Part 2: {}", part_two(&matrix));
+pub struct Puzzle {
+    matrix: Matrix,
+}
+
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            matrix: parse(input),
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.matrix).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.matrix).into()
+    }
 }
 
 const SPACE: char = '.';
@@ -94,16 +110,4 @@ MXMXAXMASX";
         let matrix = parse(EXAMPLE_INPUT);
         assert_eq!(matrix.size, 10);
     }
-
-    #[test]
-    fn test_part_one() {
-        let matrix = parse(EXAMPLE_INPUT);
-        assert_eq!(part_one(&matrix), 18);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let matrix = parse(EXAMPLE_INPUT);
-        assert_eq!(part_two(&matrix), 9);
-    }
 }
@@ -1,3 +1,6 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use itertools::Itertools;
 
 type Page = u32;
@@ -11,39 +14,44 @@ struct Rule {
 type RuleSet = Vec<Rule>;
 type PageUpdate = Vec<Page>;
 
-pub fn solve(input: &str) {
-    let (rules, updates) = parse(input);
-    println!("Part 1: {}", part_one(&rules, &updates));
-    println!("Part 2: {}", part_two(&rules, &updates));
+pub struct Puzzle {
+    rules: RuleSet,
+    updates: Vec<PageUpdate>,
 }
 
-fn parse(input: &str) -> (RuleSet, Vec<PageUpdate>) {
-    if let Some(parts) = input.split_once("\n\n") {
-        (
-            parts.0.lines().map(parse_rule).collect_vec(),
-            parts.1.lines().map(parse_update).collect_vec(),
-        )
-    } else {
-        panic!("Could not parse!")
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let (rules, updates) = parse(input)?;
+        Ok(Self { rules, updates })
     }
-}
 
-fn parse_rule(rule: &str) -> Rule {
-    if let Some(numbers) = rule.split_once('|') {
-        Rule {
-            x: numbers.0.parse().unwrap(),
-            y: numbers.1.parse().unwrap(),
-        }
-    } else {
-        panic!("Cannot parse rule")
+    fn part_one(&self) -> Answer {
+        part_one(&self.rules, &self.updates).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.rules, &self.updates).into()
     }
 }
 
-fn parse_update(update: &str) -> PageUpdate {
-    update
-        .split(',')
-        .map(|page| page.parse().unwrap())
-        .collect()
+fn parse(input: &str) -> Result<(RuleSet, Vec<PageUpdate>), ParseError> {
+    let (rules, updates) = parser::split_once(input, "\n\n")?;
+    Ok((
+        rules.lines().map(parse_rule).try_collect()?,
+        updates.lines().map(parse_update).try_collect()?,
+    ))
+}
+
+fn parse_rule(rule: &str) -> Result<Rule, ParseError> {
+    let (x, y) = parser::split_once(rule, "|")?;
+    Ok(Rule {
+        x: parser::number(x)?,
+        y: parser::number(y)?,
+    })
+}
+
+fn parse_update(update: &str) -> Result<PageUpdate, ParseError> {
+    update.split(',').map(parser::number).collect()
 }
 
 fn part_one(rules: &RuleSet, updates: &[PageUpdate]) -> u32 {
@@ -102,57 +110,16 @@ fn middle_page(update: &PageUpdate) -> Page {
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
-
     #[test]
     fn test_parse_rule() {
-        assert_eq!(Rule { x: 47, y: 53 }, parse_rule("47|53"));
-        assert_eq!(Rule { x: 0, y: 87 }, parse_rule("0|87"));
-        assert_eq!(Rule { x: 987, y: 123 }, parse_rule("987|123"));
+        assert_eq!(Rule { x: 47, y: 53 }, parse_rule("47|53").unwrap());
+        assert_eq!(Rule { x: 0, y: 87 }, parse_rule("0|87").unwrap());
+        assert_eq!(Rule { x: 987, y: 123 }, parse_rule("987|123").unwrap());
     }
 
     #[test]
     fn test_parse_update() {
-        assert_eq!(vec![47, 53], parse_update("47,53"));
-        assert_eq!(vec![1, 2, 3, 4, 5], parse_update("1,2,3,4,5"));
-    }
-
-    #[test]
-    fn test_part_one() {
-        let (rules, updates) = parse(EXAMPLE_INPUT);
-        assert_eq!(143, part_one(&rules, &updates));
-    }
-
-    #[test]
-    fn test_part_two() {
-        let (rules, updates) = parse(EXAMPLE_INPUT);
-        assert_eq!(123, part_two(&rules, &updates));
+        assert_eq!(vec![47, 53], parse_update("47,53").unwrap());
+        assert_eq!(vec![1, 2, 3, 4, 5], parse_update("1,2,3,4,5").unwrap());
     }
 }
@@ -1,29 +1,60 @@
 use std::collections::HashSet;
 
+use crate::cancel;
+use crate::error::ParseError;
+use crate::solutions::{Answer, Solution};
 use crate::{
     euclidic::{coord::Coord2D, direction::Direction},
     parser,
 };
 
-pub fn solve(input: &str) {
-    let matrix = parse(input);
-    println!("Part 1: {}", part_one(&matrix));
-    println!("Part 2: {}", part_two(&matrix));
+pub struct Puzzle {
+    matrix: Matrix,
 }
 
-fn parse(input: &str) -> Matrix {
-    let grid = parser::lines_custom(input, |line| {
-        line.chars()
-            .map(|c| match c {
-                '#' => Field::Obstacle,
-                '^' => Field::Guard,
-                _ => Field::Empty,
-            })
-            .collect::<Vec<Field>>()
-    });
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            matrix: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.matrix).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.matrix).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Matrix, ParseError> {
+    let lines = parser::lines_as_strings(input);
+    for line in &lines {
+        parser::check_chars(line, ".#^")?;
+        if line.len() != lines.len() {
+            return Err(ParseError::new(line, "expected a square map"));
+        }
+    }
+    if input.matches('^').count() != 1 {
+        return Err(ParseError::new(input, "expected exactly one guard `^`"));
+    }
+
+    let grid = lines
+        .iter()
+        .map(|line| {
+            line.chars()
+                .map(|c| match c {
+                    '#' => Field::Obstacle,
+                    '^' => Field::Guard,
+                    _ => Field::Empty,
+                })
+                .collect::<Vec<Field>>()
+        })
+        .collect::<Vec<_>>();
     let size = grid.len() as isize;
 
-    Matrix { grid, size }
+    Ok(Matrix { grid, size })
 }
 
 fn part_one(matrix: &Matrix) -> usize {
@@ -35,6 +66,7 @@ fn part_two(matrix: &Matrix) -> usize {
     let original = find_visited(matrix);
 
     for pos in original {
+        cancel::checkpoint();
         if matrix.field(pos) != Field::Guard {
             let mut m = matrix.clone();
             m.set_field(pos, Field::Obstacle);
@@ -116,7 +148,7 @@ impl Matrix {
             }
         }
 
-        panic!("No Guard found!");
+        unreachable!("checked by parse");
     }
 
     fn in_bounds(&self, pos: Coord2D) -> bool {
@@ -146,29 +178,3 @@ impl Guard {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(41, part_one(&parse(EXAMPLE_INPUT)));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(6, part_two(&parse(EXAMPLE_INPUT)));
-    }
-}
@@ -1,17 +1,32 @@
 use itertools::Itertools;
 
+use crate::error::ParseError;
 use crate::parser;
+use crate::solutions::{Answer, Solution};
 
-pub fn solve(input: &str) {
-    let equations = parse(input);
-    println!("Part 1: {}", part_one(&equations));
-    println!("Part 2: {}", part_two(&equations));
+pub struct Puzzle {
+    equations: Vec<Equation>,
 }
 
-fn parse(input: &str) -> Vec<Equation> {
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            equations: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.equations).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.equations).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Equation>, ParseError> {
     parser::lines_custom(input, parse_line)
         .into_iter()
-        .flatten()
         .collect()
 }
 
@@ -20,22 +35,26 @@ struct Equation {
     values: Vec<i64>,
 }
 
-fn parse_line(line: &str) -> Option<Equation> {
-    if let Some((result, values)) = line.split_once(':') {
-        let equation = Equation {
-            result: result.parse().unwrap(),
-            values: values
-                .split(' ')
-                .map(str::trim)
-                .filter(|x| !x.is_empty())
-                .map(|x| x.parse().unwrap())
-                .collect(),
-        };
-
-        Some(equation)
-    } else {
-        None
+fn parse_line(line: &str) -> Result<Equation, ParseError> {
+    let (result, values) = parser::split_once(line, ":")?;
+    let values = values
+        .split(' ')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(|x| {
+            parser::number::<u32>(x)
+                .map(i64::from)
+                .map_err(|_| ParseError::new(x, "expected a non-negative number"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if values.is_empty() {
+        return Err(ParseError::new(line, "expected at least one value"));
     }
+
+    Ok(Equation {
+        result: parser::number(result)?,
+        values,
+    })
 }
 
 fn part_one(equations: &[Equation]) -> i64 {
@@ -73,12 +92,9 @@ fn is_valid_equation(equation: &Equation, chains: &[Vec<Operator>]) -> bool {
         equation.values.iter().skip(1).zip(chain).try_fold(
             equation.values[0],
             |acc, (&x, operator)| {
-                let new_value = operator.apply(acc, x);
-                if new_value > equation.result {
-                    None
-                } else {
-                    Some(new_value)
-                }
+                operator
+                    .apply(acc, x)
+                    .filter(|&new_value| new_value <= equation.result)
             },
         ) == Some(equation.result)
     })
@@ -92,11 +108,11 @@ enum Operator {
 }
 
 impl Operator {
-    fn apply(self, a: i64, b: i64) -> i64 {
+    fn apply(self, a: i64, b: i64) -> Option<i64> {
         match self {
-            Operator::Add => a + b,
-            Operator::Multiply => a * b,
-            Operator::Concatenation => format!("{a}{b}").parse().unwrap(),
+            Operator::Add => a.checked_add(b),
+            Operator::Multiply => a.checked_mul(b),
+            Operator::Concatenation => format!("{a}{b}").parse().ok(),
         }
     }
 }
@@ -117,28 +133,3 @@ fn all_operator_chains(len: usize, operators: &[Operator]) -> Vec<Vec<Operator>>
             .collect()
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const EXAMPLE_INPUT: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
-
-    #[test]
-    fn test_part_one() {
-        assert_eq!(3749, part_one(&parse(EXAMPLE_INPUT)));
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(11387, part_two(&parse(EXAMPLE_INPUT)));
-    }
-}
@@ -1,27 +1,41 @@
+use crate::error::ParseError;
+use crate::parser;
+use crate::solutions::{Answer, Solution};
 use log::debug;
 
-pub fn solve(input: &str) {
-    let directions = parse(input);
-    println!("Part 1: {}", part_one(&directions));
-    println!("Part 2: {}", part_two(&directions));
+pub struct Puzzle {
+    directions: Vec<Rotation>,
 }
 
-fn parse(input: &str) -> Vec<Rotation> {
-    input
-        .lines()
-        .map(str::trim)
-        .filter(|x| !x.is_empty())
-        .map(|x| x.split_at(1))
-        .map(|(direction, steps)| to_rotation(direction, steps))
+impl Solution<'_> for Puzzle {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            directions: parse(input)?,
+        })
+    }
+
+    fn part_one(&self) -> Answer {
+        part_one(&self.directions).into()
+    }
+
+    fn part_two(&self) -> Answer {
+        part_two(&self.directions).into()
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Rotation>, ParseError> {
+    parser::lines_custom(input, to_rotation)
+        .into_iter()
         .collect()
 }
 
-fn to_rotation(direction: &str, steps: &str) -> Rotation {
-    let num = steps.parse().expect("Should be a number");
-    match direction {
-        "L" => Rotation::Left(num),
-        "R" => Rotation::Right(num),
-        _ => panic!("Unexpected direction: {direction}"),
+fn to_rotation(line: &str) -> Result<Rotation, ParseError> {
+    if let Some(steps) = line.strip_prefix('L') {
+        Ok(Rotation::Left(parser::number(steps)?))
+    } else if let Some(steps) = line.strip_prefix('R') {
+        Ok(Rotation::Right(parser::number(steps)?))
+    } else {
+        Err(ParseError::new(line, "expected `L` or `R`"))
     }
 }
 
@@ -75,31 +89,3 @@ fn part_two(rotations: &[Rotation]) -> usize {
     }
     counter
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "
-L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-
-    #[test]
-    fn part_one_works() {
-        assert_eq!(3, part_one(&parse(INPUT)));
-    }
-
-    #[test]
-    fn part_two_works() {
-        assert_eq!(6, part_two(&parse(INPUT)));
-    }
-}
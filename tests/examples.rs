@@ -0,0 +1,161 @@
+//! Runs every registered day against the puzzle examples in `tests/examples`.
+//!
+//! An example is stored as `<year>/day_<dd>.txt`, further examples of the day as `<year>/day_<dd>_<name>.txt`.
+//! The file starts with `key = value` lines, followed by `---` and the example input:
+//!
+//! ```text
+//! # comments are allowed in the header
+//! part_one = 1120
+//! part_two = 689
+//! time = 1000
+//! ---
+//! Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+//! ```
+//!
+//! `part_one` and `part_two` are the expected answers, only the parts with an answer are calculated.
+//! All other keys set parameters of the day (see `--list-params`).
+
+use aoc::params::Params;
+use aoc::runner;
+use aoc::solutions::{self, Entry, Parts};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const EXAMPLES_DIR: &str = "tests/examples";
+const SEPARATOR: &str = "---";
+
+/// A puzzle example with its expected answers
+#[derive(Debug)]
+struct Example {
+    path: PathBuf,
+    part_one: Option<String>,
+    part_two: Option<String>,
+    params: Params,
+    input: String,
+}
+
+impl Example {
+    fn read(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let separator = text
+            .lines()
+            .position(|x| x.trim_end() == SEPARATOR)
+            .ok_or(format!("missing the separator {SEPARATOR:?}"))?;
+        let input = text
+            .split_inclusive('\n')
+            .skip(separator + 1)
+            .collect::<String>();
+
+        let mut example = Example {
+            path: path.to_path_buf(),
+            part_one: None,
+            part_two: None,
+            params: Params::default(),
+            input,
+        };
+        let mut params = Vec::new();
+        for line in text.lines().take(separator).map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .map(|(x, y)| (x.trim(), y.trim().to_string()))
+                .ok_or(format!("expected <key> = <value>, got {line:?}"))?;
+            match key {
+                "part_one" => example.part_one = Some(value),
+                "part_two" => example.part_two = Some(value),
+                _ => params.push((key.to_string(), value)),
+            }
+        }
+        example.params = params.into_iter().collect();
+        Ok(example)
+    }
+
+    fn parts(&self) -> Option<Parts> {
+        match (&self.part_one, &self.part_two) {
+            (Some(_), Some(_)) => Some(Parts::Both),
+            (Some(_), None) => Some(Parts::One),
+            (None, Some(_)) => Some(Parts::Two),
+            (None, None) => None,
+        }
+    }
+
+    /// Solves the example, returns a description of every wrong answer
+    fn check(&self, entry: &Entry) -> Vec<String> {
+        let name = self.path.display();
+        let Some(parts) = self.parts() else {
+            return Vec::new();
+        };
+        if let Err(message) = self.params.check(entry.params()) {
+            return vec![format!("{name}: {message}")];
+        }
+        let answers = match runner::catch(|| entry.solve_with(&self.input, parts, &self.params)) {
+            Ok(Ok((answers, _))) => answers,
+            Ok(Err(error)) => return vec![format!("{name}: {error}")],
+            Err(message) => return vec![format!("{name}: panicked: {message}")],
+        };
+
+        [&self.part_one, &self.part_two]
+            .into_iter()
+            .zip(answers.iter())
+            .filter_map(|(expected, (part, actual))| {
+                let expected = expected.as_ref()?;
+                let actual = actual.to_string();
+                (*expected != actual)
+                    .then(|| format!("{name}: part {part} expected {expected}, got {actual}"))
+            })
+            .collect()
+    }
+}
+
+/// Returns the example files of `entry`, ordered by name
+fn example_files(entry: &Entry) -> Vec<PathBuf> {
+    let prefix = format!("day_{:02}", entry.day);
+    let Ok(files) = fs::read_dir(Path::new(EXAMPLES_DIR).join(entry.year.to_string())) else {
+        return Vec::new();
+    };
+    let mut files = files
+        .filter_map(Result::ok)
+        .map(|x| x.path())
+        .filter(|path| {
+            let stem = path.file_stem().and_then(|x| x.to_str()).unwrap_or("");
+            path.extension().is_some_and(|x| x == "txt")
+                && (stem == prefix || stem.starts_with(&format!("{prefix}_")))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+#[test]
+fn examples_are_solved() {
+    let mut failures = Vec::new();
+    let mut checked = 0;
+    for entry in solutions::all() {
+        for path in example_files(entry) {
+            match Example::read(&path) {
+                Ok(example) => failures.extend(example.check(entry)),
+                Err(message) => failures.push(format!("{}: {message}", path.display())),
+            }
+            checked += 1;
+        }
+    }
+    assert!(checked > 0, "no examples found in {EXAMPLES_DIR}");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn examples_belong_to_registered_days() {
+    let registered = solutions::all().flat_map(example_files).collect::<Vec<_>>();
+    for year in fs::read_dir(EXAMPLES_DIR).unwrap() {
+        for file in fs::read_dir(year.unwrap().path()).unwrap() {
+            let path = file.unwrap().path();
+            assert!(
+                registered.contains(&path),
+                "{} belongs to no registered day",
+                path.display()
+            );
+        }
+    }
+}
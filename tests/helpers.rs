@@ -0,0 +1,56 @@
+use aoc::euclidic::coord::Coord2D;
+use aoc::euclidic::direction::Direction;
+use aoc::graph::{Graph, SimpleGraph, WeightedGraph};
+use aoc::parser;
+use aoc::util;
+
+#[test]
+fn weighted_graph_finds_shortest_path() {
+    let mut graph = WeightedGraph::new();
+    graph.add_edge('a', 'b', 7);
+    graph.add_edge('a', 'c', 2);
+    graph.add_edge('c', 'b', 3);
+    graph.add_edge('b', 'd', 1);
+
+    assert_eq!(Some(6), graph.shortest_path('a', 'd'));
+    assert_eq!(None, graph.shortest_path('d', 'a'));
+    assert_eq!(vec!['b', 'c'], {
+        let mut x = graph.neighbours(&'a');
+        x.sort_unstable();
+        x
+    });
+}
+
+#[test]
+fn simple_graph_works() {
+    let mut graph = SimpleGraph::new();
+    assert!(graph.is_empty());
+    graph.add_edge(1, 2);
+    assert_eq!(vec![2], graph.neighbours(&1));
+}
+
+#[test]
+fn coord_and_direction_work() {
+    let start = Coord2D(1, 1);
+    assert_eq!(Coord2D(1, 0), start + Direction::North.coordinates());
+    assert_eq!(Coord2D(-2, -2), -2 * start);
+    assert_eq!(
+        Direction::South.coordinates(),
+        Direction::North.opposing().coordinates()
+    );
+    assert_eq!(
+        Direction::East.coordinates(),
+        Direction::North.rotate_clockwise().coordinates()
+    );
+}
+
+#[test]
+fn parser_and_util_work() {
+    assert_eq!(
+        vec![1, -2],
+        parser::lines_as_numbers::<i32>("1\n\n-2\n").unwrap()
+    );
+    assert!(parser::lines_as_numbers::<u8>("1\nx").is_err());
+    assert_eq!(vec!["a b", "c"], parser::lines_as_strings(" a b \nc"));
+    assert_eq!(12, util::least_common_multiplier(&[4, 6]));
+}
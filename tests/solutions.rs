@@ -0,0 +1,87 @@
+use aoc::error::AocError;
+use aoc::input::InputMode;
+use aoc::params::Params;
+use aoc::runner::{self, Outcome};
+use aoc::solutions::{self, Answer, Parts};
+use std::fs;
+
+#[test]
+fn find_and_solve() {
+    let entry = solutions::find(2015, 1).unwrap();
+    let answers = entry.solve("()())", Parts::Both).unwrap();
+    assert_eq!(Answer::Number(-1), answers.part_one);
+    assert_eq!(Answer::Number(5), answers.part_two);
+
+    let answers = entry.solve("(()(()(", Parts::One).unwrap();
+    assert_eq!(Answer::Number(3), answers.part_one);
+    assert_eq!(Answer::Unsolved, answers.part_two);
+
+    assert!(solutions::find(2015, 26).is_err());
+    assert!(solutions::of_year(2015).all(|x| x.year == 2015));
+}
+
+#[test]
+fn solve_with_params() {
+    let entry = solutions::find(15, 14).unwrap();
+    let input = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.
+Dancer can fly 16 km/s for 11 seconds, but then must rest for 162 seconds.";
+    let params = [(String::from("time"), String::from("1000"))]
+        .into_iter()
+        .collect::<Params>();
+    assert_eq!(Ok(()), params.check(entry.params()));
+
+    let (answers, _) = entry.solve_with(input, Parts::Both, &params).unwrap();
+    assert_eq!(Answer::Number(1120), answers.part_one);
+    assert_eq!(Answer::Number(689), answers.part_two);
+}
+
+#[test]
+fn invalid_input_is_reported() {
+    let entry = solutions::find(2015, 7).unwrap();
+    let error = entry
+        .solve("123 -> x\nx FOO y -> a", Parts::Both)
+        .unwrap_err();
+    let AocError::Parse { year, day, error } = error else {
+        panic!("expected a parse error, got {error:?}");
+    };
+    assert_eq!((2015, 7), (year, day));
+    assert_eq!(Some(2), error.position.map(|(line, _)| line));
+}
+
+#[test]
+fn input_is_normalized() {
+    let entry = solutions::find(2015, 1).unwrap();
+    let answers = entry.solve("\u{feff}()())\r\n\r\n", Parts::Both).unwrap();
+    assert_eq!(Answer::Number(-1), answers.part_one);
+
+    let entry = solutions::find(2016, 1).unwrap();
+    assert_eq!(InputMode::Ascii, entry.input_mode());
+    assert!(entry.solve("R2, L3\r\n", Parts::One).is_ok());
+    assert!(matches!(
+        entry.solve("R2, Ł3", Parts::One),
+        Err(AocError::Parse { error, .. }) if error.position == Some((1, 5))
+    ));
+}
+
+#[test]
+fn run_all_reads_inputs() {
+    let inputs = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+    let path = runner::input_path(&inputs, 2015, 1);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, "()())").unwrap();
+
+    let entries = [
+        solutions::find(2015, 1).unwrap(),
+        solutions::find(2015, 2).unwrap(),
+    ];
+    let results = runner::run_all(&entries, &inputs, Parts::Both, None, 2, |_| {
+        Params::default()
+    });
+    fs::remove_dir_all(&inputs).unwrap();
+
+    assert!(matches!(&results[0].outcome, Outcome::Solved(x) if x.part_two == Answer::Number(5)));
+    assert!(
+        matches!(&results[1].outcome, Outcome::MissingInput(x) if *x == runner::input_path(&inputs, 2015, 2))
+    );
+    assert!(results.iter().all(|x| !x.is_failure()));
+}